@@ -0,0 +1,47 @@
+use farmfe_core::error::{CompilationError, Result};
+
+pub const USAGE: &str = r#"Usage: farm [build] [options]
+
+Options:
+  -c, --config <file>  json or toml config file, default to farm.config.json or farm.config.toml
+  -w, --watch          rebuild when the modules change
+  -h, --help           print this message"#;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CliArgs {
+  pub config: Option<String>,
+  pub watch: bool,
+  pub help: bool,
+}
+
+impl CliArgs {
+  /// Parse the args without the binary name
+  pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self> {
+    let mut cli_args = Self::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+      match arg.as_str() {
+        "build" => {}
+        "-w" | "--watch" => cli_args.watch = true,
+        "-h" | "--help" => cli_args.help = true,
+        "-c" | "--config" => {
+          cli_args.config = Some(args.next().ok_or_else(|| {
+            CompilationError::GenericError(format!("Missing value of {arg}\n\n{USAGE}"))
+          })?)
+        }
+        _ => {
+          if let Some(config) = arg.strip_prefix("--config=") {
+            cli_args.config = Some(config.to_string());
+          } else {
+            return Err(CompilationError::GenericError(format!(
+              "Unknown argument {arg}\n\n{USAGE}"
+            )));
+          }
+        }
+      }
+    }
+
+    Ok(cli_args)
+  }
+}
@@ -0,0 +1,247 @@
+#![deny(clippy::all)]
+
+//! Drive [Compiler] without node: load the config file, compile, write the resources to `output.path` and rebuild on changes.
+
+use std::{
+  path::{Path, PathBuf},
+  sync::mpsc,
+  time::Duration,
+};
+
+use farmfe_compiler::{watcher::FsWatcher, Compiler};
+use farmfe_core::{
+  config::{persistent_cache::PersistentCacheConfig, Config},
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  module::ModuleId,
+  plugin::UpdateType,
+  serde_json::{self, Value},
+};
+
+pub mod args;
+
+/// Config files looked up in the current dir when `--config` is not specified, in order
+pub const DEFAULT_CONFIG_FILES: [&str; 2] = ["farm.config.json", "farm.config.toml"];
+
+/// Find the config file of `cwd` if `config` is not specified
+pub fn resolve_config_path(cwd: &Path, config: Option<&str>) -> Result<PathBuf> {
+  if let Some(config) = config {
+    return Ok(cwd.join(config));
+  }
+
+  DEFAULT_CONFIG_FILES
+    .iter()
+    .map(|name| cwd.join(name))
+    .find(|path| path.exists())
+    .ok_or_else(|| {
+      CompilationError::GenericError(format!(
+        "Can not find config file in {:?}, expected one of {:?}",
+        cwd, DEFAULT_CONFIG_FILES
+      ))
+    })
+}
+
+/// Load a json or toml config file. `root` defaults to the dir of the config file,
+/// and relative `root`, `runtime.path` and `runtime.swcHelpersPath` are resolved from the dir of the config file.
+pub fn load_config(path: &Path) -> Result<Config> {
+  let content = std::fs::read_to_string(path).map_err(|e| {
+    CompilationError::GenericError(format!("Failed to read config file {path:?}: {e}"))
+  })?;
+  let parse_error = |e: String| {
+    CompilationError::GenericError(format!("Failed to parse config file {path:?}: {e}"))
+  };
+
+  let mut value: Value = match path.extension().and_then(|e| e.to_str()) {
+    Some("json") => serde_json::from_str(&content).map_err(|e| parse_error(e.to_string()))?,
+    Some("toml") => toml::from_str(&content).map_err(|e| parse_error(e.to_string()))?,
+    _ => {
+      return Err(parse_error(
+        "only .json and .toml are supported".to_string(),
+      ))
+    }
+  };
+
+  let config_dir = path.parent().unwrap_or(Path::new("."));
+  let object = value
+    .as_object_mut()
+    .ok_or_else(|| parse_error("the config should be an object".to_string()))?;
+
+  let root = match object.get("root").and_then(|r| r.as_str()) {
+    Some(root) => config_dir.join(root),
+    None => config_dir.to_path_buf(),
+  };
+  object.insert("root".to_string(), Value::String(normalize_path(&root)));
+
+  if let Some(runtime) = object.get_mut("runtime").and_then(|r| r.as_object_mut()) {
+    for key in ["path", "swcHelpersPath"] {
+      if let Some(p) = runtime.get(key).and_then(|p| p.as_str()) {
+        let resolved = normalize_path(&config_dir.join(p));
+        runtime.insert(key.to_string(), Value::String(resolved));
+      }
+    }
+  }
+
+  let has_persistent_cache = object.contains_key("persistentCache");
+  let mut config: Config = serde_json::from_value(value).map_err(|e| parse_error(e.to_string()))?;
+
+  // the default cache dir is under the current dir, use the root instead
+  if !has_persistent_cache {
+    config.persistent_cache = Box::new(PersistentCacheConfig::get_default_config(&config.root));
+  }
+
+  Ok(config)
+}
+
+/// The absolute dir that the resources are written to
+pub fn output_dir(config: &Config) -> PathBuf {
+  Path::new(&config.root).join(&config.output.path)
+}
+
+/// Write the non-emitted resources to `output.path`
+pub fn write_resources(context: &CompilationContext) -> Result<()> {
+  let output_dir = output_dir(&context.config);
+  let resources_map = context.resources_map.lock();
+
+  for resource in resources_map.values().filter(|r| !r.emitted) {
+    let path = output_dir.join(&resource.name);
+
+    if let Some(parent) = path.parent() {
+      std::fs::create_dir_all(parent).map_err(|e| {
+        CompilationError::GenericError(format!("Failed to create dir {parent:?}: {e}"))
+      })?;
+    }
+
+    std::fs::write(&path, &resource.bytes)
+      .map_err(|e| CompilationError::GenericError(format!("Failed to write {path:?}: {e}")))?;
+  }
+
+  Ok(())
+}
+
+/// Compile and write the resources
+pub fn build(compiler: &Compiler) -> Result<()> {
+  compiler.compile()?;
+  write_resources(compiler.context())
+}
+
+/// Watch the modules of the compilation, update the compiler and rewrite the resources when they change.
+/// Blocks the current thread until the watcher is dropped.
+pub fn watch(compiler: &Compiler) -> Result<()> {
+  let (sender, receiver) = mpsc::channel::<Vec<(String, UpdateType)>>();
+  let mut watcher = FsWatcher::new(move |paths| {
+    sender.send(paths).ok();
+  })
+  .map_err(|e| CompilationError::GenericError(format!("Failed to create watcher: {e}")))?;
+
+  let mut watched = watched_paths(compiler.context());
+  watcher.watch(watched.iter().map(Path::new).collect()).ok();
+
+  while let Ok(mut events) = receiver.recv() {
+    // a save usually triggers multiple events, collect them into one update
+    while let Ok(more) = receiver.recv_timeout(Duration::from_millis(50)) {
+      events.extend(more);
+    }
+
+    let paths = update_paths(compiler.context(), events);
+
+    if paths.is_empty() {
+      continue;
+    }
+
+    let start = std::time::Instant::now();
+    let result = compiler
+      .update(paths, |_| {}, true, false)
+      .and_then(|_| write_resources(compiler.context()));
+
+    match result {
+      Ok(()) => println!("[farm] rebuilt in {}ms", start.elapsed().as_millis()),
+      Err(e) => eprintln!("[farm] {e}"),
+    }
+
+    let added = watched_paths(compiler.context())
+      .into_iter()
+      .filter(|p| !watched.contains(p))
+      .collect::<Vec<_>>();
+    watcher.watch(added.iter().map(Path::new).collect()).ok();
+    watched.extend(added);
+  }
+
+  Ok(())
+}
+
+/// Resolved paths of the modules and extra watched files that exist on disk
+fn watched_paths(context: &CompilationContext) -> Vec<String> {
+  let root = &context.config.root;
+  let module_graph = context.module_graph.read();
+  let watch_graph = context.watch_graph.read();
+
+  let mut paths = module_graph
+    .modules()
+    .into_iter()
+    .filter(|m| !m.external)
+    .map(|m| m.id.resolved_path(root))
+    .chain(
+      watch_graph
+        .modules()
+        .into_iter()
+        .map(|m| m.resolved_path(root)),
+    )
+    .filter(|p| Path::new(p).is_file())
+    .collect::<Vec<_>>();
+  paths.sort();
+  paths.dedup();
+
+  paths
+}
+
+/// Collapse the events of the same file into one by its final state, as editors may remove and recreate a file when saving.
+/// Changes of the files that are not modules are ignored unless they are newly created
+pub fn update_paths(
+  context: &CompilationContext,
+  mut events: Vec<(String, UpdateType)>,
+) -> Vec<(String, UpdateType)> {
+  events.sort_by(|a, b| a.0.cmp(&b.0));
+
+  let mut paths: Vec<String> = events.iter().map(|(p, _)| p.clone()).collect();
+  paths.dedup();
+
+  paths
+    .into_iter()
+    .filter_map(|path| {
+      let added = events
+        .iter()
+        .any(|(p, ty)| *p == path && *ty == UpdateType::Added);
+
+      let update_type = match (Path::new(&path).is_file(), has_module(context, &path)) {
+        (true, true) => UpdateType::Updated,
+        (true, false) if added => UpdateType::Added,
+        (false, true) => UpdateType::Removed,
+        _ => return None,
+      };
+
+      Some((path, update_type))
+    })
+    .collect()
+}
+
+fn has_module(context: &CompilationContext, path: &str) -> bool {
+  let id = ModuleId::new(path, "", &context.config.root);
+
+  context.module_graph.read().has_module(&id) || context.watch_graph.read().has_module(&id)
+}
+
+fn normalize_path(path: &Path) -> String {
+  let mut normalized = PathBuf::new();
+
+  for comp in path.components() {
+    match comp {
+      std::path::Component::CurDir => {}
+      std::path::Component::ParentDir => {
+        normalized.pop();
+      }
+      _ => normalized.push(comp),
+    }
+  }
+
+  normalized.to_string_lossy().to_string()
+}
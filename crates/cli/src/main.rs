@@ -0,0 +1,43 @@
+use std::time::Instant;
+
+use farmfe_cli::{
+  args::{CliArgs, USAGE},
+  build, load_config, output_dir, resolve_config_path, watch,
+};
+use farmfe_compiler::Compiler;
+use farmfe_core::error::Result;
+
+fn run() -> Result<()> {
+  let args = CliArgs::parse(std::env::args().skip(1))?;
+
+  if args.help {
+    println!("{USAGE}");
+    return Ok(());
+  }
+
+  let cwd = std::env::current_dir().unwrap();
+  let config = load_config(&resolve_config_path(&cwd, args.config.as_deref())?)?;
+  let output_dir = output_dir(&config);
+  let compiler = Compiler::new(config, vec![])?;
+
+  let start = Instant::now();
+  build(&compiler)?;
+  println!(
+    "[farm] built in {}ms, resources are written to {:?}",
+    start.elapsed().as_millis(),
+    output_dir
+  );
+
+  if args.watch {
+    watch(&compiler)?;
+  }
+
+  Ok(())
+}
+
+fn main() {
+  if let Err(e) = run() {
+    eprintln!("[farm] {e}");
+    std::process::exit(1);
+  }
+}
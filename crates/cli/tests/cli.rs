@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use farmfe_cli::{args::CliArgs, build, load_config, output_dir, resolve_config_path};
+use farmfe_compiler::Compiler;
+
+fn fixture_dir() -> PathBuf {
+  PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    .join("tests")
+    .join("fixtures")
+    .join("basic")
+}
+
+#[test]
+fn parse_args() {
+  let parse = |args: &[&str]| CliArgs::parse(args.iter().map(|a| a.to_string()));
+
+  assert_eq!(parse(&[]).unwrap(), CliArgs::default());
+  assert_eq!(
+    parse(&["build", "-c", "farm.config.toml", "--watch"]).unwrap(),
+    CliArgs {
+      config: Some("farm.config.toml".to_string()),
+      watch: true,
+      help: false,
+    }
+  );
+  assert_eq!(
+    parse(&["--config=farm.config.json"]).unwrap().config,
+    Some("farm.config.json".to_string())
+  );
+  assert!(parse(&["--config"]).is_err());
+  assert!(parse(&["--unknown"]).is_err());
+}
+
+#[test]
+fn load_json_and_toml_config() {
+  let dir = fixture_dir();
+  assert_eq!(
+    resolve_config_path(&dir, None).unwrap(),
+    dir.join("farm.config.json")
+  );
+
+  for file in ["farm.config.json", "farm.config.toml"] {
+    let config = load_config(&resolve_config_path(&dir, Some(file)).unwrap()).unwrap();
+
+    assert_eq!(PathBuf::from(&config.root), dir.join("src"));
+    assert_eq!(config.input["index"], "./index.ts");
+    assert_eq!(output_dir(&config), dir.join("src").join("../dist"));
+    assert!(PathBuf::from(&config.runtime.path).is_file());
+    assert!(PathBuf::from(&config.runtime.swc_helpers_path).is_dir());
+    assert!(!config.persistent_cache.enabled());
+  }
+}
+
+#[test]
+fn build_writes_resources() {
+  let mut config = load_config(&fixture_dir().join("farm.config.json")).unwrap();
+  let output = std::env::temp_dir().join("farmfe_cli_build_writes_resources");
+  std::fs::remove_dir_all(&output).ok();
+  config.output.path = output.to_string_lossy().to_string();
+
+  let compiler = Compiler::new(config, vec![]).unwrap();
+  build(&compiler).unwrap();
+
+  let index = std::fs::read_to_string(output.join("index.js")).unwrap();
+  assert!(index.contains("built by the native cli"));
+
+  std::fs::remove_dir_all(&output).ok();
+}
@@ -0,0 +1,3 @@
+export const message: string = 'built by the native cli';
+
+console.log(message);
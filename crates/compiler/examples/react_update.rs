@@ -57,7 +57,7 @@ fn main() {
           .to_string(),
         UpdateType::Updated,
       )],
-      || {},
+      |_| {},
       false,
       true,
     )
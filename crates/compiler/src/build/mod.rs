@@ -136,7 +136,7 @@ impl Compiler {
     if !errors.is_empty() {
       let mut error_messages = vec![];
       for error in errors {
-        error_messages.push(error.to_string());
+        error_messages.extend(error.to_messages());
       }
       let errors_json = json!(error_messages
         .iter()
@@ -171,8 +171,10 @@ impl Compiler {
   }
 
   pub(crate) fn handle_global_log(&self, errors: &mut Vec<CompilationError>) {
-    for err in self.context.log_store.lock().errors() {
-      errors.push(CompilationError::GenericError(err.to_string()));
+    let log_errors = self.context.log_store.lock().errors().clone();
+
+    if !log_errors.is_empty() {
+      errors.push(CompilationError::Diagnostics(log_errors));
     }
 
     if !self.context.log_store.lock().warnings().is_empty() {
@@ -213,6 +215,16 @@ impl Compiler {
     let mut resolve_result = match resolve(resolve_param, context, &hook_context) {
       Ok(resolved) => resolved,
       Err(e) => {
+        // the importer should be rebuilt when the missing file is added
+        if let Some(importer) = &resolve_param.importer {
+          context
+            .failed_resolve_importers
+            .lock()
+            .entry(importer.clone())
+            .or_default()
+            .insert(resolve_param.source.clone());
+        }
+
         return Err(e);
       }
     };
@@ -253,6 +265,7 @@ impl Compiler {
     if let Some(cached_module) =
       try_get_module_cache_by_timestamp(&module.id, module.last_update_timestamp, context)?
     {
+      context.cache_manager.stats.module_cache_hit();
       *module = cached_module.module;
       return Ok(CachedModule::dep_sources(cached_module.dependencies));
     }
@@ -303,10 +316,15 @@ impl Compiler {
     if let Some(cached_module) =
       try_get_module_cache_by_hash(&module.id, &module.content_hash, context)?
     {
+      context.cache_manager.stats.module_cache_hit();
       *module = cached_module.module;
       return Ok(CachedModule::dep_sources(cached_module.dependencies));
     }
 
+    if context.config.persistent_cache.enabled() {
+      context.cache_manager.stats.module_cache_miss();
+    }
+
     let deps = Self::build_module_after_transform(
       resolve_result,
       load_module_type,
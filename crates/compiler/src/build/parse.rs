@@ -29,6 +29,8 @@ pub fn parse(
         ),
       }),
     },
+    // diagnostics already carry the location of the error
+    Err(e @ CompilationError::Diagnostics(_)) => Err(e),
     Err(e) => Err(CompilationError::ParseError {
       resolved_path: parse_param.module_id.to_string(),
       msg: e.to_string(),
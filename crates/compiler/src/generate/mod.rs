@@ -1,6 +1,6 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Instant};
 
-use farmfe_core::{error::Result, plugin::PluginHookContext};
+use farmfe_core::{error::Result, plugin::PluginHookContext, stats::StageTimings};
 
 use crate::{
   generate::{
@@ -16,8 +16,8 @@ pub(crate) mod render_resource_pots;
 pub(crate) mod resource_cache;
 
 impl Compiler {
-  /// the generate stage
-  pub(crate) fn generate(&self) -> Result<()> {
+  /// the generate stage, time cost of each step is recorded in `timings`
+  pub(crate) fn generate(&self, timings: &mut StageTimings) -> Result<()> {
     self.context.plugin_driver.generate_start(&self.context)?;
 
     let hook_context = PluginHookContext {
@@ -25,15 +25,22 @@ impl Compiler {
       meta: HashMap::new(),
     };
 
+    let start = Instant::now();
     self.optimize_module_graph()?;
+    timings.optimize = elapsed_ms(start);
 
+    let start = Instant::now();
     partial_bundling(&self.context, &hook_context)?;
-
     self.process_resource_pot_map()?;
+    timings.partial_bundling = elapsed_ms(start);
 
+    let start = Instant::now();
     self.render_and_generate_resources(&hook_context)?;
+    timings.render = elapsed_ms(start);
 
+    let start = Instant::now();
     finalize_resources(&self.context)?;
+    timings.finalize = elapsed_ms(start);
 
     self.context.plugin_driver.generate_end(&self.context)
   }
@@ -71,3 +78,7 @@ impl Compiler {
     Ok(())
   }
 }
+
+pub(crate) fn elapsed_ms(start: Instant) -> f64 {
+  start.elapsed().as_secs_f64() * 1000.0
+}
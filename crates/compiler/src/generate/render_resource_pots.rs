@@ -1,21 +1,26 @@
-use std::sync::Arc;
+use std::{
+  collections::{HashMap, HashSet},
+  sync::Arc,
+};
 
 use farmfe_core::{
+  cache::resource_cache::resource_memory_store::CachedResourcePot,
   context::CompilationContext,
   error::{CompilationError, Result},
+  module::{module_group::ModuleGroupGraph, ModuleId},
   parking_lot::Mutex,
   plugin::{
     PluginGenerateResourcesHookResult, PluginHookContext, PluginRenderResourcePotHookParam,
   },
   rayon::prelude::{IntoParallelIterator, ParallelIterator},
   resource::{
-    resource_pot::{ResourcePot, ResourcePotInfo},
-    ResourceType,
+    resource_pot::{ResourcePot, ResourcePotId, ResourcePotInfo},
+    Resource, ResourceOrigin, ResourceType,
   },
 };
 use farmfe_toolkit::{
   common::append_source_map_comment,
-  fs::{transform_output_entry_filename, transform_output_filename},
+  fs::{has_content_hash, transform_output_entry_filename, transform_output_filename},
 };
 
 use crate::generate::resource_cache::{set_resource_cache, try_get_resource_cache};
@@ -32,13 +37,38 @@ pub fn render_resource_pots_and_generate_resources(
   let entries = context.module_graph.read().entries.clone();
 
   let mut resource_pots_need_render = vec![];
+  let mut cached_deferred_entries = vec![];
 
   for resource_pot in resource_pots {
-    let cached_resource_pot = try_get_resource_cache(resource_pot, context)?;
-
-    if let Some(cached_resource_pot) = cached_resource_pot {
+    if let Some(cached_resource_pot) = try_get_resource_cache(resource_pot, context)? {
+      context.cache_manager.stats.resource_cache_hit();
       let rendered_resource_pot_info = ResourcePotInfo::new(resource_pot);
 
+      // the cached entry resource is not renamed yet as its name depends on the names of other resources
+      if is_deferred_entry(resource_pot, context) {
+        let CachedResourcePot {
+          resources,
+          meta,
+          augment_resource_hash,
+          ..
+        } = cached_resource_pot;
+
+        resource_pot.meta = meta;
+        let hash_content = [
+          resources.resource.bytes.as_slice(),
+          augment_resource_hash.unwrap_or_default().as_bytes(),
+        ]
+        .concat();
+
+        cached_deferred_entries.push(RenderedResourcePot {
+          resource_pot,
+          res: resources,
+          hash_content,
+          resource_pot_info: rendered_resource_pot_info,
+        });
+        continue;
+      }
+
       let mut cached_resource = cached_resource_pot.resources;
       let cached_meta = cached_resource_pot.meta;
 
@@ -55,6 +85,10 @@ pub fn render_resource_pots_and_generate_resources(
         resources.lock().push(map);
       }
     } else {
+      if context.config.persistent_cache.enabled() {
+        context.cache_manager.stats.resource_cache_miss();
+      }
+
       resource_pots_need_render.push(resource_pot);
     }
   }
@@ -64,9 +98,9 @@ pub fn render_resource_pots_and_generate_resources(
     .render_start(&context.config, context)?;
 
   // Note: Plugins should not using context.resource_pot_map, as it may cause deadlock
-  resource_pots_need_render
+  let deferred_entries = resource_pots_need_render
     .into_par_iter()
-    .try_for_each(|resource_pot| {
+    .map(|resource_pot| {
       #[cfg(feature = "profile")]
       let id = farmfe_utils::transform_string_to_static_str(format!(
         "Render and generate resources for {:?}",
@@ -76,7 +110,7 @@ pub fn render_resource_pots_and_generate_resources(
       farmfe_core::puffin::profile_scope!(id);
 
       let mut resource_pot_info: Option<ResourcePotInfo> = None;
-      let (mut res, augment_resource_hash) = render_resource_pot_generate_resources(
+      let (res, augment_resource_hash) = render_resource_pot_generate_resources(
         resource_pot,
         context,
         hook_context,
@@ -84,81 +118,243 @@ pub fn render_resource_pots_and_generate_resources(
         &mut resource_pot_info,
       )?;
 
-      let r = &mut res.resource;
-      let resource_pot_info: ResourcePotInfo = resource_pot_info.unwrap();
+      let hash_content = [
+        res.resource.bytes.as_slice(),
+        augment_resource_hash
+          .as_deref()
+          .unwrap_or_default()
+          .as_bytes(),
+      ]
+      .concat();
+      let mut rendered = RenderedResourcePot {
+        resource_pot,
+        res,
+        hash_content,
+        resource_pot_info: resource_pot_info.unwrap(),
+      };
 
-      // ignore runtime resource
-      if !matches!(r.resource_type, ResourceType::Runtime) {
-        let content_with_extra_content_hash = &[
-          &r.bytes,
-          augment_resource_hash.unwrap_or_default().as_bytes(),
-        ]
-        .concat();
-        if let Some(name) = resource_pot.entry_module.as_ref() {
-          let entry_name = entries.get(name).unwrap();
-          r.name = transform_output_entry_filename(
-            context.config.output.entry_filename.clone(),
-            resource_pot.id.to_string().as_str(),
-            entry_name,
-            content_with_extra_content_hash,
-            &r.resource_type.to_ext(),
-          );
-        } else {
-          r.name = transform_output_filename(
-            context.config.output.filename.clone(),
-            &r.name,
-            content_with_extra_content_hash,
-            &r.resource_type.to_ext(),
+      if is_deferred_entry(rendered.resource_pot, context) {
+        // cache the entry before it's renamed, so only the renaming is applied on cache hit
+        if context.config.persistent_cache.enabled() {
+          set_resource_cache(
+            rendered.resource_pot,
+            &rendered.res,
+            augment_resource_hash,
+            context,
           );
         }
 
-        // resource_pot_info.file_name = r.name.clone();
+        return Ok(Some(rendered));
       }
 
-      let mut cached_result: PluginGenerateResourcesHookResult =
-        PluginGenerateResourcesHookResult {
-          resource: Default::default(),
-          source_map: None,
-        };
-      // if source map is generated, we need to update the resource name and the content of the resource
-      // to make sure the source map can be found.
-      if let Some(mut source_map) = res.source_map {
-        source_map.name = format!("{}.{}", r.name, source_map.resource_type.to_ext());
-        append_source_map_comment(&mut res.resource, &source_map, &context.config.sourcemap);
+      rename_resource(&mut rendered, &[], &entries, context);
+      resources
+        .lock()
+        .extend(finish_rendered_resource_pot(rendered, context));
 
-        if context.config.persistent_cache.enabled() {
-          cached_result.source_map = Some(source_map.clone());
-        }
+      Ok(None)
+    })
+    .collect::<Result<Vec<_>>>()?;
 
-        resource_pot.add_resource(source_map.name.clone());
+  let mut resources_map = context.resources_map.lock();
 
-        resources.lock().push(source_map);
-      }
+  for resource in resources.lock().drain(..) {
+    resources_map.insert(resource.name.clone(), resource);
+  }
 
-      if context.config.persistent_cache.enabled() {
-        cached_result.resource = res.resource.clone();
-        set_resource_cache(resource_pot, &cached_result, context);
-      }
+  let deferred_entries = deferred_entries
+    .into_iter()
+    .flatten()
+    .chain(cached_deferred_entries)
+    .collect::<Vec<_>>();
+
+  if !deferred_entries.is_empty() {
+    rename_entry_resources(deferred_entries, &mut resources_map, &entries, context);
+  }
+
+  Ok(())
+}
 
-      resource_pot.add_resource(res.resource.name.clone());
+/// Resource pots of the module groups that the entry module group depends on, statically or by dynamic imports.
+/// The entry resource loads their resources by name.
+pub(crate) fn entry_dependency_resource_pots(
+  entry: &ModuleId,
+  module_group_graph: &ModuleGroupGraph,
+) -> HashSet<ResourcePotId> {
+  let mut resource_pots = HashSet::new();
+
+  if module_group_graph.has(entry) {
+    module_group_graph.bfs(entry, &mut |module_group_id| {
+      let module_group = module_group_graph.module_group(module_group_id).unwrap();
+      resource_pots.extend(module_group.resource_pots().iter().cloned());
+    });
+  }
 
-      res.resource.info = Some(resource_pot_info);
+  resource_pots
+}
 
-      resources.lock().push(res.resource);
-      Ok(())
-    })?;
+struct RenderedResourcePot<'a> {
+  resource_pot: &'a mut ResourcePot,
+  res: PluginGenerateResourcesHookResult,
+  /// own content and the augmented hash of the resource pot
+  hash_content: Vec<u8>,
+  resource_pot_info: ResourcePotInfo,
+}
 
-  let mut resources_map: farmfe_core::parking_lot::lock_api::MutexGuard<
-    '_,
-    farmfe_core::parking_lot::RawMutex,
-    std::collections::HashMap<String, farmfe_core::resource::Resource>,
-  > = context.resources_map.lock();
+/// Entry resources that have `[contentHash]` in `output.entryFilename` are named after all other resources,
+/// see [rename_entry_resources]
+fn is_deferred_entry(resource_pot: &ResourcePot, context: &Arc<CompilationContext>) -> bool {
+  resource_pot.entry_module.is_some() && has_content_hash(&context.config.output.entry_filename)
+}
 
-  for resource in resources.lock().drain(..) {
-    resources_map.insert(resource.name.clone(), resource);
+/// Name the entry resources in dependency order. Besides its own content, the hash of an entry resource covers
+/// the final names of the resources it loads and the runtime that is injected into it,
+/// so it changes when any of them changes while the hash of other resources only depends on their own content.
+fn rename_entry_resources(
+  mut deferred_entries: Vec<RenderedResourcePot>,
+  resources_map: &mut HashMap<String, Resource>,
+  entries: &HashMap<ModuleId, String>,
+  context: &Arc<CompilationContext>,
+) {
+  let module_group_graph = context.module_group_graph.read();
+  let runtime_content = resources_map
+    .values()
+    .filter(|r| matches!(r.resource_type, ResourceType::Runtime))
+    .map(|r| r.bytes.as_slice())
+    .collect::<Vec<_>>()
+    .concat();
+
+  // sort to make sure the entries in a cycle are named in the same order across builds
+  deferred_entries.sort_by(|a, b| a.resource_pot.id.cmp(&b.resource_pot.id));
+  let mut pending = deferred_entries
+    .into_iter()
+    .map(|rendered| {
+      let entry = rendered.resource_pot.entry_module.as_ref().unwrap();
+      let mut deps = entry_dependency_resource_pots(entry, &module_group_graph);
+      deps.remove(&rendered.resource_pot.id);
+
+      (rendered, deps)
+    })
+    .collect::<Vec<_>>();
+
+  while !pending.is_empty() {
+    let pending_ids = pending
+      .iter()
+      .map(|(rendered, _)| rendered.resource_pot.id.clone())
+      .collect::<HashSet<_>>();
+    // entries that depend on each other fall back to the names that are known
+    let index = pending
+      .iter()
+      .position(|(_, deps)| deps.is_disjoint(&pending_ids))
+      .unwrap_or(0);
+    let (mut rendered, deps) = pending.remove(index);
+
+    let mut dep_resource_names = resources_map
+      .values()
+      .filter(|r| !matches!(r.resource_type, ResourceType::SourceMap(_)))
+      .filter(|r| matches!(&r.origin, ResourceOrigin::ResourcePot(id) if deps.contains(id)))
+      .map(|r| r.name.as_str())
+      .collect::<Vec<_>>();
+    dep_resource_names.sort();
+
+    let extra_hash_content = [
+      dep_resource_names.join(",").as_bytes(),
+      runtime_content.as_slice(),
+    ]
+    .concat();
+    rename_resource(&mut rendered, &extra_hash_content, entries, context);
+
+    for resource in finish_rendered_resource_pot(rendered, context) {
+      resources_map.insert(resource.name.clone(), resource);
+    }
   }
+}
 
-  Ok(())
+fn rename_resource(
+  rendered: &mut RenderedResourcePot,
+  extra_hash_content: &[u8],
+  entries: &HashMap<ModuleId, String>,
+  context: &Arc<CompilationContext>,
+) {
+  let r = &mut rendered.res.resource;
+
+  // ignore runtime resource
+  if matches!(r.resource_type, ResourceType::Runtime) {
+    return;
+  }
+
+  let content_with_extra_content_hash =
+    &[rendered.hash_content.as_slice(), extra_hash_content].concat();
+
+  if let Some(name) = rendered.resource_pot.entry_module.as_ref() {
+    let entry_name = entries.get(name).unwrap();
+    r.name = transform_output_entry_filename(
+      context.config.output.entry_filename.clone(),
+      rendered.resource_pot.id.to_string().as_str(),
+      entry_name,
+      content_with_extra_content_hash,
+      &r.resource_type.to_ext(),
+    );
+  } else {
+    r.name = transform_output_filename(
+      context.config.output.filename.clone(),
+      &r.name,
+      content_with_extra_content_hash,
+      &r.resource_type.to_ext(),
+    );
+  }
+}
+
+/// Name the source map after the resource, write the resource cache and record the resources to the resource pot
+fn finish_rendered_resource_pot(
+  rendered: RenderedResourcePot,
+  context: &Arc<CompilationContext>,
+) -> Vec<Resource> {
+  let RenderedResourcePot {
+    resource_pot,
+    mut res,
+    resource_pot_info,
+    ..
+  } = rendered;
+  let mut resources = vec![];
+
+  let mut cached_result: PluginGenerateResourcesHookResult = PluginGenerateResourcesHookResult {
+    resource: Default::default(),
+    source_map: None,
+  };
+  let cache_enabled =
+    context.config.persistent_cache.enabled() && !is_deferred_entry(resource_pot, context);
+  // if source map is generated, we need to update the resource name and the content of the resource
+  // to make sure the source map can be found.
+  if let Some(mut source_map) = res.source_map {
+    source_map.name = format!(
+      "{}.{}",
+      res.resource.name,
+      source_map.resource_type.to_ext()
+    );
+    append_source_map_comment(&mut res.resource, &source_map, &context.config.sourcemap);
+
+    if cache_enabled {
+      cached_result.source_map = Some(source_map.clone());
+    }
+
+    resource_pot.add_resource(source_map.name.clone());
+
+    resources.push(source_map);
+  }
+
+  if cache_enabled {
+    cached_result.resource = res.resource.clone();
+    set_resource_cache(resource_pot, &cached_result, None, context);
+  }
+
+  resource_pot.add_resource(res.resource.name.clone());
+
+  res.resource.info = Some(resource_pot_info);
+
+  resources.push(res.resource);
+
+  resources
 }
 
 pub fn render_resource_pot_generate_resources(
@@ -74,6 +74,7 @@ pub fn try_get_resource_cache(
 pub fn set_resource_cache(
   resource_pot: &ResourcePot,
   resource: &PluginGenerateResourcesHookResult,
+  augment_resource_hash: Option<String>,
   context: &Arc<CompilationContext>,
 ) {
   let cache_key = get_resource_cache_key(resource_pot, context);
@@ -84,6 +85,7 @@ pub fn set_resource_cache(
       resources: resource.clone(),
       meta: resource_pot.meta.clone(),
       hash: cache_key,
+      augment_resource_hash,
     },
   );
 }
@@ -3,8 +3,9 @@
 #![allow(clippy::ptr_arg)]
 #![allow(clippy::too_many_arguments)]
 #![feature(box_patterns)]
+#![feature(path_file_prefix)]
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use farmfe_core::{
   config::{Config, Mode},
@@ -16,6 +17,8 @@ use farmfe_core::{
   stats::Stats,
 };
 
+use crate::generate::elapsed_ms;
+
 pub use farmfe_plugin_css::FARM_CSS_MODULES_SUFFIX;
 pub use farmfe_plugin_lazy_compilation::DYNAMIC_VIRTUAL_SUFFIX;
 pub use farmfe_plugin_runtime::RUNTIME_SUFFIX;
@@ -23,6 +26,9 @@ pub use farmfe_plugin_runtime::RUNTIME_SUFFIX;
 pub mod build;
 pub mod generate;
 pub mod update;
+#[cfg(feature = "file_watcher")]
+pub mod watcher;
+pub mod worker;
 
 pub struct Compiler {
   context: Arc<CompilationContext>,
@@ -31,11 +37,17 @@ pub struct Compiler {
 
 impl Compiler {
   /// The params are [farmfe_core::config::Config] and dynamic load rust plugins and js plugins [farmfe_core::plugin::Plugin]
-  pub fn new(config: Config, mut plugin_adapters: Vec<Arc<dyn Plugin>>) -> Result<Self> {
+  pub fn new(mut config: Config, mut plugin_adapters: Vec<Arc<dyn Plugin>>) -> Result<Self> {
+    // derive script target, polyfill and css prefixer targets from the shared browserslist targets
+    farmfe_toolkit::targets::normalize_targets(&mut config)?;
+
     let mut plugins = vec![
       Arc::new(farmfe_plugin_runtime::FarmPluginRuntime::new(&config)) as _,
       // register internal core plugins
-      Arc::new(farmfe_plugin_script::FarmPluginScript::new(&config)) as _,
+      Arc::new(
+        farmfe_plugin_script::FarmPluginScript::new(&config)
+          .with_worker_compiler(worker::create_worker_compiler(plugin_adapters.clone())),
+      ) as _,
       Arc::new(farmfe_plugin_partial_bundling::FarmPluginPartialBundling::new(&config)) as _,
       Arc::new(farmfe_plugin_html::FarmPluginHtml::new(&config)) as _,
       Arc::new(farmfe_plugin_html::FarmPluginTransformHtml::new(&config)) as _,
@@ -67,6 +79,14 @@ impl Compiler {
       plugins.push(Arc::new(farmfe_plugin_minify::FarmPluginMinify::new(&config)) as _);
     }
 
+    if config.output.manifest.enabled() {
+      plugins.push(Arc::new(farmfe_plugin_manifest::FarmPluginManifest::new(&config)) as _);
+    }
+
+    if config.output.compress.enabled() {
+      plugins.push(Arc::new(farmfe_plugin_compress::FarmPluginCompress::new(&config)) as _);
+    }
+
     if config.preset_env.enabled() {
       plugins.push(Arc::new(farmfe_plugin_polyfill::FarmPluginPolyfill::new(&config)) as _);
     }
@@ -123,23 +143,29 @@ impl Compiler {
         .plugin_cache_loaded(&self.context)?;
     }
 
+    self.context.cache_manager.stats.reset();
+    let mut stats = Stats::default();
+
     // triggering build stage
     {
       #[cfg(feature = "profile")]
       farmfe_core::puffin::profile_scope!("Build Stage");
+      let start = Instant::now();
       self.build()?;
+      stats.timings.build = elapsed_ms(start);
     }
 
     {
       #[cfg(feature = "profile")]
       farmfe_core::puffin::profile_scope!("Generate Stage");
-      self.generate()?;
+      self.generate(&mut stats.timings)?;
     }
 
-    self
-      .context
-      .plugin_driver
-      .finish(&Stats {}, &self.context)?;
+    stats.set_module_counts(&self.context.module_graph.read());
+    stats.set_resources(self.context.resources_map.lock().values());
+    stats.cache = self.context.cache_manager.stats.snapshot();
+
+    self.context.plugin_driver.finish(&stats, &self.context)?;
 
     if self.context.config.persistent_cache.enabled() {
       self
@@ -1,8 +1,12 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+  collections::HashMap,
+  path::{Component, Path, PathBuf},
+  sync::Arc,
+};
 
 use farmfe_core::{
   context::CompilationContext,
-  module::ModuleId,
+  module::{module_graph::ModuleGraph, ModuleId},
   plugin::{PluginUpdateModulesHookParams, UpdateResult, UpdateType},
 };
 use farmfe_utils::relative;
@@ -20,6 +24,7 @@ pub fn handle_update_modules(
 
   let paths = plugin_update_modules_hook_params.paths;
   let mut module_graph = context.module_graph.write();
+  let paths = updates_of_added_and_removed_paths(paths, &module_graph, context);
 
   let mut additional_paths = vec![];
 
@@ -112,3 +117,170 @@ pub fn handle_update_modules(
       .collect(),
   )
 }
+
+/// Added and removed files are not built directly, the modules affected by them are updated instead:
+/// * an added file may be the missing dependency of the importers that failed to resolve it before
+/// * the importers of a removed file are rebuilt, so that the missing dependency is reported or dropped
+///
+/// Files that are still in the module graph are updated, e.g. a file that is removed and added back when saving
+fn updates_of_added_and_removed_paths(
+  paths: Vec<(String, UpdateType)>,
+  module_graph: &ModuleGraph,
+  context: &Arc<CompilationContext>,
+) -> Vec<(String, UpdateType)> {
+  let root = &context.config.root;
+
+  // the resolved results may point to the removed files or miss the added files
+  if paths.iter().any(|(_, ty)| *ty != UpdateType::Updated) {
+    context.resolve_cache.lock().clear();
+  }
+
+  let removed_paths = paths
+    .iter()
+    .filter(|(_, ty)| *ty == UpdateType::Removed)
+    .map(|(p, _)| p.clone())
+    .collect::<Vec<_>>();
+  let mut updated_paths: Vec<String> = vec![];
+  let mut push = |path: String| {
+    if !updated_paths.contains(&path) {
+      updated_paths.push(path);
+    }
+  };
+
+  for (path, update_type) in paths {
+    let id = ModuleId::from_resolved_path_with_query(&path, root);
+    // modules with queries of the file, e.g. `index.vue?vue&type=style`
+    let mut module_ids = module_graph.module_ids_by_file(&id);
+
+    if module_graph.has_module(&id) {
+      module_ids.push(id);
+    }
+
+    match update_type {
+      UpdateType::Updated => push(path),
+      UpdateType::Added => {
+        for importer in take_importers_missing_added_file(&path, context) {
+          push(importer.resolved_path_with_query(root));
+        }
+
+        if !module_ids.is_empty() {
+          push(path);
+        }
+      }
+      UpdateType::Removed => {
+        if module_ids.is_empty() {
+          continue;
+        }
+
+        let dependents = module_ids
+          .iter()
+          .flat_map(|id| module_graph.dependents_ids(id))
+          .map(|id| id.resolved_path_with_query(root))
+          .filter(|p| !removed_paths.contains(p))
+          .collect::<Vec<_>>();
+
+        // a removed entry is updated to report that it can not be loaded
+        if dependents.is_empty() {
+          push(path);
+        }
+
+        dependents.into_iter().for_each(&mut push);
+      }
+    }
+  }
+
+  updated_paths
+    .into_iter()
+    .map(|p| (p, UpdateType::Updated))
+    .collect()
+}
+
+/// Whether the added file may be a dependency that some importers failed to resolve, see [take_importers_missing_added_file]
+pub fn is_missing_dependency(path: &str, context: &Arc<CompilationContext>) -> bool {
+  let root = &context.config.root;
+
+  context
+    .failed_resolve_importers
+    .lock()
+    .iter()
+    .any(|(importer, sources)| {
+      sources
+        .iter()
+        .any(|source| added_file_matches_source(path, importer, source, root))
+    })
+}
+
+/// Remove and return the importers that failed to resolve a dependency the added file may be.
+/// Relative and absolute sources are matched against the path of the file, other sources like
+/// aliases and packages can not be matched without resolving them, so any added file matches them
+fn take_importers_missing_added_file(
+  path: &str,
+  context: &Arc<CompilationContext>,
+) -> Vec<ModuleId> {
+  let root = &context.config.root;
+  let mut failed_resolve_importers = context.failed_resolve_importers.lock();
+  let importers = failed_resolve_importers
+    .iter()
+    .filter(|(importer, sources)| {
+      sources
+        .iter()
+        .any(|source| added_file_matches_source(path, importer, source, root))
+    })
+    .map(|(importer, _)| importer.clone())
+    .collect::<Vec<_>>();
+
+  for importer in &importers {
+    failed_resolve_importers.remove(importer);
+  }
+
+  importers
+}
+
+fn added_file_matches_source(path: &str, importer: &ModuleId, source: &str, root: &str) -> bool {
+  let source = source.split(['?', '#']).next().unwrap_or(source);
+  let expected_paths = if source.starts_with('.') {
+    let importer_path = PathBuf::from(importer.resolved_path(root));
+
+    match importer_path.parent() {
+      Some(dir) => vec![dir.join(source)],
+      None => return false,
+    }
+  } else if Path::new(source).is_absolute() {
+    // an absolute source may be relative to the root, e.g. `/src/dep`
+    vec![
+      PathBuf::from(source),
+      Path::new(root).join(source.trim_start_matches('/')),
+    ]
+  } else {
+    return true;
+  };
+
+  let path = Path::new(path);
+  let is_index = path.file_stem().is_some_and(|stem| stem == "index");
+
+  // `./dep` may be the file itself, `dep.ts` or `dep/index.ts`
+  expected_paths
+    .iter()
+    .map(|p| normalize_path(p))
+    .any(|expected| {
+      path == expected
+        || path.with_extension("") == expected
+        || (is_index && path.parent() == Some(expected.as_path()))
+    })
+}
+
+fn normalize_path(path: &Path) -> PathBuf {
+  let mut normalized = PathBuf::new();
+
+  for component in path.components() {
+    match component {
+      Component::CurDir => {}
+      Component::ParentDir => {
+        normalized.pop();
+      }
+      c => normalized.push(c),
+    }
+  }
+
+  normalized
+}
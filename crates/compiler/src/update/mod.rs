@@ -24,6 +24,7 @@ use crate::{
     ResolvedModuleInfo,
   },
   generate::finalize_resources::finalize_resources,
+  worker::is_worker_update,
   Compiler,
 };
 use farmfe_core::error::Result;
@@ -35,6 +36,7 @@ use self::{
   patch_module_group_graph::patch_module_group_graph,
   regenerate_resources::{
     regenerate_resources_for_affected_module_groups, render_and_generate_update_resource,
+    ClearedResources,
   },
   update_context::UpdateContext,
 };
@@ -47,6 +49,8 @@ mod patch_module_group_graph;
 mod regenerate_resources;
 mod update_context;
 
+type DynamicResourcesMap = HashMap<ModuleId, Vec<(String, ResourceType)>>;
+
 enum ResolveModuleResult {
   Cached(ModuleId),
   /// This module is already in previous module graph before the update, and we met it again when resolving dependencies
@@ -71,6 +75,12 @@ struct HandleUpdateDependenciesParams {
 }
 
 impl Compiler {
+  /// Whether the added file may be a dependency that some modules failed to resolve, so that the
+  /// file watchers only update the compiler when the added file can fix the failed resolves
+  pub fn is_missing_dependency(&self, path: &str) -> bool {
+    handle_update_modules::is_missing_dependency(path, &self.context)
+  }
+
   pub fn update<F>(
     &self,
     paths: Vec<(String, UpdateType)>,
@@ -79,12 +89,20 @@ impl Compiler {
     generate_update_resource: bool,
   ) -> Result<UpdateResult>
   where
-    F: FnOnce() + Send + Sync + 'static,
+    F: FnOnce(Result<()>) + Send + Sync + 'static,
   {
     // mark the compilation as update
     self.context.set_update();
     let (err_sender, err_receiver) = Self::create_thread_channel();
     let update_context = Arc::new(UpdateContext::new());
+    // web workers do not support hmr, reload the page when any module of a worker is updated
+    let is_worker_update = is_worker_update(
+      &paths
+        .iter()
+        .map(|(path, _)| ModuleId::new(path, "", &self.context.config.root))
+        .collect::<Vec<_>>(),
+      &self.context,
+    );
 
     let watch_graph = self.context.watch_graph.read();
     let module_graph = self.context.module_graph.read();
@@ -137,42 +155,28 @@ impl Compiler {
     self.context.clear_log_store();
     let paths = handle_update_modules(paths, &self.context, &mut update_result)?;
 
-    for (path, update_type) in paths.clone() {
-      match update_type {
-        UpdateType::Added => {
-          return Err(farmfe_core::error::CompilationError::GenericError(
-            "Added is not supported yet".to_string(),
-          ));
-        }
-
-        UpdateType::Updated => {
-          let resolve_param = PluginResolveHookParam {
-            kind: ResolveKind::HmrUpdate,
-            source: path,
-            importer: None,
-          };
+    // added and removed paths have been turned into updates of the affected modules
+    for (path, _) in paths.clone() {
+      let resolve_param = PluginResolveHookParam {
+        kind: ResolveKind::HmrUpdate,
+        source: path,
+        importer: None,
+      };
 
-          let params = BuildUpdateModuleGraphThreadedParams {
-            build_module_graph_threaded_params: BuildModuleGraphThreadedParams {
-              resolve_param,
-              context: self.context.clone(),
-              err_sender: err_sender.clone(),
-              thread_pool: self.thread_pool.clone(),
-              order: 0,
-              cached_dependency: None,
-            },
-            order: None,
-            update_context: update_context.clone(),
-          };
+      let params = BuildUpdateModuleGraphThreadedParams {
+        build_module_graph_threaded_params: BuildModuleGraphThreadedParams {
+          resolve_param,
+          context: self.context.clone(),
+          err_sender: err_sender.clone(),
+          thread_pool: self.thread_pool.clone(),
+          order: 0,
+          cached_dependency: None,
+        },
+        order: None,
+        update_context: update_context.clone(),
+      };
 
-          Self::update_module_graph_threaded(params);
-        }
-        UpdateType::Removed => {
-          return Err(farmfe_core::error::CompilationError::GenericError(
-            "Removed is not supported yet".to_string(),
-          ));
-        }
-      }
+      Self::update_module_graph_threaded(params);
     }
 
     drop(err_sender);
@@ -186,15 +190,7 @@ impl Compiler {
     self.handle_global_log(&mut errors);
 
     if !errors.is_empty() {
-      let mut error_messages = vec![];
-      for error in errors {
-        error_messages.push(error.to_string());
-      }
-      let errors_json = json!(error_messages
-        .iter()
-        .map(|e| e.to_string())
-        .collect::<Vec<_>>());
-      return Err(CompilationError::GenericError(errors_json.to_string()));
+      return Err(update_error(errors));
     }
 
     let previous_module_groups = {
@@ -230,7 +226,7 @@ impl Compiler {
       removed_modules,
       callback,
       sync,
-    );
+    )?;
 
     // after update_module, diff old_resource and new_resource
     {
@@ -256,11 +252,12 @@ impl Compiler {
 
     // If the module type is not script, we should skip render and generate update resource.
     // and just return `window.location.reload()`
-    let should_reload_page = updated_module_ids.iter().any(|id| {
-      let module_graph = self.context.module_graph.read();
-      let module = module_graph.module(id).unwrap();
-      !module.module_type.is_script()
-    });
+    let should_reload_page = is_worker_update
+      || updated_module_ids.iter().any(|id| {
+        let module_graph = self.context.module_graph.read();
+        let module = module_graph.module(id).unwrap();
+        !module.module_type.is_script()
+      });
     let (immutable_resources, mutable_resources) = if should_reload_page {
       ("window.location.reload()".to_string(), "{}".to_string())
     } else if generate_update_resource {
@@ -539,6 +536,9 @@ impl Compiler {
     )
   }
 
+  /// Regenerate the resources of the affected module groups. When `sync` is false and no module group is added,
+  /// the resources are regenerated in a separate thread and the errors are passed to `callback`,
+  /// otherwise the errors are returned and `callback` is only called when the regeneration succeeds
   fn regenerate_resources<F>(
     &self,
     affected_module_groups: HashSet<ModuleGroupId>,
@@ -548,9 +548,9 @@ impl Compiler {
     removed_modules: HashMap<ModuleId, Module>,
     callback: F,
     sync: bool,
-  ) -> Option<HashMap<ModuleId, Vec<(String, ResourceType)>>>
+  ) -> Result<Option<DynamicResourcesMap>>
   where
-    F: FnOnce() + Send + Sync + 'static,
+    F: FnOnce(Result<()>) + Send + Sync + 'static,
   {
     let mut dynamic_resources_map = None;
     let cloned_updated_module_ids = updated_module_ids.clone();
@@ -562,65 +562,95 @@ impl Compiler {
         .iter()
         .any(|ag| !previous_module_groups.contains(ag))
     {
-      regenerate_resources_for_affected_module_groups(
+      regenerate_and_finalize_resources(
         affected_module_groups,
         diff_result,
         &cloned_updated_module_ids,
         &removed_modules,
         &cloned_context,
-      )
-      .unwrap();
+      )?;
+
+      {
+        let module_group_graph = self.context.module_group_graph.read();
+        let resource_pot_map = self.context.resource_pot_map.read();
+        let resources_map = self.context.resources_map.lock();
+        let module_graph = self.context.module_graph.read();
+
+        let mut dynamic_resources = HashMap::new();
+
+        for entry_id in module_graph.entries.keys() {
+          dynamic_resources.extend(get_dynamic_resources_map(
+            &module_group_graph,
+            entry_id,
+            &resource_pot_map,
+            &resources_map,
+          ));
+        }
 
-      finalize_resources(&cloned_context).unwrap();
-      let module_group_graph = self.context.module_group_graph.read();
-      let resource_pot_map = self.context.resource_pot_map.read();
-      let resources_map = self.context.resources_map.lock();
-      let module_graph = self.context.module_graph.read();
-
-      let mut dynamic_resources = HashMap::new();
-
-      for entry_id in module_graph.entries.keys() {
-        dynamic_resources.extend(get_dynamic_resources_map(
-          &module_group_graph,
-          entry_id,
-          &resource_pot_map,
-          &resources_map,
-        ));
+        dynamic_resources_map = Some(dynamic_resources);
       }
 
-      dynamic_resources_map = Some(dynamic_resources);
-      callback();
-      self
-        .context
-        .plugin_driver
-        .update_finished(&self.context)
-        .unwrap();
+      callback(Ok(()));
+      self.context.plugin_driver.update_finished(&self.context)?;
     } else {
       std::thread::spawn(move || {
-        if let Err(e) = regenerate_resources_for_affected_module_groups(
+        let result = regenerate_and_finalize_resources(
           affected_module_groups,
           diff_result,
           &cloned_updated_module_ids,
           &removed_modules,
           &cloned_context,
-        ) {
-          println!("Failed to regenerate resources: {}", e);
-          println!("modules to regenerate: {:?}", cloned_updated_module_ids);
-        }
+        )
+        .and_then(|_| {
+          cloned_context
+            .plugin_driver
+            .update_finished(&cloned_context)
+        });
 
-        finalize_resources(&cloned_context).unwrap();
-        callback();
-        cloned_context
-          .plugin_driver
-          .update_finished(&cloned_context)
-          .unwrap();
+        callback(result);
       });
     }
 
-    dynamic_resources_map
+    Ok(dynamic_resources_map)
   }
 }
 
+/// Regenerate and finalize the resources of the affected module groups. If anything fails, the resources of the
+/// last successful compilation are restored, so that they can still be served until the error is fixed
+fn regenerate_and_finalize_resources(
+  affected_module_groups: HashSet<ModuleGroupId>,
+  diff_result: DiffResult,
+  updated_module_ids: &Vec<ModuleId>,
+  removed_modules: &HashMap<ModuleId, Module>,
+  context: &Arc<CompilationContext>,
+) -> Result<()> {
+  let mut cleared_resources = ClearedResources::default();
+
+  regenerate_resources_for_affected_module_groups(
+    affected_module_groups,
+    diff_result,
+    updated_module_ids,
+    removed_modules,
+    &mut cleared_resources,
+    context,
+  )
+  .and_then(|_| finalize_resources(context))
+  .map_err(|e| {
+    cleared_resources.restore(context);
+    update_error(vec![e])
+  })
+}
+
+/// Merge the errors of an update into a single error whose message is a json array of the error messages
+fn update_error(errors: Vec<CompilationError>) -> CompilationError {
+  let error_messages = errors
+    .iter()
+    .flat_map(|e| e.to_messages())
+    .collect::<Vec<_>>();
+
+  CompilationError::GenericError(json!(error_messages).to_string())
+}
+
 /// Similar to [crate::build::resolve_module], but the resolved module may be existed in both context and update_context
 fn resolve_module(
   resolve_param: &PluginResolveHookParam,
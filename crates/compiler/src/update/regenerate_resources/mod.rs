@@ -7,19 +7,23 @@ use farmfe_core::{
   enhanced_magic_string::types::SourceMapOptions,
   error::CompilationError,
   module::{module_graph::ModuleGraph, module_group::ModuleGroupId, Module, ModuleId},
-  resource::resource_pot::{ResourcePot, ResourcePotMetaData, ResourcePotType},
+  resource::{
+    resource_pot::{ResourcePot, ResourcePotId, ResourcePotMetaData, ResourcePotType},
+    Resource,
+  },
 };
 
 use farmfe_plugin_runtime::render_resource_pot::{
   resource_pot_to_runtime_object, RenderedJsResourcePot,
 };
 use farmfe_plugin_runtime::ASYNC_MODULES;
-use farmfe_toolkit::hash::base64_encode;
+use farmfe_toolkit::{fs::has_content_hash, hash::base64_encode};
 use farmfe_utils::{hash::sha256, relative};
 
 use crate::{
   generate::render_resource_pots::{
-    render_resource_pot_generate_resources, render_resource_pots_and_generate_resources,
+    entry_dependency_resource_pots, render_resource_pot_generate_resources,
+    render_resource_pots_and_generate_resources,
   },
   write_cache_async,
 };
@@ -206,11 +210,78 @@ pub fn render_and_generate_update_resource(
   Ok((immutable_update_resource, mutable_update_resource))
 }
 
+/// Resources of the resource pots that are regenerated by an update, used to restore them when the regeneration fails
+#[derive(Default)]
+pub struct ClearedResources {
+  resource_pots: HashMap<ResourcePotId, Vec<Resource>>,
+}
+
+impl ClearedResources {
+  /// Remove the resources of the resource pot from `resources_map` and record them
+  fn clear(
+    &mut self,
+    resource_pot: &mut ResourcePot,
+    resources_map: &mut HashMap<String, Resource>,
+  ) {
+    let resources = resource_pot
+      .resources()
+      .into_iter()
+      .filter_map(|name| resources_map.remove(name));
+
+    self
+      .resource_pots
+      .entry(resource_pot.id.clone())
+      .or_default()
+      .extend(resources);
+    resource_pot.clear_resources();
+  }
+
+  /// Record the resources of the resource pot that is regenerated without being cleared
+  fn record(&mut self, resource_pot: &ResourcePot, resources_map: &HashMap<String, Resource>) {
+    if !self.resource_pots.contains_key(&resource_pot.id) {
+      let resources = resource_pot
+        .resources()
+        .into_iter()
+        .filter_map(|name| resources_map.get(name).cloned())
+        .collect();
+
+      self
+        .resource_pots
+        .insert(resource_pot.id.clone(), resources);
+    }
+  }
+
+  /// Drop the resources that are regenerated before the failure and restore the cleared resources
+  pub fn restore(self, context: &Arc<CompilationContext>) {
+    let mut resource_pot_map = context.resource_pot_map.write();
+    let mut resources_map = context.resources_map.lock();
+
+    for (resource_pot_id, resources) in self.resource_pots {
+      if let Some(resource_pot) = resource_pot_map.resource_pot_mut(&resource_pot_id) {
+        for name in resource_pot.resources() {
+          resources_map.remove(name);
+        }
+
+        resource_pot.clear_resources();
+
+        for resource in &resources {
+          resource_pot.add_resource(resource.name.clone());
+        }
+      }
+
+      for resource in resources {
+        resources_map.insert(resource.name.clone(), resource);
+      }
+    }
+  }
+}
+
 pub fn regenerate_resources_for_affected_module_groups(
   affected_module_groups: HashSet<ModuleGroupId>,
   diff_result: DiffResult,
   updated_module_ids: &Vec<ModuleId>,
   removed_modules: &HashMap<ModuleId, Module>,
+  cleared_resources: &mut ClearedResources,
   context: &Arc<CompilationContext>,
 ) -> farmfe_core::error::Result<()> {
   // if there are deps changes, update execution order
@@ -252,11 +323,35 @@ pub fn regenerate_resources_for_affected_module_groups(
     let mut resource_maps = context.resources_map.lock();
     let resource_pot = resource_pot_map.resource_pot_mut(resource_pot_id).unwrap();
 
-    for resource in resource_pot.resources() {
-      resource_maps.remove(resource);
-    }
+    cleared_resources.clear(resource_pot, &mut resource_maps);
+  }
 
-    resource_pot.clear_resources();
+  // the hash of an entry resource covers the names of the resources it loads, rerender the entries
+  // that depend on the affected resource pots so that their names are updated too
+  if has_content_hash(&context.config.output.entry_filename) {
+    let module_group_graph = context.module_group_graph.read();
+    let dependent_entries = resource_pot_map
+      .resource_pots()
+      .into_iter()
+      .filter(|rp| !affected_resource_pots_ids.contains(&rp.id))
+      .filter(|rp| {
+        rp.entry_module.as_ref().is_some_and(|entry| {
+          entry_dependency_resource_pots(entry, &module_group_graph)
+            .iter()
+            .any(|id| affected_resource_pots_ids.contains(id))
+        })
+      })
+      .map(|rp| rp.id.clone())
+      .collect::<Vec<_>>();
+
+    let mut resource_maps = context.resources_map.lock();
+
+    for resource_pot_id in dependent_entries {
+      let resource_pot = resource_pot_map.resource_pot_mut(&resource_pot_id).unwrap();
+
+      cleared_resources.clear(resource_pot, &mut resource_maps);
+      affected_resource_pots_ids.push(resource_pot_id);
+    }
   }
 
   let mut resource_pots = resource_pot_map
@@ -265,6 +360,15 @@ pub fn regenerate_resources_for_affected_module_groups(
     .filter(|rp| affected_resource_pots_ids.contains(&rp.id))
     .collect::<Vec<&mut ResourcePot>>();
 
+  // the resource pots of the diff are regenerated too, restore them as well when the regeneration fails
+  {
+    let resource_maps = context.resources_map.lock();
+
+    for resource_pot in &resource_pots {
+      cleared_resources.record(resource_pot, &resource_maps);
+    }
+  }
+
   drop(module_graph);
 
   // call process_resource_pot_map hook
@@ -0,0 +1,163 @@
+//! The file watcher shared by the node binding and the native cli to trigger [crate::Compiler::update].
+use std::path::{Path, PathBuf};
+
+use farmfe_core::plugin::UpdateType;
+use notify::{
+  event::{AccessKind, CreateKind, ModifyKind, RemoveKind},
+  EventKind, RecommendedWatcher, Watcher,
+};
+
+pub struct FsWatcher {
+  watcher: notify::RecommendedWatcher,
+  watched_paths: Vec<PathBuf>,
+}
+
+impl FsWatcher {
+  /// The callback receives the changed paths with the type of the change. Files that are created or
+  /// removed under the watched directories are reported as [UpdateType::Added] and [UpdateType::Removed]
+  pub fn new<F>(mut callback: F) -> notify::Result<Self>
+  where
+    F: FnMut(Vec<(String, UpdateType)>) + Send + Sync + 'static,
+  {
+    let watcher = RecommendedWatcher::new(
+      move |result: std::result::Result<notify::Event, notify::Error>| {
+        let event = result.unwrap();
+        let get_paths = |update_type: UpdateType| {
+          event
+            .paths
+            .iter()
+            .map(|p| (p.to_str().unwrap().to_string(), update_type.clone()))
+            .collect::<Vec<_>>()
+        };
+        // println!("{:?} {:?}", event.kind, event);
+        match event.kind {
+          EventKind::Create(CreateKind::Folder) | EventKind::Remove(RemoveKind::Folder) => {}
+          EventKind::Create(_) => callback(get_paths(UpdateType::Added)),
+          EventKind::Remove(_) => callback(get_paths(UpdateType::Removed)),
+          // a renamed file is removed from its old path and added to the new path
+          EventKind::Modify(ModifyKind::Name(_)) => callback(
+            event
+              .paths
+              .iter()
+              .map(|p| {
+                let update_type = if p.exists() {
+                  UpdateType::Added
+                } else {
+                  UpdateType::Removed
+                };
+                (p.to_str().unwrap().to_string(), update_type)
+              })
+              .collect(),
+          ),
+          _ => {
+            if cfg!(target_os = "macos") {
+              if matches!(event.kind, EventKind::Modify(ModifyKind::Data(_))) {
+                callback(get_paths(UpdateType::Updated));
+              }
+            } else if cfg!(target_os = "linux") {
+              // a close event is always followed by a modify event
+              if matches!(event.kind, EventKind::Access(AccessKind::Close(_))) {
+                callback(get_paths(UpdateType::Updated));
+              }
+            } else if event.kind.is_modify() {
+              callback(get_paths(UpdateType::Updated));
+            }
+          }
+        }
+      },
+      Default::default(),
+    )?;
+
+    Ok(Self {
+      watcher,
+      watched_paths: vec![],
+    })
+  }
+
+  #[cfg(any(target_os = "macos", target_os = "windows"))]
+  pub fn watch(&mut self, paths: Vec<&Path>) -> notify::Result<()> {
+    if paths.is_empty() {
+      return Ok(());
+    }
+    // find the longest common prefix
+    let mut prefix_comps = vec![];
+    let first_item = &paths[0];
+    let rest = &paths[1..];
+
+    for (index, comp) in first_item.components().enumerate() {
+      if rest.iter().all(|item| {
+        let mut item_comps = item.components();
+
+        if index >= item.components().count() {
+          return false;
+        }
+
+        item_comps.nth(index).unwrap() == comp
+      }) {
+        prefix_comps.push(comp);
+      }
+    }
+
+    let watch_path = PathBuf::from_iter(prefix_comps.iter());
+
+    if self
+      .watched_paths
+      .iter()
+      .any(|item| watch_path.starts_with(item))
+    {
+      return Ok(());
+    } else {
+      self.watched_paths.push(watch_path.clone());
+    }
+
+    // println!("watch path {:?}", watch_path);
+
+    self
+      .watcher
+      .watch(watch_path.as_path(), notify::RecursiveMode::Recursive)
+  }
+
+  /// Watch the parent directories of the files, so that the files created or removed later are reported too.
+  /// Note that changes of the other files in these directories are reported as well
+  #[cfg(target_os = "linux")]
+  pub fn watch(&mut self, paths: Vec<&Path>) -> notify::Result<()> {
+    for path in paths {
+      let dir = if path.is_dir() {
+        path
+      } else {
+        path.parent().unwrap_or(path)
+      };
+
+      if self.watched_paths.iter().any(|p| p == dir) {
+        continue;
+      }
+
+      self
+        .watcher
+        .watch(dir, notify::RecursiveMode::NonRecursive)
+        .ok();
+
+      self.watched_paths.push(dir.to_path_buf());
+    }
+
+    Ok(())
+  }
+
+  #[cfg(any(target_os = "macos", target_os = "windows"))]
+  pub fn unwatch(&mut self, path: &str) -> notify::Result<()> {
+    self.watcher.unwatch(Path::new(path))
+  }
+
+  /// The parent directory is still watched as other files in it may be watched
+  #[cfg(target_os = "linux")]
+  pub fn unwatch(&mut self, path: &str) -> notify::Result<()> {
+    let path = Path::new(path);
+
+    if path.is_dir() {
+      self.watched_paths.retain(|p| p != path);
+      return self.watcher.unwatch(path);
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,122 @@
+use std::{collections::HashSet, path::Path, sync::Arc};
+
+use farmfe_core::{
+  config::{
+    bool_or_obj::BoolOrObj, persistent_cache::PersistentCacheConfig, Config, ModuleFormat,
+    TargetEnv,
+  },
+  context::{CompilationContext, EmitFileParams},
+  error::{CompilationError, Result},
+  module::ModuleId,
+  plugin::Plugin,
+};
+use farmfe_plugin_script::{WorkerCompiler, WorkerModule};
+
+use crate::Compiler;
+
+/// module ids of all modules that are compiled into web workers, a full reload is needed when they are updated
+pub const WORKER_MODULES: &str = "__farm_worker_modules__";
+
+/// Create a [WorkerCompiler] that compiles every web worker by a separate [Compiler] with the same plugins,
+/// so the worker gets its own runtime and resources.
+pub fn create_worker_compiler(plugins: Vec<Arc<dyn Plugin>>) -> WorkerCompiler {
+  Arc::new(move |worker, context| compile_worker(worker, &plugins, context))
+}
+
+fn compile_worker(
+  worker: &WorkerModule,
+  plugins: &[Arc<dyn Plugin>],
+  context: &Arc<CompilationContext>,
+) -> Result<String> {
+  let compiler = Compiler::new(worker_config(worker, &context.config), plugins.to_vec())?;
+  compiler.compile()?;
+
+  let worker_context = compiler.context();
+  let module_graph = worker_context.module_graph.read();
+  let resource_pot_map = worker_context.resource_pot_map.read();
+  let resources_map = worker_context.resources_map.lock();
+
+  let entry_resource_name = module_graph
+    .entries
+    .keys()
+    .filter_map(|entry| module_graph.module(entry))
+    .filter_map(|module| module.resource_pot.as_ref())
+    .filter_map(|id| resource_pot_map.resource_pot(id))
+    .flat_map(|resource_pot| resource_pot.resources())
+    .find(|name| resources_map.get(*name).is_some_and(|r| !r.emitted))
+    .cloned()
+    .ok_or_else(|| {
+      CompilationError::GenericError(format!(
+        "Compile worker `{}` imported by `{}` failed: entry resource is not found",
+        worker.resolved_path,
+        worker.importer.to_string()
+      ))
+    })?;
+
+  for resource in resources_map.values().filter(|r| !r.emitted) {
+    context.emit_file(EmitFileParams {
+      resolved_path: worker.importer.to_string(),
+      name: resource.name.clone(),
+      content: resource.bytes.clone(),
+      resource_type: resource.resource_type.clone(),
+    });
+  }
+
+  // the worker is not a part of the module graph, watch its modules to trigger a update of the importer
+  let worker_modules = module_graph
+    .modules()
+    .into_iter()
+    .filter(|m| !m.external)
+    .map(|m| m.id.clone())
+    .collect::<Vec<_>>();
+  context.add_watch_files(worker.importer.clone(), worker_modules.clone())?;
+
+  let mut all_worker_modules = context
+    .custom
+    .entry(WORKER_MODULES.to_string())
+    .or_insert_with(|| Box::<HashSet<ModuleId>>::default());
+  all_worker_modules
+    .downcast_mut::<HashSet<ModuleId>>()
+    .unwrap()
+    .extend(worker_modules);
+
+  Ok(entry_resource_name)
+}
+
+fn worker_config(worker: &WorkerModule, config: &Config) -> Config {
+  let mut config = config.clone();
+  let name = Path::new(&worker.resolved_path)
+    .file_prefix()
+    .map(|s| s.to_string_lossy().to_string())
+    .unwrap_or_else(|| "worker".to_string());
+
+  config.input = [(format!("{name}.worker"), worker.resolved_path.clone())].into();
+  // name the worker the same way as other resources so it's hashed in production
+  config.output.entry_filename = config
+    .output
+    .filename
+    .replace("[resourceName]", "[entryName]");
+  config.output.format = ModuleFormat::Iife;
+  config.output.target_env = TargetEnv::Browser;
+  config.output.name = None;
+  config.output.manifest = BoolOrObj::Bool(false);
+  // hmr is not supported in workers, the page is reloaded instead
+  config.runtime.plugins = vec![];
+  config.lazy_compilation = false;
+  config.progress = false;
+  config.persistent_cache = Box::new(PersistentCacheConfig::Bool(false));
+
+  config
+}
+
+/// Whether the updated paths contain modules of web workers
+pub fn is_worker_update(paths: &[ModuleId], context: &Arc<CompilationContext>) -> bool {
+  context
+    .custom
+    .get(WORKER_MODULES)
+    .map(|worker_modules| {
+      let worker_modules = worker_modules.downcast_ref::<HashSet<ModuleId>>().unwrap();
+      paths.iter().any(|id| worker_modules.contains(id))
+    })
+    .unwrap_or(false)
+}
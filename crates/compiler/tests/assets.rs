@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use farmfe_core::{config::TargetEnv, resource::ResourceType};
+use farmfe_testing_helpers::fixture;
+
+use crate::common::{create_config, create_with_compiler};
+
+mod common;
+
+fn compile_new_url(
+  file: std::path::PathBuf,
+  crate_path: std::path::PathBuf,
+  target_env: TargetEnv,
+) -> (String, Vec<String>) {
+  let cwd = file.parent().unwrap();
+  let mut config = create_config(cwd.to_path_buf(), crate_path);
+  config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+  config.output.target_env = target_env;
+  config.output.assets_filename = "assets/[resourceName].[hash].[ext]".to_string();
+
+  let compiler = create_with_compiler(config, vec![]);
+  compiler.compile().unwrap();
+
+  let resources_map = compiler.context().resources_map.lock();
+  let entry = resources_map
+    .values()
+    .find(|r| r.name == "index.js")
+    .expect("entry resource should be emitted");
+  let assets = resources_map
+    .values()
+    .filter(|r| matches!(r.resource_type, ResourceType::Asset(_)) && !r.emitted)
+    .map(|r| r.name.clone())
+    .collect();
+
+  (String::from_utf8(entry.bytes.clone()).unwrap(), assets)
+}
+
+#[test]
+fn new_url_asset_browser() {
+  fixture!(
+    "tests/fixtures/assets/new_url/index.ts",
+    |file, crate_path| {
+      let (code, assets) = compile_new_url(file, crate_path, TargetEnv::Browser);
+
+      assert_eq!(assets.len(), 1);
+      assert!(assets[0].starts_with("assets/logo."));
+      assert!(code.contains(&format!(r#"new URL("/{}", location.href)"#, assets[0])));
+      // absolute urls are not dependencies
+      assert!(code.contains(r#"new URL("https://farmfe.org/logo.png", module.meta.url)"#));
+      assert!(code.contains(r#"new URL("./logo.png", document.baseURI)"#));
+    }
+  );
+}
+
+#[test]
+fn new_url_asset_node() {
+  fixture!(
+    "tests/fixtures/assets/new_url/index.ts",
+    |file, crate_path| {
+      let (code, assets) = compile_new_url(file, crate_path, TargetEnv::Node);
+
+      assert_eq!(assets.len(), 1);
+      assert!(code.contains(&format!(r#"new URL("./{}", import.meta.url)"#, assets[0])));
+    }
+  );
+}
+
+#[test]
+fn inline_limit() {
+  fixture!(
+    "tests/fixtures/assets/inline_limit/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let mut config = create_config(cwd.to_path_buf(), crate_path);
+      config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+      config.output.target_env = TargetEnv::Browser;
+      config.assets.inline_limit = 160;
+
+      let compiler = create_with_compiler(config, vec![]);
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let code_of = |ext: &str| {
+        let resource = resources_map
+          .values()
+          .find(|r| r.name.ends_with(ext))
+          .unwrap();
+        String::from_utf8(resource.bytes.clone()).unwrap()
+      };
+      let mut assets = resources_map
+        .values()
+        .filter(|r| matches!(r.resource_type, ResourceType::Asset(_)) && !r.emitted)
+        .map(|r| r.name.as_str())
+        .collect::<Vec<_>>();
+      assets.sort();
+
+      // only the big asset and the asset imported with `?url` are emitted
+      assert_eq!(assets.len(), 2);
+      assert!(assets[0].starts_with("big"));
+      assert!(assets[1].starts_with("small") && assets[1].contains("?url"));
+
+      // quotes in the attribute values are kept
+      let svg = "data:image/svg+xml,%3Csvg%20xmlns=%22http://www.w3.org/2000/svg%22%20viewBox=%220%200%2010%2010%22%3E%20%3Ccircle%20cx=%225%22%20cy=%225%22%20r=%224%22%20fill=%22%23f00%22%20/%3E%20%3Ctext%20font-family=%22'Fira%20Sans'%22%3Ea%3C/text%3E%20%3C/svg%3E";
+      let js = code_of(".js");
+      assert!(js.contains("data:image/png;base64,"));
+      assert!(js.contains(svg));
+
+      let css = code_of(".css");
+      assert!(css.contains("data:image/png;base64,"));
+      assert!(css.contains(svg));
+      assert!(css.contains(&format!("/{}", assets[0])));
+    }
+  );
+}
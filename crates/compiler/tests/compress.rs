@@ -0,0 +1,62 @@
+use std::{collections::HashMap, io::Read};
+
+use farmfe_core::{
+  config::{bool_or_obj::BoolOrObj, CompressConfig},
+  resource::ResourceType,
+};
+use farmfe_testing_helpers::fixture;
+
+use crate::common::{create_config, create_with_compiler};
+
+mod common;
+
+#[test]
+fn compress_emits_gzip_and_brotli_siblings() {
+  fixture!(
+    "tests/fixtures/compress/basic/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let mut config = create_config(cwd.to_path_buf(), crate_path);
+      config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+      config.output.compress = BoolOrObj::Obj(CompressConfig {
+        threshold: 100,
+        ..Default::default()
+      });
+
+      let compiler = create_with_compiler(config, vec![]);
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+
+      let js = resources_map
+        .values()
+        .find(|r| matches!(r.resource_type, ResourceType::Js))
+        .unwrap();
+      let gz = &resources_map[&format!("{}.gz", js.name)];
+      let br = &resources_map[&format!("{}.br", js.name)];
+      assert!(matches!(&gz.resource_type, ResourceType::Custom(t) if t == "gz"));
+      assert_eq!(gz.origin.as_resource_pot(), js.origin.as_resource_pot());
+
+      let mut gunzipped = vec![];
+      flate2::read::GzDecoder::new(&gz.bytes[..])
+        .read_to_end(&mut gunzipped)
+        .unwrap();
+      assert_eq!(gunzipped, js.bytes);
+
+      let mut unbrotlied = vec![];
+      brotli::Decompressor::new(&br.bytes[..], 4096)
+        .read_to_end(&mut unbrotlied)
+        .unwrap();
+      assert_eq!(unbrotlied, js.bytes);
+
+      // the css is smaller than the threshold
+      let css = resources_map
+        .values()
+        .find(|r| matches!(r.resource_type, ResourceType::Css))
+        .unwrap();
+      assert!(css.bytes.len() < 100);
+      assert!(!resources_map.contains_key(&format!("{}.gz", css.name)));
+      assert!(!resources_map.contains_key(&format!("{}.br", css.name)));
+    }
+  );
+}
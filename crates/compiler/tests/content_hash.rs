@@ -0,0 +1,130 @@
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+};
+
+use farmfe_core::{
+  config::persistent_cache::PersistentCacheConfig, resource::ResourceType, stats::CacheStats,
+};
+use farmfe_testing_helpers::fixture;
+
+use crate::common::{create_config, create_with_compiler};
+
+mod common;
+
+/// compile the fixture and return the names of the js and css resources
+fn compile(cwd: &Path, crate_path: PathBuf) -> (Vec<String>, HashMap<String, String>) {
+  let (names, contents, _) = compile_with_cache(cwd, crate_path, None);
+
+  (names, contents)
+}
+
+/// same as [compile], with the persistent cache stored in `cache_dir` if present, also returns the cache stats
+fn compile_with_cache(
+  cwd: &Path,
+  crate_path: PathBuf,
+  cache_dir: Option<&Path>,
+) -> (Vec<String>, HashMap<String, String>, CacheStats) {
+  let mut config = create_config(cwd.to_path_buf(), crate_path);
+  if let Some(cache_dir) = cache_dir {
+    let mut persistent_cache = PersistentCacheConfig::get_default_config(&config.root);
+    if let PersistentCacheConfig::Obj(obj) = &mut persistent_cache {
+      obj.cache_dir = cache_dir.to_string_lossy().to_string();
+    }
+    config.persistent_cache = Box::new(persistent_cache);
+  }
+  config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+  config.output.entry_filename = "[entryName].[contentHash].[ext]".to_string();
+  config.output.filename = "[resourceName].[contentHash].[ext]".to_string();
+
+  let compiler = create_with_compiler(config, vec![]);
+  compiler.compile().unwrap();
+
+  let resources_map = compiler.context().resources_map.lock();
+  let mut names = resources_map
+    .values()
+    .filter(|r| matches!(r.resource_type, ResourceType::Js | ResourceType::Css))
+    .map(|r| r.name.clone())
+    .collect::<Vec<_>>();
+  names.sort();
+  let contents = resources_map
+    .values()
+    .map(|r| {
+      (
+        r.name.clone(),
+        String::from_utf8_lossy(&r.bytes).to_string(),
+      )
+    })
+    .collect();
+
+  (
+    names,
+    contents,
+    compiler.context().cache_manager.stats.snapshot(),
+  )
+}
+
+fn find<'a>(names: &'a [String], prefix: &str, ext: &str) -> &'a String {
+  names
+    .iter()
+    .find(|n| n.starts_with(prefix) && n.ends_with(ext))
+    .unwrap()
+}
+
+#[test]
+fn entry_hash_cascades_from_dependencies() {
+  fixture!(
+    "tests/fixtures/content_hash/before/index.ts",
+    |file, crate_path| {
+      let before_dir = file.parent().unwrap();
+      let after_dir = before_dir.parent().unwrap().join("after");
+
+      let (before, before_contents) = compile(before_dir, crate_path.clone());
+      let (before_again, _) = compile(before_dir, crate_path.clone());
+      let (after, _) = compile(&after_dir, crate_path);
+
+      // deterministic across builds
+      assert_eq!(before, before_again);
+
+      let entry = find(&before, "index.", ".js");
+      let lazy = find(&before, "lazy_", ".js");
+      // the entry loads the lazy resource by name
+      assert!(before_contents[entry].contains(lazy.as_str()));
+
+      // only the lazy module changes, the entry is renamed as it embeds the new lazy resource name
+      assert_ne!(lazy, find(&after, "lazy_", ".js"));
+      assert_ne!(entry, find(&after, "index.", ".js"));
+      // unrelated resources keep their names
+      let css = before.iter().find(|n| n.ends_with(".css")).unwrap();
+      assert!(after.contains(css));
+    }
+  );
+}
+
+#[test]
+fn cached_entry_is_renamed() {
+  fixture!(
+    "tests/fixtures/content_hash/before/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let cache_dir =
+        std::env::temp_dir().join(format!("farm-content-hash-cache-{}", std::process::id()));
+      let _ = std::fs::remove_dir_all(&cache_dir);
+
+      let (names, contents, _) = compile_with_cache(cwd, crate_path.clone(), Some(&cache_dir));
+      let (cached_names, cached_contents, stats) =
+        compile_with_cache(cwd, crate_path, Some(&cache_dir));
+      std::fs::remove_dir_all(&cache_dir).unwrap();
+
+      // the entry is restored from the cache too and gets the same name
+      assert_eq!(stats.resource_cache_misses, 0);
+      assert!(stats.resource_cache_hits > 0);
+      assert_eq!(names, cached_names);
+
+      let entry = find(&cached_names, "index.", ".js");
+      let lazy = find(&cached_names, "lazy_", ".js");
+      assert!(cached_contents[entry].contains(lazy.as_str()));
+      assert_eq!(contents[entry], cached_contents[entry]);
+    }
+  );
+}
@@ -1,6 +1,12 @@
-use std::collections::HashMap;
-
-use farmfe_core::config::{CssConfig, CssModulesConfig, CssPrefixerConfig};
+use std::{collections::HashMap, path::Path};
+
+use farmfe_core::{
+  config::{
+    bool_or_obj::BoolOrObj, CssConfig, CssModulesConfig, CssModulesDtsConfig, CssPrefixerConfig,
+    LocalsConvention,
+  },
+  plugin::UpdateType,
+};
 use farmfe_testing_helpers::fixture;
 mod common;
 
@@ -22,6 +28,7 @@ fn css_modules() {
         modules: Some(CssModulesConfig {
           indent_name: "farm-[name]".into(),
           paths: vec![".+".to_string()],
+          ..Default::default()
         }),
         ..Default::default()
       },
@@ -33,6 +40,116 @@ fn css_modules() {
   });
 }
 
+fn css_modules_named_exports_config(dts_out_dir: &Path) -> CssConfig {
+  CssConfig {
+    modules: Some(CssModulesConfig {
+      indent_name: "farm-[name]".into(),
+      paths: vec![".+".to_string()],
+      locals_convention: LocalsConvention::CamelCaseOnly,
+      named_exports: true,
+      dts: BoolOrObj::Obj(CssModulesDtsConfig {
+        out_dir: Some(dts_out_dir.to_string_lossy().to_string()),
+      }),
+    }),
+    ..Default::default()
+  }
+}
+
+#[test]
+fn css_modules_named_exports() {
+  fixture!(
+    "tests/fixtures/css/modules_named_exports/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let dts_out_dir =
+        std::env::temp_dir().join(format!("farm-css-modules-dts-{}", std::process::id()));
+
+      let entry_name = "index".to_string();
+
+      let compiler = create_css_compiler(
+        HashMap::from([(entry_name.clone(), "./index.ts".into())]),
+        cwd.to_path_buf(),
+        crate_path,
+        css_modules_named_exports_config(&dts_out_dir),
+      );
+
+      compiler.compile().unwrap();
+
+      assert_compiler_result(&compiler, Some(&entry_name));
+
+      let dts = std::fs::read_to_string(dts_out_dir.join("index.css.d.ts")).unwrap();
+      std::fs::remove_dir_all(&dts_out_dir).unwrap();
+      assert_eq!(
+        dts,
+        r#"// This file is generated by farm from the css module, do not edit it manually
+declare const styles: {
+  readonly "fooBar": string;
+  readonly "unusedClass": string;
+};
+export default styles;
+export declare const fooBar: string;
+export declare const unusedClass: string;
+"#
+      );
+    }
+  );
+}
+
+#[test]
+fn css_modules_dts_regenerated_on_update() {
+  fixture!(
+    "tests/fixtures/css/modules_named_exports/index.ts",
+    |file, crate_path| {
+      // the css file is modified, so the fixture is copied
+      let cwd = std::env::temp_dir().join(format!(
+        "farm-css-modules-dts-update-{}",
+        std::process::id()
+      ));
+      std::fs::create_dir_all(&cwd).unwrap();
+      for name in ["index.ts", "index.css"] {
+        std::fs::copy(file.parent().unwrap().join(name), cwd.join(name)).unwrap();
+      }
+      let dts_out_dir = cwd.join("types");
+
+      let compiler = create_css_compiler(
+        HashMap::from([("index".to_string(), "./index.ts".into())]),
+        cwd.clone(),
+        crate_path,
+        css_modules_named_exports_config(&dts_out_dir),
+      );
+
+      compiler.compile().unwrap();
+
+      let dts_path = dts_out_dir.join("index.css.d.ts");
+      assert!(!std::fs::read_to_string(&dts_path)
+        .unwrap()
+        .contains("bazQux"));
+
+      let css_path = cwd.join("index.css");
+      let css = std::fs::read_to_string(&css_path).unwrap();
+      std::fs::write(
+        &css_path,
+        format!("{css}\n.baz-qux {{\n  color: green;\n}}\n"),
+      )
+      .unwrap();
+
+      compiler
+        .update(
+          vec![(css_path.to_string_lossy().to_string(), UpdateType::Updated)],
+          |_| {},
+          true,
+          true,
+        )
+        .unwrap();
+
+      let dts = std::fs::read_to_string(&dts_path).unwrap();
+      std::fs::remove_dir_all(&cwd).unwrap();
+      assert!(dts.contains("readonly \"bazQux\": string;"));
+      assert!(dts.contains("export declare const bazQux: string;"));
+    }
+  );
+}
+
 #[test]
 fn css_prefixer() {
   fixture!("tests/fixtures/css/prefixer/**/*.ts", |file, crate_path| {
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use farmfe_core::serde_json::Value;
+use farmfe_testing_helpers::fixture;
+
+use crate::common::{create_config, create_with_compiler};
+
+mod common;
+
+#[test]
+fn define_replaced_by_ast() {
+  fixture!(
+    "tests/fixtures/define/basic/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let mut config = create_config(cwd.to_path_buf(), crate_path);
+      config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+      config.define = HashMap::from([
+        (
+          "process.env.NODE_ENV".to_string(),
+          Value::String("\"production\"".to_string()),
+        ),
+        ("__DEV__".to_string(), Value::Bool(false)),
+        (
+          "import.meta.env.MODE".to_string(),
+          Value::String("\"production\"".to_string()),
+        ),
+      ]);
+
+      let compiler = create_with_compiler(config, vec![]);
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let code = String::from_utf8(resources_map["index.js"].bytes.clone()).unwrap();
+
+      assert!(code
+        .contains(r#"console.log("production", process.env.NODE_ENV_X, message, obj.process);"#));
+      // strings, properties and local variables are not replaced
+      assert!(code.contains(r#"const message = "process.env.NODE_ENV";"#));
+      assert!(code.contains("return process1.env.NODE_ENV;"));
+      assert!(code.contains(r#"console.log("boolean", local, "production");"#));
+      // the dead branch is removed and so is the module only used in it
+      assert!(!code.contains("devOnly"));
+      assert!(!code.contains("dev only"));
+    }
+  );
+}
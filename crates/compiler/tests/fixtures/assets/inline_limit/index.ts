@@ -0,0 +1,7 @@
+import './index.css';
+import small from './small.png';
+import icon from './icon.svg';
+import big from './big.png';
+import smallUrl from './small.png?url';
+
+export { small, icon, big, smallUrl };
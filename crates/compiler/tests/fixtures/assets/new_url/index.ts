@@ -0,0 +1,6 @@
+const logo = new URL('./logo.png', import.meta.url);
+const remote = new URL('https://farmfe.org/logo.png', import.meta.url);
+// not resolved relative to the module
+const page = new URL('./logo.png', document.baseURI);
+
+export { logo, remote, page };
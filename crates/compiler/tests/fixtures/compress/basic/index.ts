@@ -0,0 +1,9 @@
+import './index.css';
+
+export const messages = [
+  'pre-compressed resources are served directly by the server',
+  'so the server does not have to compress them on the fly',
+  'resources smaller than the threshold are not compressed'
+];
+
+console.log(messages.join('\n'));
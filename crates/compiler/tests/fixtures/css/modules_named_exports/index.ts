@@ -0,0 +1,3 @@
+import { fooBar } from './index.css';
+
+console.log(fooBar);
@@ -0,0 +1,18 @@
+import { devOnly } from './dev';
+
+const message = 'process.env.NODE_ENV';
+const obj = { process: 1 };
+
+function local(process: any) {
+  return process.env.NODE_ENV;
+}
+
+export function run() {
+  console.log(process.env.NODE_ENV, process.env.NODE_ENV_X, message, obj.process);
+
+  if (process.env.NODE_ENV !== 'production') {
+    devOnly();
+  }
+
+  console.log(typeof __DEV__, local, import.meta.env.MODE);
+}
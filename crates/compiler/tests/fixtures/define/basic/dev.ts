@@ -0,0 +1,3 @@
+export function devOnly() {
+  console.log('dev only');
+}
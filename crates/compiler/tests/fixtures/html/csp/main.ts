@@ -0,0 +1,3 @@
+import './main.css';
+
+document.getElementById('root')!.innerText = 'csp';
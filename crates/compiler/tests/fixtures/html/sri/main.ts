@@ -0,0 +1,5 @@
+import './main.css';
+
+import('./lazy').then(({ text }) => {
+  document.getElementById('root')!.innerText = text;
+});
@@ -0,0 +1,8 @@
+import React from 'react';
+import { add } from './utils';
+
+export const version = React.version;
+
+export default function sum(a: number, b: number) {
+  return add(a, b);
+}
@@ -0,0 +1,5 @@
+import './index.css';
+
+export function load() {
+  return import('./lazy');
+}
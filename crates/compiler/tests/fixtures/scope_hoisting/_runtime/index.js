@@ -0,0 +1,21 @@
+// a minimal module system to run the hoisted bundles
+const modules = {};
+const cache = {};
+
+function require(id) {
+  if (cache[id]) return cache[id].exports;
+
+  const module = { id, exports: {} };
+  cache[id] = module;
+  modules[id](module, module.exports, require, (id) => Promise.resolve(require(id)));
+  return module.exports;
+}
+
+(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__ = {
+  register: (id, fn) => (modules[id] = fn),
+  require,
+  bootstrap: () => {},
+  setPlugins: () => {},
+  setInitialLoadedResources: () => {},
+  setDynamicModuleResourcesMap: () => {}
+};
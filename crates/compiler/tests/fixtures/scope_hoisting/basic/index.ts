@@ -0,0 +1,17 @@
+import { sum, PI as pi } from './math';
+import * as strings from './strings';
+import config from './config';
+import './side';
+
+const value = 'index';
+
+export const result = sum(1, 2) + pi;
+export { strings };
+export * from './math';
+export default config;
+
+console.log(value, result, strings.upper(config.name), { pi });
+
+export function load() {
+  return import('./lazy');
+}
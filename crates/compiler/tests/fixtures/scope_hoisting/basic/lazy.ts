@@ -0,0 +1,3 @@
+import { sum } from './math';
+
+export const lazy = sum(2, 3);
@@ -0,0 +1,9 @@
+const value = 'math';
+
+export function sum(a: number, b: number) {
+  return a + b;
+}
+
+export const PI = 3.14;
+
+console.log(value);
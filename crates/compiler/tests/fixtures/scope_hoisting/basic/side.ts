@@ -0,0 +1 @@
+globalThis.__side__ = true;
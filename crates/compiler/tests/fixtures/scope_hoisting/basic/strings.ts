@@ -0,0 +1,2 @@
+export const upper = (s: string) => s.toUpperCase();
+export { sum as add } from './math';
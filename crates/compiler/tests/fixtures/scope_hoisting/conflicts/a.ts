@@ -0,0 +1,9 @@
+import { b } from './b';
+
+const name = 'a';
+
+export const a = name;
+
+export function describe() {
+  return `${name} imports ${b}`;
+}
@@ -0,0 +1,9 @@
+import { describe } from './a';
+
+const name = 'b';
+
+export const b = name;
+
+export function describeA() {
+  return describe();
+}
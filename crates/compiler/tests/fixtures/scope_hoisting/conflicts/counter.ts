@@ -0,0 +1,9 @@
+export let count = 0;
+
+export function inc() {
+  count++;
+}
+
+export default function () {
+  return 'anonymous';
+}
@@ -0,0 +1,10 @@
+import { a, describe } from './a';
+import { b } from './b';
+import anonymous, { count, inc } from './counter';
+
+const name = 'index';
+
+inc();
+console.log(name, a, b, describe(), anonymous(), count);
+
+export { count };
@@ -0,0 +1,6 @@
+// single letter globals may be taken by the mangled names of locals
+export function readGlobals() {
+  const first = 'first';
+  const second = 'second';
+  return [first, second, e, t, n, o, s].join(' ');
+}
@@ -0,0 +1,5 @@
+import { readGlobals } from './env';
+
+Object.assign(globalThis, { e: 'e', t: 't', n: 'n', o: 'o', s: 's' });
+
+console.log(readGlobals());
@@ -0,0 +1,4 @@
+import { add } from './utils';
+import './index.css';
+
+console.log(add(1, 2));
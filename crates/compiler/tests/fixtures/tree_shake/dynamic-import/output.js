@@ -12,35 +12,20 @@
     }
     _export(exports, {
         default: function() {
-            return _default;
+            return _default1;
         },
         dep: function() {
             return dep;
         }
     });
-    var _interop_require_default = farmRequire("@swc/helpers/_/_interop_require_default");
-    var _dep1 = _interop_require_default._(farmRequire("ef0c4c9d"));
-    const dep = "dep";
-    function _default() {
-        return (0, _dep1.default)();
-    }
-    console.log("side effect in dep.ts");
-}
-,
-"ef0c4c9d":function  (module, exports, farmRequire, farmDynamicRequire) {
-    "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
-    Object.defineProperty(exports, "default", {
-        enumerable: true,
-        get: function() {
-            return _default;
-        }
-    });
     function _default() {
         console.log("1111");
     }
+    const dep = "dep";
+    function _default1() {
+        return _default();
+    }
+    console.log("side effect in dep.ts");
 }
 ,});
 
@@ -2,32 +2,7 @@
  (globalThis || window || global)['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};(function(r,e){var t={};function n(r){return Promise.resolve(o(r))}function o(e){if(t[e])return t[e].exports;var i={id:e,exports:{}};r[e](i,i.exports,o,n);t[e]=i;return i.exports}o(e)})({"d2214aaa":function  (module, exports, farmRequire, farmDynamicRequire) {
     console.log("runtime/index.js")(globalThis || window || global)["__farm_default_namespace__"].__farm_module_system__.setPlugins([]);
 }
-,},"d2214aaa");(function(_){for(var r in _){_[r].__farm_resource_pot__='index_ddf1.js';(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"05ee5ec7":function  (module, exports, farmRequire, farmDynamicRequire) {
-    "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
-    function _export(target, all) {
-        for(var name in all)Object.defineProperty(target, name, {
-            enumerable: true,
-            get: all[name]
-        });
-    }
-    _export(exports, {
-        a: function() {
-            return a;
-        },
-        invalidate: function() {
-            return invalidate;
-        }
-    });
-    const a = "1";
-    function invalidate() {
-        return `invalidate data`;
-    }
-}
-,
-"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+,},"d2214aaa");(function(_){for(var r in _){_[r].__farm_resource_pot__='index_ddf1.js';(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
     "use strict";
     Object.defineProperty(exports, "__esModule", {
         value: true
@@ -38,13 +13,16 @@
             return InvalidateParent;
         }
     });
-    var _dep = farmRequire("05ee5ec7");
-    console.log(_dep.a);
+    const a = "1";
+    function invalidate() {
+        return `invalidate data`;
+    }
+    console.log(a);
     const id = "InvalidateParent";
     function InvalidateParent() {
         return {
             render: ()=>{
-                const renderData = (0, _dep.invalidate)();
+                const renderData = invalidate();
                 const div = document.createElement("div", {});
                 div.id = id;
                 div.innerText = renderData;
@@ -4,38 +4,20 @@
 }
 ,},"d2214aaa");(function(_){for(var r in _){_[r].__farm_resource_pot__='index_7104.js';(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
     "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
-    var _interop_require_wildcard = farmRequire("@swc/helpers/_/_interop_require_wildcard");
-    var _b = _interop_require_wildcard._(farmRequire("f380ea31"));
-    console.log(_b);
-}
-,
-"f380ea31":function  (module, exports, farmRequire, farmDynamicRequire) {
-    "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
-    function _export(target, all) {
-        for(var name in all)Object.defineProperty(target, name, {
-            enumerable: true,
-            get: all[name]
-        });
-    }
-    _export(exports, {
-        A: function() {
+    const A = 10;
+    const B = 20;
+    const C = 30;
+    var _namespace = {
+        get "A" () {
             return A;
         },
-        B: function() {
+        get "B" () {
             return B;
         },
-        C: function() {
+        get "C" () {
             return C;
         }
-    });
-    const A = 10;
-    const B = 20;
-    const C = 30;
+    };
+    console.log(_namespace);
 }
 ,});(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap({  });var farmModuleSystem = (globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
\ No newline at end of file
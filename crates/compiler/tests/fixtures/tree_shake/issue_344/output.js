@@ -8,63 +8,14 @@
     };
 }
 ,
-"abc9a879":function  (module, exports, farmRequire, farmDynamicRequire) {
+"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
     "use strict";
     Object.defineProperty(exports, "__esModule", {
         value: true
     });
-    function _export(target, all) {
-        for(var name in all)Object.defineProperty(target, name, {
-            enumerable: true,
-            get: all[name]
-        });
-    }
-    _export(exports, {
-        Argument: function() {
-            return Argument;
-        },
-        Command: function() {
-            return Command;
-        },
-        CommanderError: function() {
-            return CommanderError;
-        },
-        Help: function() {
-            return Help;
-        },
-        InvalidArgumentError: function() {
-            return InvalidArgumentError;
-        },
-        InvalidOptionArgumentError: function() {
-            return InvalidOptionArgumentError;
-        },
-        Option: function() {
-            return Option;
-        },
-        createArgument: function() {
-            return createArgument;
-        },
-        createCommand: function() {
-            return createCommand;
-        },
-        createOption: function() {
-            return createOption;
-        },
-        program: function() {
-            return program;
-        }
-    });
     var _interop_require_default = farmRequire("@swc/helpers/_/_interop_require_default");
     var _command = _interop_require_default._(farmRequire("4ef5be87"));
     const { program, createCommand, createArgument, createOption, CommanderError, InvalidArgumentError, InvalidOptionArgumentError, Command, Argument, Option, Help } = _command.default;
-}
-,
-"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
-    "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
-    var _esm = farmRequire("abc9a879");
-    (0, _esm.program)();
+    program();
 }
 ,});(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap({  });var farmModuleSystem = (globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
\ No newline at end of file
@@ -2,63 +2,27 @@
  (globalThis || window || global)['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};(function(r,e){var t={};function n(r){return Promise.resolve(o(r))}function o(e){if(t[e])return t[e].exports;var i={id:e,exports:{}};r[e](i,i.exports,o,n);t[e]=i;return i.exports}o(e)})({"d2214aaa":function  (module, exports, farmRequire, farmDynamicRequire) {
     console.log("runtime/index.js")(globalThis || window || global)["__farm_default_namespace__"].__farm_module_system__.setPlugins([]);
 }
-,},"d2214aaa");(function(_){for(var r in _){_[r].__farm_resource_pot__='index_2c69.js';(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"10c43cb2":function  (module, exports, farmRequire, farmDynamicRequire) {
+,},"d2214aaa");(function(_){for(var r in _){_[r].__farm_resource_pot__='index_2c69.js';(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
     "use strict";
     Object.defineProperty(exports, "__esModule", {
         value: true
     });
-    Object.defineProperty(exports, "registerTickMethod", {
+    Object.defineProperty(exports, "tick", {
         enumerable: true,
         get: function() {
-            return registerTickMethod;
+            return tick;
         }
     });
     const cache = {};
     function registerTickMethod(id, method) {
         cache[id] = method;
     }
-}
-,
-"11ecb1ee":function  (module, exports, farmRequire, farmDynamicRequire) {
-    "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
-    Object.defineProperty(exports, "scaleFunc", {
-        enumerable: true,
-        get: function() {
-            return scaleFunc;
-        }
-    });
-    farmRequire("3e3af5b6");
+    registerTickMethod("xxx", ()=>console.log("xxx"));
     function scaleFunc() {
         return "tick";
     }
-}
-,
-"3e3af5b6":function  (module, exports, farmRequire, farmDynamicRequire) {
-    "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
-    var _register = farmRequire("10c43cb2");
-    (0, _register.registerTickMethod)("xxx", ()=>console.log("xxx"));
-}
-,
-"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
-    "use strict";
-    Object.defineProperty(exports, "__esModule", {
-        value: true
-    });
-    Object.defineProperty(exports, "tick", {
-        enumerable: true,
-        get: function() {
-            return tick;
-        }
-    });
-    var _depindex = farmRequire("11ecb1ee");
     function tick() {
-        (0, _depindex.scaleFunc)();
+        scaleFunc();
     }
 }
 ,});(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources([]);(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap({  });var farmModuleSystem = (globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");var tick=entry.tick;export { tick };
\ No newline at end of file
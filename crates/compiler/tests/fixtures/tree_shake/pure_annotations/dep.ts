@@ -0,0 +1,18 @@
+function create(name: string) {
+  console.log('create', name);
+  return { name };
+}
+
+/*#__NO_SIDE_EFFECTS__*/
+function createPure(name: string) {
+  console.log('createPure', name);
+  return { name };
+}
+
+const pure = /*#__PURE__*/ create('pure');
+const atPure = /*@__PURE__*/ create('atPure');
+const noSideEffects = createPure('noSideEffects');
+/*#__PURE__*/ create('pureStmt');
+const impure = create('impure');
+
+export const used = /*#__PURE__*/ createPure('used');
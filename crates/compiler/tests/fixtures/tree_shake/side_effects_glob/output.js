@@ -0,0 +1,43 @@
+//__farm_runtime.fc09085b.mjs:
+ (globalThis || window || global)['__farm_default_namespace__'] = {__FARM_TARGET_ENV__: 'browser'};(function(r,e){var t={};function n(r){return Promise.resolve(o(r))}function o(e){if(t[e])return t[e].exports;var i={id:e,exports:{}};r[e](i,i.exports,o,n);t[e]=i;return i.exports}o(e)})({"d2214aaa":function  (module, exports, farmRequire, farmDynamicRequire) {
+    console.log("runtime/index.js")(globalThis || window || global)["__farm_default_namespace__"].__farm_module_system__.setPlugins([]);
+}
+,},"d2214aaa");
+
+//index.js:
+ import "./__farm_runtime.fc09085b.mjs";import "./index_c584.js";(function(_){for(var r in _){_[r].__farm_resource_pot__='index_dcdc.js';(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"b5d64806":function  (module, exports, farmRequire, farmDynamicRequire) {
+    "use strict";
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+    var _lib = farmRequire("d0bdeb3a");
+    console.log(_lib.a);
+}
+,});(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.setInitialLoadedResources(['index_c584.js']);(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.setDynamicModuleResourcesMap({  });var farmModuleSystem = (globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__;farmModuleSystem.bootstrap();var entry = farmModuleSystem.require("b5d64806");
+
+//index_c584.js:
+ (function(_){for(var r in _){_[r].__farm_resource_pot__='index_c584.js';(globalThis || window || global)['__farm_default_namespace__'].__farm_module_system__.register(r,_[r])}})({"81d72dd4":function  (module, exports, farmRequire, farmDynamicRequire) {
+    globalThis.__setup__ = true;
+}
+,
+"d0bdeb3a":function  (module, exports, farmRequire, farmDynamicRequire) {
+    "use strict";
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+    Object.defineProperty(exports, "a", {
+        enumerable: true,
+        get: function() {
+            return a;
+        }
+    });
+    farmRequire("81d72dd4");
+    farmRequire("dd9cf8ec");
+    const a = "a";
+    console.log("b");
+}
+,
+"dd9cf8ec":function  (module, exports, farmRequire, farmDynamicRequire) {
+    Array.prototype.__polyfill__ = true;
+}
+,});
\ No newline at end of file
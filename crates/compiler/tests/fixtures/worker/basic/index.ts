@@ -0,0 +1,9 @@
+const worker = new Worker(new URL('./worker.ts', import.meta.url), {
+  type: 'module'
+});
+const shared = new SharedWorker(new URL('./shared.ts', import.meta.url));
+
+worker.postMessage('ping');
+shared.port.start();
+
+export { worker, shared };
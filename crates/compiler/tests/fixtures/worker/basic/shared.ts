@@ -0,0 +1,5 @@
+import { greet } from './utils';
+
+self.addEventListener('connect', (e: any) => {
+  e.ports[0].postMessage(greet('shared'));
+});
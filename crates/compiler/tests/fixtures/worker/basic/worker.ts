@@ -0,0 +1,5 @@
+import { greet } from './utils';
+
+self.onmessage = (e: MessageEvent<string>) => {
+  self.postMessage(greet(e.data));
+};
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use farmfe_core::{
+  config::{html::HtmlCspConfig, Config, SriAlgorithm},
+  resource::{Resource, ResourceType},
+  serde_json::{self, Value},
+};
+use farmfe_testing_helpers::fixture;
+use farmfe_toolkit::hash::{integrity, sha256_base64};
+
+use crate::common::{create_config, create_with_compiler};
+
+mod common;
+
+fn compile_html(
+  file: &std::path::Path,
+  crate_path: std::path::PathBuf,
+  update_config: impl FnOnce(&mut Config),
+) -> HashMap<String, Resource> {
+  let cwd = file.parent().unwrap();
+  let mut config = create_config(cwd.to_path_buf(), crate_path);
+  config.input = HashMap::from([("index".to_string(), "./index.html".to_string())]);
+  update_config(&mut config);
+
+  let compiler = create_with_compiler(config, vec![]);
+  compiler.compile().unwrap();
+
+  let resources_map = compiler.context().resources_map.lock();
+  resources_map.clone()
+}
+
+fn get_html(resources_map: &HashMap<String, Resource>) -> &Resource {
+  resources_map
+    .values()
+    .find(|r| matches!(r.resource_type, ResourceType::Html))
+    .unwrap()
+}
+
+fn get_html_code(resources_map: &HashMap<String, Resource>) -> String {
+  String::from_utf8(get_html(resources_map).bytes.clone()).unwrap()
+}
+
+fn get_csp_hashes(resources_map: &HashMap<String, Resource>) -> Vec<String> {
+  let hashes: Value = serde_json::from_slice(&resources_map["csp-hashes.json"].bytes).unwrap();
+  hashes[&get_html(resources_map).name]
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|h| h.as_str().unwrap().to_string())
+    .collect()
+}
+
+#[test]
+fn csp_nonce() {
+  fixture!("tests/fixtures/html/csp/index.html", |file, crate_path| {
+    let resources_map = compile_html(&file, crate_path, |config| {
+      config.html.csp = Some(HtmlCspConfig {
+        nonce: Some("{{CSP_NONCE}}".to_string()),
+        ..Default::default()
+      });
+    });
+    let html = get_html_code(&resources_map);
+    let hashes = get_csp_hashes(&resources_map);
+
+    let script_count = html.matches("<script").count();
+    assert!(script_count > 0);
+    assert_eq!(
+      html.matches(r#"nonce="{{CSP_NONCE}}""#).count(),
+      script_count + html.matches("<link").count()
+    );
+
+    // every inline script is listed in the hashes
+    for code in html.split("<script").skip(1).filter_map(|s| {
+      let code = &s[s.find('>').unwrap() + 1..s.find("</script>").unwrap()];
+      (!code.is_empty()).then_some(code)
+    }) {
+      assert!(hashes.contains(&format!("sha256-{}", sha256_base64(code.as_bytes()))));
+    }
+  });
+}
+
+#[test]
+fn csp_external() {
+  fixture!("tests/fixtures/html/csp/index.html", |file, crate_path| {
+    let resources_map = compile_html(&file, crate_path, |config| {
+      config.html.csp = Some(HtmlCspConfig {
+        external: true,
+        ..Default::default()
+      });
+    });
+    let html = get_html_code(&resources_map);
+    let hashes = get_csp_hashes(&resources_map);
+
+    assert!(!html.contains("<script data-farm-entry-script=\"true\">"));
+    assert!(!html.contains("</script><script>"));
+
+    let external_scripts = html
+      .split("<script")
+      .skip(1)
+      .filter(|s| s.contains("data-farm-entry-script"))
+      .collect::<Vec<_>>();
+    assert_eq!(external_scripts.len(), hashes.len());
+
+    for script in external_scripts {
+      assert!(script.contains(" src=\"/"));
+      let integrity = script.split("integrity=\"").nth(1).unwrap();
+      let integrity = &integrity[..integrity.find('"').unwrap()];
+      assert!(hashes.contains(&integrity.to_string()));
+    }
+
+    for hash in &hashes {
+      assert!(resources_map
+        .values()
+        .any(|r| &format!("sha256-{}", sha256_base64(&r.bytes)) == hash));
+    }
+  });
+}
+
+#[test]
+fn sri() {
+  fixture!("tests/fixtures/html/sri/index.html", |file, crate_path| {
+    let resources_map = compile_html(&file, crate_path, |config| {
+      config.output.sri = Some(SriAlgorithm::Sha384);
+    });
+    let html = get_html_code(&resources_map);
+
+    let tags = html
+      .split('<')
+      .filter(|tag| {
+        tag.starts_with("link rel=\"stylesheet\"") || tag.contains("data-farm-resource")
+      })
+      .collect::<Vec<_>>();
+    assert!(tags.len() >= 2);
+
+    for tag in tags {
+      let attr = |name: &str| {
+        let value = tag.split(&format!("{name}=\"")).nth(1).unwrap();
+        value[..value.find('"').unwrap()].to_string()
+      };
+      let path = if tag.starts_with("link") {
+        attr("href")
+      } else {
+        attr("src")
+      };
+      let resource = &resources_map[path.trim_start_matches('/')];
+
+      assert_eq!(
+        attr("integrity"),
+        integrity(&resource.bytes, SriAlgorithm::Sha384)
+      );
+      assert_eq!(attr("crossorigin"), "anonymous");
+    }
+
+    // lazily loaded resources carry their integrity in the dynamic resources map
+    assert!(html.contains("integrity: 'sha384-"));
+  });
+}
+
+#[test]
+fn resource_hints_preload() {
+  fixture!(
+    "tests/fixtures/html/resource_hints/index.html",
+    |file, crate_path| {
+      let html = get_html_code(&compile_html(&file, crate_path.clone(), |_| {}));
+      // disabled by default
+      assert!(!html.contains("preload"));
+
+      let html = get_html_code(&compile_html(&file, crate_path, |config| {
+        config.html.preload = true;
+      }));
+
+      let scripts = html.matches("data-farm-resource").count();
+      assert!(scripts > 0);
+      assert_eq!(
+        html.matches(r#"rel="preload" href="/"#).count(),
+        scripts + 2
+      );
+      assert_eq!(html.matches(r#"as="script""#).count(), scripts);
+      assert!(html.contains(r#"as="font" type="font/woff2" crossorigin="anonymous""#));
+      assert!(html.contains(r#"as="image""#));
+      assert!(!html.contains("prefetch"));
+    }
+  );
+}
+
+#[test]
+fn resource_hints_prefetch() {
+  fixture!(
+    "tests/fixtures/html/resource_hints/index.html",
+    |file, crate_path| {
+      let html = get_html_code(&compile_html(&file, crate_path, |config| {
+        config.html.prefetch = true;
+      }));
+
+      assert!(!html.contains("preload"));
+
+      let prefetch = html
+        .split("<link")
+        .filter(|l| l.contains(r#"rel="prefetch""#))
+        .collect::<Vec<_>>();
+      assert_eq!(prefetch.len(), 1);
+      assert!(prefetch[0].contains(r#"as="script""#));
+
+      // the prefetched resource is the one loaded by the dynamic import
+      let href = prefetch[0].split("href=\"/").nth(1).unwrap();
+      let href = &href[..href.find('"').unwrap()];
+      assert!(html.contains(&format!("path: '{href}'")));
+    }
+  );
+}
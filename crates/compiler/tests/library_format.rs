@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use farmfe_core::config::{config_regex::ConfigRegex, ModuleFormat, TargetEnv};
+use farmfe_testing_helpers::fixture;
+
+use crate::common::{create_config, create_with_compiler};
+
+mod common;
+
+fn compile_library(
+  file: std::path::PathBuf,
+  crate_path: std::path::PathBuf,
+  format: ModuleFormat,
+) -> String {
+  let cwd = file.parent().unwrap();
+  let mut config = create_config(cwd.to_path_buf(), crate_path);
+  config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+  config.output.format = format;
+  config.output.target_env = TargetEnv::Browser;
+  config.output.name = Some("MyLib".to_string());
+  config.output.globals = HashMap::from([("react".to_string(), "React".to_string())]);
+  config.external = vec![ConfigRegex::new("^react$")];
+
+  let compiler = create_with_compiler(config, vec![]);
+  compiler.compile().unwrap();
+
+  let resources_map = compiler.context().resources_map.lock();
+  let entry = resources_map
+    .values()
+    .find(|r| r.name.starts_with("index") && r.name.ends_with(".js") && !r.emitted)
+    .expect("entry resource should be emitted");
+
+  // dep resources are inlined into the entry resource
+  assert_eq!(
+    resources_map
+      .values()
+      .filter(|r| r.name.ends_with(".js") && !r.emitted)
+      .count(),
+    1
+  );
+
+  String::from_utf8(entry.bytes.clone()).unwrap()
+}
+
+#[test]
+fn iife_format_wraps_entry() {
+  fixture!(
+    "tests/fixtures/library_format/basic/index.ts",
+    |file, crate_path| {
+      let code = compile_library(file, crate_path, ModuleFormat::Iife);
+
+      assert!(code.starts_with("var MyLib=(function(__farm_externals__){"));
+      assert!(code.contains(r#"({"react":(globalThis||window||{})["React"]});"#));
+      assert!(code.contains("setExternalModules(__farm_externals__);"));
+      assert!(code.contains("__farm_entry_exports__.default = entry.default || entry;"));
+      assert!(code.contains("__farm_entry_exports__.version = entry.version;"));
+      assert!(!code.contains("export default"));
+    }
+  );
+}
+
+#[test]
+fn umd_format_wraps_entry() {
+  fixture!(
+    "tests/fixtures/library_format/basic/index.ts",
+    |file, crate_path| {
+      let code = compile_library(file, crate_path, ModuleFormat::Umd);
+
+      assert!(code.starts_with("(function(root,factory){"));
+      assert!(code.contains(r#"module.exports=factory({"react":require("react")});"#));
+      assert!(code.contains(r#"define(["react"],function(__farm_amd_dep_0){"#));
+      assert!(code.contains(r#"root["MyLib"]=factory({"react":root["React"]});"#));
+      assert!(code.contains("__farm_entry_exports__.version = entry.version;"));
+    }
+  );
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use farmfe_core::{
+  config::{bool_or_obj::BoolOrObj, ManifestConfig},
+  serde_json::{self, Value},
+};
+use farmfe_testing_helpers::fixture;
+
+use crate::common::{create_config, create_with_compiler};
+
+mod common;
+
+#[test]
+fn manifest_maps_entries_and_dynamic_imports() {
+  fixture!(
+    "tests/fixtures/manifest/basic/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let mut config = create_config(cwd.to_path_buf(), crate_path);
+      config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+      config.output.manifest = BoolOrObj::Obj(ManifestConfig {
+        filename: "build-manifest.json".to_string(),
+      });
+
+      let compiler = create_with_compiler(config, vec![]);
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let manifest: Value =
+        serde_json::from_slice(&resources_map["build-manifest.json"].bytes).unwrap();
+
+      let entry = &manifest["index"];
+      assert_eq!(entry["isEntry"], Value::Bool(true));
+      assert_eq!(entry["dynamicImports"], serde_json::json!(["lazy.ts"]));
+
+      let lazy = &manifest["lazy.ts"];
+      assert_eq!(lazy["isDynamicEntry"], Value::Bool(true));
+
+      for item in [entry, lazy] {
+        let js = item["js"].as_array().unwrap();
+        let css = item["css"].as_array().unwrap();
+        assert!(!js.is_empty());
+        assert_eq!(css.len(), 1);
+
+        for name in js.iter().chain(css.iter()) {
+          assert!(resources_map.contains_key(name.as_str().unwrap()));
+        }
+      }
+    }
+  );
+}
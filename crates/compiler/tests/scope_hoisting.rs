@@ -0,0 +1,133 @@
+use std::{collections::HashMap, path::PathBuf, process::Command};
+
+use farmfe_core::{
+  config::{bool_or_obj::BoolOrObj, Mode, TargetEnv},
+  module::ModuleId,
+};
+use farmfe_testing_helpers::fixture;
+
+use crate::common::{create_config, create_with_compiler};
+
+mod common;
+
+fn compile_with_concatenation(file: PathBuf, crate_path: PathBuf, minify: bool) -> String {
+  let cwd = file.parent().unwrap();
+  let mut config = create_config(cwd.to_path_buf(), crate_path.clone());
+  // a runnable module system instead of the stub runtime
+  config.runtime.path = crate_path
+    .join("tests/fixtures/scope_hoisting/_runtime/index.js")
+    .to_string_lossy()
+    .to_string();
+  config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+  config.output.target_env = TargetEnv::Node;
+  // concatenate_modules is enabled by default in production
+  config.mode = Mode::Production;
+  config.minify = Box::new(BoolOrObj::from(minify));
+
+  let compiler = create_with_compiler(config, vec![]);
+  compiler.compile().unwrap();
+
+  let resources_map = compiler.context().resources_map.lock();
+  resources_map
+    .values()
+    .filter(|r| r.name.ends_with(".js"))
+    .map(|r| String::from_utf8(r.bytes.clone()).unwrap())
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Run the bundle with node and return the stdout
+fn run_bundle(code: String) -> String {
+  let dir = std::env::temp_dir().join(format!("farm-scope-hoisting-{}", std::process::id()));
+  std::fs::create_dir_all(&dir).unwrap();
+  let output = dir.join("index.js");
+  std::fs::write(&output, code).unwrap();
+
+  let result = Command::new("node").arg(&output).output().unwrap();
+  std::fs::remove_dir_all(&dir).ok();
+
+  assert!(
+    result.status.success(),
+    "{}",
+    String::from_utf8_lossy(&result.stderr)
+  );
+  String::from_utf8_lossy(&result.stdout).trim().to_string()
+}
+
+fn is_wrapped(code: &str, module: &str) -> bool {
+  let id = ModuleId::from(module).id(Mode::Production);
+  code.contains(&format!("{id:?}:function"))
+}
+
+#[test]
+fn scope_hoisting_concatenates_modules() {
+  fixture!(
+    "tests/fixtures/scope_hoisting/basic/index.ts",
+    |file, crate_path| {
+      let code = compile_with_concatenation(file, crate_path, false);
+
+      assert!(is_wrapped(&code, "index.ts"));
+      // dynamic imported
+      assert!(is_wrapped(&code, "lazy.ts"));
+      // imported by two roots
+      assert!(is_wrapped(&code, "math.ts"));
+      // commonjs
+      assert!(is_wrapped(&code, "side.ts"));
+
+      assert!(!is_wrapped(&code, "strings.ts"));
+      assert!(!is_wrapped(&code, "config.ts"));
+      assert!(code.contains("const upper = (s)=>s.toUpperCase();"));
+      // namespace import of a hoisted module
+      assert!(code.contains("var _namespace = {"));
+      assert!(code.contains("console.log(value, result, _namespace.upper(_default.name), {"));
+    }
+  );
+}
+
+#[test]
+fn scope_hoisting_renames_conflicting_bindings() {
+  fixture!(
+    "tests/fixtures/scope_hoisting/conflicts/index.ts",
+    |file, crate_path| {
+      let code = compile_with_concatenation(file, crate_path, false);
+
+      assert!(is_wrapped(&code, "index.ts"));
+      for module in ["a.ts", "b.ts", "counter.ts"] {
+        assert!(!is_wrapped(&code, module));
+      }
+
+      // the root keeps its names
+      assert!(code.contains(r#"const name = "index";"#));
+      assert!(code.contains(r#"const name1 = "b";"#));
+      assert!(code.contains(r#"const name2 = "a";"#));
+      // anonymous default export
+      assert!(code.contains("function _default() {"));
+      assert!(code.contains("console.log(name, a, b, describe(), _default(), count);"));
+    }
+  );
+}
+
+#[test]
+fn scope_hoisting_runs_hoisted_bundle() {
+  fixture!(
+    "tests/fixtures/scope_hoisting/conflicts/index.ts",
+    |file, crate_path| {
+      let code = compile_with_concatenation(file, crate_path, false);
+
+      assert_eq!(run_bundle(code), "index a b a imports b anonymous 1");
+    }
+  );
+}
+
+#[test]
+fn scope_hoisting_keeps_globals_of_hoisted_modules() {
+  fixture!(
+    "tests/fixtures/scope_hoisting/globals/index.ts",
+    |file, crate_path| {
+      let code = compile_with_concatenation(file, crate_path, true);
+
+      assert!(!is_wrapped(&code, "env.ts"));
+      assert_eq!(run_bundle(code), "first second e t n o s");
+    }
+  );
+}
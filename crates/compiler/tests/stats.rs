@@ -0,0 +1,65 @@
+use std::{collections::HashMap, sync::Arc};
+
+use farmfe_core::{
+  context::CompilationContext, error::Result, parking_lot::Mutex, plugin::Plugin, stats::Stats,
+};
+use farmfe_testing_helpers::fixture;
+
+use crate::common::create_compiler_with_plugins;
+
+mod common;
+
+#[derive(Default)]
+struct StatsCollectorPlugin {
+  stats: Mutex<Option<Stats>>,
+}
+
+impl Plugin for StatsCollectorPlugin {
+  fn name(&self) -> &str {
+    "StatsCollectorPlugin"
+  }
+
+  fn finish(&self, stat: &Stats, _context: &Arc<CompilationContext>) -> Result<Option<()>> {
+    self.stats.lock().replace(stat.clone());
+    Ok(Some(()))
+  }
+}
+
+#[test]
+fn finish_hook_receives_stats() {
+  fixture!("tests/fixtures/stats/basic/index.ts", |file, crate_path| {
+    let cwd = file.parent().unwrap();
+    let plugin = Arc::new(StatsCollectorPlugin::default());
+
+    let compiler = create_compiler_with_plugins(
+      HashMap::from([("index".to_string(), "./index.ts".to_string())]),
+      cwd.to_path_buf(),
+      crate_path,
+      false,
+      vec![plugin.clone()],
+    );
+    compiler.compile().unwrap();
+
+    let stats = plugin
+      .stats
+      .lock()
+      .take()
+      .expect("finish hook is not called");
+
+    let module_count = compiler.context().module_graph.read().modules().len();
+    assert_eq!(stats.module_counts.values().sum::<usize>(), module_count);
+    assert_eq!(stats.module_counts.get("runtime"), Some(&1));
+
+    let resources_map = compiler.context().resources_map.lock();
+    assert_eq!(stats.resources.len(), resources_map.len());
+
+    for resource in &stats.resources {
+      assert_eq!(resource.size, resources_map[&resource.name].bytes.len());
+    }
+
+    // persistent cache is disabled
+    assert_eq!(stats.cache.module_cache_hits, 0);
+    assert_eq!(stats.cache.module_cache_misses, 0);
+    assert!(stats.timings.build > 0.0);
+  });
+}
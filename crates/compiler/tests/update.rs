@@ -2,15 +2,19 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
 
-use common::generate_runtime;
+use common::{create_compiler_with_plugins, generate_runtime};
 use farmfe_compiler::{Compiler, DYNAMIC_VIRTUAL_SUFFIX};
 use farmfe_core::config::bool_or_obj::BoolOrObj;
 use farmfe_core::config::config_regex::ConfigRegex;
 use farmfe_core::config::persistent_cache::PersistentCacheConfig;
 use farmfe_core::config::TargetEnv;
 use farmfe_core::config::{preset_env::PresetEnvConfig, Config, Mode, SourcemapConfig};
-use farmfe_core::plugin::UpdateType;
+use farmfe_core::context::CompilationContext;
+use farmfe_core::error::CompilationError;
+use farmfe_core::plugin::{Plugin, PluginFinalizeResourcesHookParams, UpdateType};
 use farmfe_testing_helpers::fixture;
 
 mod common;
@@ -127,7 +131,7 @@ fn update_without_dependencies_change() {
         .to_string_lossy()
         .to_string();
       let result = compiler
-        .update(vec![(update_file, UpdateType::Updated)], || {}, true, true)
+        .update(vec![(update_file, UpdateType::Updated)], |_| {}, true, true)
         .unwrap();
 
       assert_eq!(result.added_module_ids.len(), 0);
@@ -163,7 +167,7 @@ fn update_without_dependencies_change_css() {
       let result = compiler
         .update(
           vec![(update_file.clone(), UpdateType::Updated)],
-          || {},
+          |_| {},
           true,
           true,
         )
@@ -176,7 +180,12 @@ fn update_without_dependencies_change_css() {
       asset_update_result_code(cwd.clone(), &result, Some("update1"));
 
       let result = compiler
-        .update(vec![(update_file, UpdateType::Updated)], || {}, false, true)
+        .update(
+          vec![(update_file, UpdateType::Updated)],
+          |_| {},
+          false,
+          true,
+        )
         .unwrap();
 
       assert_eq!(result.added_module_ids.len(), 0);
@@ -229,7 +238,7 @@ fn update_with_dependencies_change_css_modules() {
       let result = compiler
         .update(
           vec![(update_file.clone(), UpdateType::Updated)],
-          || {},
+          |_| {},
           true,
           true,
         )
@@ -257,7 +266,7 @@ fn update_with_dependencies_change_css_modules() {
       let result = compiler
         .update(
           vec![(update_file_css.clone(), UpdateType::Updated)],
-          || {},
+          |_| {},
           true,
           true,
         )
@@ -281,7 +290,12 @@ fn update_with_dependencies_change_css_modules() {
       let mut original_ts_file = File::create(&update_file).unwrap();
       original_ts_file.write_all(original_ts.as_bytes()).unwrap();
       let result = compiler
-        .update(vec![(update_file, UpdateType::Updated)], || {}, false, true)
+        .update(
+          vec![(update_file, UpdateType::Updated)],
+          |_| {},
+          false,
+          true,
+        )
         .unwrap();
 
       assert_eq!(result.added_module_ids.len(), 0);
@@ -319,7 +333,7 @@ fn update_css_and_css_raw() {
       .to_string();
 
     let result = compiler
-      .update(vec![(update_file, UpdateType::Updated)], || {}, true, true)
+      .update(vec![(update_file, UpdateType::Updated)], |_| {}, true, true)
       .unwrap();
 
     assert_eq!(
@@ -360,7 +374,7 @@ fn update_lazy_compilation() {
       let result = compiler
         .update(
           vec![(update_module_id.clone(), UpdateType::Updated)],
-          || {},
+          |_| {},
           true,
           true,
         )
@@ -397,7 +411,7 @@ fn update_lazy_compilation_node() {
       let result = compiler
         .update(
           vec![(update_module_id.clone(), UpdateType::Updated)],
-          || {},
+          |_| {},
           true,
           true,
         )
@@ -414,3 +428,160 @@ fn update_lazy_compilation_node() {
     }
   );
 }
+
+#[test]
+fn update_added_and_removed_files() {
+  fixture!(
+    "tests/fixtures/update/added-removed/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap().to_path_buf();
+      let compiler = create_update_compiler(
+        HashMap::from([("index".to_string(), "./index.ts".to_string())]),
+        cwd.clone(),
+        crate_path,
+        false,
+      );
+
+      compiler.compile().unwrap();
+
+      let dep_file = cwd.join("dep.ts").to_string_lossy().to_string();
+      let dep_content = std::fs::read_to_string(&dep_file).unwrap();
+
+      // the importer is rebuilt and fails to resolve the removed file
+      std::fs::remove_file(&dep_file).unwrap();
+      let result = compiler.update(
+        vec![(dep_file.clone(), UpdateType::Removed)],
+        |_| {},
+        true,
+        true,
+      );
+      std::fs::write(&dep_file, dep_content).unwrap();
+
+      assert!(result.is_err());
+      assert!(compiler
+        .context()
+        .failed_resolve_importers
+        .lock()
+        .contains_key(&"index.ts".into()));
+      assert!(compiler.is_missing_dependency(&dep_file));
+      assert!(!compiler.is_missing_dependency(&cwd.join("other.ts").to_string_lossy()));
+
+      // the importer is rebuilt when the missing file is added back
+      let result = compiler
+        .update(vec![(dep_file, UpdateType::Added)], |_| {}, true, true)
+        .unwrap();
+
+      assert!(result.updated_module_ids.contains(&"index.ts".into()));
+      assert!(compiler
+        .context()
+        .failed_resolve_importers
+        .lock()
+        .is_empty());
+    }
+  );
+}
+
+#[derive(Default)]
+struct FailingFinalizePlugin {
+  fail: AtomicBool,
+}
+
+impl Plugin for FailingFinalizePlugin {
+  fn name(&self) -> &str {
+    "FailingFinalizePlugin"
+  }
+
+  fn finalize_resources(
+    &self,
+    _param: &mut PluginFinalizeResourcesHookParams,
+    _context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<()>> {
+    if self.fail.load(Ordering::SeqCst) {
+      return Err(CompilationError::GenericError(
+        "finalize resources failed".to_string(),
+      ));
+    }
+
+    Ok(None)
+  }
+}
+
+#[test]
+fn update_regenerate_resources_error() {
+  fixture!(
+    "tests/fixtures/update/added-removed/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap().to_path_buf();
+      let plugin = Arc::new(FailingFinalizePlugin::default());
+      let compiler = create_compiler_with_plugins(
+        HashMap::from([("index".to_string(), "./index.ts".to_string())]),
+        cwd.clone(),
+        crate_path,
+        false,
+        vec![plugin.clone()],
+      );
+
+      compiler.compile().unwrap();
+
+      let resources = || {
+        let resources_map = compiler.context().resources_map.lock();
+        let mut resources = resources_map
+          .iter()
+          .map(|(name, resource)| (name.clone(), resource.bytes.clone()))
+          .collect::<Vec<_>>();
+        resources.sort();
+        // the resources recorded by the resource pots are restored too
+        let resource_pot_map = compiler.context().resource_pot_map.read();
+        let mut resource_pots = resource_pot_map
+          .resource_pots()
+          .into_iter()
+          .map(|rp| {
+            let mut names = rp.resources().into_iter().cloned().collect::<Vec<_>>();
+            names.sort();
+            (rp.id.clone(), names)
+          })
+          .collect::<Vec<_>>();
+        resource_pots.sort();
+        (resources, resource_pots)
+      };
+      let previous_resources = resources();
+      let update_file = file.to_string_lossy().to_string();
+      plugin.fail.store(true, Ordering::SeqCst);
+
+      // the error is returned and the previous resources are kept
+      let result = compiler.update(
+        vec![(update_file.clone(), UpdateType::Updated)],
+        |_| {},
+        true,
+        true,
+      );
+      let err = result.err().unwrap().to_string();
+      assert!(err.contains("finalize resources failed"));
+      assert_eq!(resources(), previous_resources);
+
+      // the error is passed to the callback when the resources are regenerated asynchronously
+      let (sender, receiver) = mpsc::channel();
+      compiler
+        .update(
+          vec![(update_file.clone(), UpdateType::Updated)],
+          move |result| sender.send(result).unwrap(),
+          false,
+          true,
+        )
+        .unwrap();
+      assert!(receiver.recv().unwrap().is_err());
+      assert_eq!(resources(), previous_resources);
+
+      // recover on the next update
+      plugin.fail.store(false, Ordering::SeqCst);
+      compiler
+        .update(
+          vec![(update_file, UpdateType::Updated)],
+          |result| result.unwrap(),
+          true,
+          true,
+        )
+        .unwrap();
+    }
+  );
+}
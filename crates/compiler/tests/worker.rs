@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use farmfe_core::{
+  config::{Mode, TargetEnv},
+  plugin::UpdateType,
+};
+use farmfe_testing_helpers::fixture;
+
+use crate::common::{create_config, create_with_compiler};
+
+mod common;
+
+fn create_worker_compiler(
+  file: &std::path::Path,
+  crate_path: std::path::PathBuf,
+  mode: Mode,
+) -> farmfe_compiler::Compiler {
+  let cwd = file.parent().unwrap();
+  let mut config = create_config(cwd.to_path_buf(), crate_path);
+  config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+  config.output.target_env = TargetEnv::Browser;
+  config.mode = mode;
+
+  create_with_compiler(config, vec![])
+}
+
+#[test]
+fn worker_compiled_as_separate_entry() {
+  fixture!(
+    "tests/fixtures/worker/basic/index.ts",
+    |file, crate_path| {
+      let compiler = create_worker_compiler(&file, crate_path, Mode::Production);
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let code_of = |name: &str| {
+        let resource = resources_map
+          .values()
+          .find(|r| r.name == name && !r.emitted)
+          .unwrap_or_else(|| panic!("resource {name} should be emitted"));
+        String::from_utf8(resource.bytes.clone()).unwrap()
+      };
+
+      let entry = code_of("index.js");
+      assert!(entry.contains(r#"new Worker("/worker.worker.js", {"#));
+      assert!(entry.contains(r#"new SharedWorker("/shared.worker.js")"#));
+      assert!(!entry.contains("import.meta.url"));
+      assert!(!entry.contains("hello"));
+
+      // every worker has its own runtime and modules
+      for worker in ["worker.worker.js", "shared.worker.js"] {
+        let code = code_of(worker);
+        assert!(code.contains("farmModuleSystem.bootstrap()"));
+        assert!(code.contains("hello ${name}"));
+      }
+    }
+  );
+}
+
+#[test]
+fn worker_url_with_absolute_public_path() {
+  fixture!(
+    "tests/fixtures/worker/basic/index.ts",
+    |file, crate_path| {
+      let cwd = file.parent().unwrap();
+      let mut config = create_config(cwd.to_path_buf(), crate_path);
+      config.input = HashMap::from([("index".to_string(), "./index.ts".to_string())]);
+      config.output.public_path = "https://cdn.com/assets/".to_string();
+
+      let compiler = create_with_compiler(config, vec![]);
+      compiler.compile().unwrap();
+
+      let resources_map = compiler.context().resources_map.lock();
+      let entry = String::from_utf8(resources_map.get("index.js").unwrap().bytes.clone()).unwrap();
+      assert!(entry.contains(r#"new Worker("https://cdn.com/assets/worker.worker.js", {"#));
+      assert!(entry.contains(r#"new SharedWorker("https://cdn.com/assets/shared.worker.js")"#));
+    }
+  );
+}
+
+#[test]
+fn worker_update_reloads_page() {
+  fixture!(
+    "tests/fixtures/worker/basic/index.ts",
+    |file, crate_path| {
+      let compiler = create_worker_compiler(&file, crate_path, Mode::Development);
+      compiler.compile().unwrap();
+
+      let update_file = file
+        .parent()
+        .unwrap()
+        .join("utils.ts")
+        .to_string_lossy()
+        .to_string();
+      let result = compiler
+        .update(vec![(update_file, UpdateType::Updated)], |_| {}, true, true)
+        .unwrap();
+
+      assert_eq!(result.updated_module_ids, vec!["index.ts".into()]);
+      assert_eq!(result.immutable_resources, "window.location.reload()");
+    }
+  );
+}
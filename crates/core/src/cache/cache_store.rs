@@ -1,181 +1,162 @@
-//! Cache store of the persistent cache, responsible for reading and writing the cache from the disk.
-use dashmap::{mapref::multiple::RefMulti, DashMap};
+//! Low level cache stores of the persistent cache. [DiskCacheStore] is used by default,
+//! `RemoteCacheStore` shares the caches through a http store when `persistentCache.remote` is configured,
+//! it's only available with the `remote_cache` feature.
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-use std::{
-  collections::HashMap,
-  path::{Path, PathBuf},
+use crate::{
+  config::{persistent_cache::RemoteCacheConfig, Mode},
+  context::log_store::LogStore,
+  error::{CompilationError, Result},
 };
 
-use crate::config::Mode;
+pub use disk_cache_store::DiskCacheStore;
+#[cfg(feature = "remote_cache")]
+pub use remote_cache_store::RemoteCacheStore;
+
+pub mod disk_cache_store;
+#[cfg(feature = "remote_cache")]
+pub mod remote_cache_store;
 
 const FARM_CACHE_VERSION: &str = "0.4.1";
 const FARM_CACHE_MANIFEST_FILE: &str = "farm-cache.json";
 
-// TODO make CacheStore a trait and implement DiskCacheStore or RemoteCacheStore or more.
-#[derive(Default)]
-pub struct CacheStore {
-  cache_dir: PathBuf,
-  /// name -> cache key manifest of this store.
-  /// it will be stored in a separate file
-  manifest: DashMap<String, String>,
-}
-
-impl CacheStore {
-  pub fn new(cache_dir_str: &str, namespace: &str, mode: Mode, name: &str) -> Self {
-    let mut cache_dir = Path::new(cache_dir_str).to_path_buf();
-    let last = cache_dir
-      .file_name()
-      .unwrap_or_default()
-      .to_string_lossy()
-      .to_string();
-    cache_dir.pop();
-
-    cache_dir.push(format!("{FARM_CACHE_VERSION}-{last}"));
-
-    if !namespace.is_empty() {
-      cache_dir.push(namespace);
-    }
-
-    if matches!(mode, Mode::Development) {
-      cache_dir.push("development");
-    } else {
-      cache_dir.push("production");
-    }
-
-    if !name.is_empty() {
-      cache_dir.push(name);
-    }
-
-    let manifest_file_path = cache_dir.join(FARM_CACHE_MANIFEST_FILE);
+pub trait CacheStore: Send + Sync {
+  fn has_cache(&self, name: &str) -> bool;
+  /// return true if the cache changed or it's a cache item
+  fn is_cache_changed(&self, store_key: &CacheStoreKey) -> bool;
+  fn write_single_cache(&self, store_key: CacheStoreKey, bytes: Vec<u8>) -> std::io::Result<()>;
+  /// Write the name -> cache key manifest of the store
+  fn write_manifest(&self);
+  fn read_cache(&self, name: &str) -> Option<Vec<u8>>;
+
+  /// Write the cache map to the store.
+  fn write_cache(&self, cache_map: HashMap<CacheStoreKey, Vec<u8>>) {
+    cache_map
+      .into_par_iter()
+      .try_for_each(|(store_key, bytes)| self.write_single_cache(store_key, bytes))
+      .unwrap();
 
-    let manifest = if manifest_file_path.exists() && manifest_file_path.is_file() {
-      let content = std::fs::read_to_string(manifest_file_path).unwrap();
-      let map = serde_json::from_str::<HashMap<String, String>>(&content).unwrap();
-      let dashmap = DashMap::new();
+    self.write_manifest();
+  }
+}
 
-      for (k, v) in map {
-        dashmap.insert(k, v);
-      }
+/// Cache key of the store, it's a pair of (name, cache_key), a name should only be related to one cache key.
+/// Previous cache will be cleared if the related cache key changed for a name
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheStoreKey {
+  pub name: String,
+  pub key: String,
+}
 
-      dashmap
-    } else {
-      DashMap::new()
-    };
+/// Creates the cache stores of the persistent cache
+#[derive(Debug, Clone, Default)]
+pub struct CacheStoreFactory {
+  cache_dir: String,
+  namespace: String,
+  mode: Mode,
+  remote: Option<RemoteCacheConfig>,
+  /// the remote cache store reports a warning when it's not available
+  #[cfg_attr(not(feature = "remote_cache"), allow(dead_code))]
+  log_store: Arc<Mutex<LogStore>>,
+}
 
-    Self {
-      cache_dir,
-      manifest,
+impl CacheStoreFactory {
+  pub fn new(
+    cache_dir: &str,
+    namespace: &str,
+    mode: Mode,
+    remote: Option<RemoteCacheConfig>,
+    log_store: Arc<Mutex<LogStore>>,
+  ) -> Result<Self> {
+    if cfg!(not(feature = "remote_cache")) && remote.is_some() {
+      return Err(CompilationError::GenericError(
+        "`persistentCache.remote` is configured, but farm is built without the `remote_cache` feature of farmfe_core".to_string(),
+      ));
     }
-  }
 
-  pub fn has_cache(&self, name: &str) -> bool {
-    self.manifest.contains_key(name)
+    Ok(Self {
+      cache_dir: cache_dir.to_string(),
+      namespace: namespace.to_string(),
+      mode,
+      remote,
+      log_store,
+    })
   }
 
-  pub fn get_store_keys(&self) -> Vec<RefMulti<String, String>> {
-    self.manifest.iter().collect()
+  pub fn cache_dir(&self) -> &str {
+    &self.cache_dir
   }
 
-  /// return true if the cache changed or it's a cache item
-  pub fn is_cache_changed(&self, store_key: &CacheStoreKey) -> bool {
-    if let Some(guard) = self.manifest.get(&store_key.name) {
-      if guard.value() == &store_key.key {
-        // the cache is not changed
-        return false;
-      }
+  pub fn create_cache_store(&self, name: &str) -> Box<dyn CacheStore> {
+    let disk_store = DiskCacheStore::new(&self.cache_dir, &self.namespace, self.mode.clone(), name);
+
+    match &self.remote {
+      #[cfg(feature = "remote_cache")]
+      Some(remote) => Box::new(RemoteCacheStore::new(
+        disk_store,
+        remote,
+        &cache_store_path(&self.cache_dir, &self.namespace, self.mode.clone(), name).join("/"),
+        self.log_store.clone(),
+      )),
+      _ => Box::new(disk_store),
     }
-
-    true
   }
+}
 
-  pub fn write_single_cache(
-    &self,
-    store_key: CacheStoreKey,
-    bytes: Vec<u8>,
-  ) -> std::io::Result<()> {
-    let cache_file_dir = &self.cache_dir;
-
-    if !cache_file_dir.exists() {
-      std::fs::create_dir_all(cache_file_dir).unwrap();
-    }
-
-    if self.is_cache_changed(&store_key) {
-      if let Some(guard) = self.manifest.get(&store_key.name) {
-        let cache_file_path = cache_file_dir.join(guard.value());
-
-        if cache_file_path.exists() && cache_file_path.is_file() {
-          std::fs::remove_file(cache_file_path).ok();
-        }
-      }
-
-      self
-        .manifest
-        .insert(store_key.name.clone(), store_key.key.clone());
-      let cache_file_path = cache_file_dir.join(store_key.key);
-      std::fs::write(&cache_file_path, bytes).map_err(|e| {
-        std::io::Error::new(
-          e.kind(),
-          format!(
-            "Failed to write cache file: {} {:?}, error: {:?}",
-            store_key.name, cache_file_path, e
-          ),
-        )
-      })?;
-    }
-
-    Ok(())
+/// Path segments of a store relative to the parent of the cache dir, e.g. `0.4.1-<hash>/farm-cache/development/plugin`.
+/// The last segment of the cache dir is the hash of the build dependencies
+fn cache_store_path(cache_dir: &str, namespace: &str, mode: Mode, name: &str) -> Vec<String> {
+  let last = Path::new(cache_dir)
+    .file_name()
+    .unwrap_or_default()
+    .to_string_lossy()
+    .to_string();
+  let mut segments = vec![format!("{FARM_CACHE_VERSION}-{last}")];
+
+  if !namespace.is_empty() {
+    segments.push(namespace.to_string());
   }
 
-  pub fn write_manifest(&self) {
-    let manifest = self.manifest.clone().into_iter().collect::<HashMap<_, _>>();
-    let manifest_file_path = &self.cache_dir.join(FARM_CACHE_MANIFEST_FILE);
-    std::fs::write(
-      manifest_file_path,
-      serde_json::to_string(&manifest).unwrap(),
-    )
-    .unwrap();
+  if matches!(mode, Mode::Development) {
+    segments.push("development".to_string());
+  } else {
+    segments.push("production".to_string());
   }
 
-  /// Write the cache map to the disk.
-  pub fn write_cache(&self, cache_map: HashMap<CacheStoreKey, Vec<u8>>) {
-    let cache_file_dir = &self.cache_dir;
-
-    if !cache_file_dir.exists() {
-      std::fs::create_dir_all(cache_file_dir).unwrap();
-    }
-
-    cache_map
-      .into_par_iter()
-      .try_for_each(|(store_key, bytes)| {
-        self.write_single_cache(store_key, bytes)?;
-        Ok::<(), std::io::Error>(())
-      })
-      .unwrap();
-
-    self.write_manifest();
+  if !name.is_empty() {
+    segments.push(name.to_string());
   }
 
-  pub fn read_cache(&self, name: &str) -> Option<Vec<u8>> {
-    if !self.manifest.contains_key(name) {
-      return None;
-    }
-
-    let cache_key = self.manifest.get(name).unwrap().value().clone();
-    let cache_file = self.cache_dir.join(cache_key);
-
-    if cache_file.exists() && cache_file.is_file() {
-      return Some(std::fs::read(cache_file).unwrap());
-    }
-
-    None
-  }
+  segments
 }
 
-/// Cache key of the store, it's a pair of (name, cache_key), a name should only be related to one cache key.
-/// Previous cache will be cleared if the related cache key changed for a name
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct CacheStoreKey {
-  pub name: String,
-  pub key: String,
+#[cfg(all(test, not(feature = "remote_cache")))]
+mod tests {
+  use crate::config::{persistent_cache::RemoteCacheConfig, Mode};
+
+  use super::CacheStoreFactory;
+
+  #[test]
+  fn remote_cache_requires_feature() {
+    let result = CacheStoreFactory::new(
+      "cache",
+      "farm-cache",
+      Mode::Development,
+      Some(RemoteCacheConfig {
+        url: "http://127.0.0.1:8080".to_string(),
+        ..Default::default()
+      }),
+      Default::default(),
+    );
+
+    assert!(result
+      .unwrap_err()
+      .to_string()
+      .contains("`remote_cache` feature"));
+  }
 }
@@ -0,0 +1,136 @@
+//! Cache store of the persistent cache, responsible for reading and writing the cache from the disk.
+use dashmap::{mapref::multiple::RefMulti, DashMap};
+
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+};
+
+use crate::config::Mode;
+
+use super::{cache_store_path, CacheStore, CacheStoreKey, FARM_CACHE_MANIFEST_FILE};
+
+#[derive(Default)]
+pub struct DiskCacheStore {
+  cache_dir: PathBuf,
+  /// name -> cache key manifest of this store.
+  /// it will be stored in a separate file
+  manifest: DashMap<String, String>,
+}
+
+impl DiskCacheStore {
+  pub fn new(cache_dir_str: &str, namespace: &str, mode: Mode, name: &str) -> Self {
+    let mut cache_dir = Path::new(cache_dir_str).to_path_buf();
+    cache_dir.pop();
+
+    for segment in cache_store_path(cache_dir_str, namespace, mode, name) {
+      cache_dir.push(segment);
+    }
+
+    let manifest_file_path = cache_dir.join(FARM_CACHE_MANIFEST_FILE);
+
+    let manifest = if manifest_file_path.exists() && manifest_file_path.is_file() {
+      let content = std::fs::read_to_string(manifest_file_path).unwrap();
+      let map = serde_json::from_str::<HashMap<String, String>>(&content).unwrap();
+      let dashmap = DashMap::new();
+
+      for (k, v) in map {
+        dashmap.insert(k, v);
+      }
+
+      dashmap
+    } else {
+      DashMap::new()
+    };
+
+    Self {
+      cache_dir,
+      manifest,
+    }
+  }
+
+  pub fn get_store_keys(&self) -> Vec<RefMulti<String, String>> {
+    self.manifest.iter().collect()
+  }
+}
+
+impl CacheStore for DiskCacheStore {
+  fn has_cache(&self, name: &str) -> bool {
+    self.manifest.contains_key(name)
+  }
+
+  /// return true if the cache changed or it's a cache item
+  fn is_cache_changed(&self, store_key: &CacheStoreKey) -> bool {
+    if let Some(guard) = self.manifest.get(&store_key.name) {
+      if guard.value() == &store_key.key {
+        // the cache is not changed
+        return false;
+      }
+    }
+
+    true
+  }
+
+  fn write_single_cache(&self, store_key: CacheStoreKey, bytes: Vec<u8>) -> std::io::Result<()> {
+    let cache_file_dir = &self.cache_dir;
+
+    if !cache_file_dir.exists() {
+      std::fs::create_dir_all(cache_file_dir).unwrap();
+    }
+
+    if self.is_cache_changed(&store_key) {
+      if let Some(guard) = self.manifest.get(&store_key.name) {
+        let cache_file_path = cache_file_dir.join(guard.value());
+
+        if cache_file_path.exists() && cache_file_path.is_file() {
+          std::fs::remove_file(cache_file_path).ok();
+        }
+      }
+
+      self
+        .manifest
+        .insert(store_key.name.clone(), store_key.key.clone());
+      let cache_file_path = cache_file_dir.join(store_key.key);
+      std::fs::write(&cache_file_path, bytes).map_err(|e| {
+        std::io::Error::new(
+          e.kind(),
+          format!(
+            "Failed to write cache file: {} {:?}, error: {:?}",
+            store_key.name, cache_file_path, e
+          ),
+        )
+      })?;
+    }
+
+    Ok(())
+  }
+
+  fn write_manifest(&self) {
+    if !self.cache_dir.exists() {
+      std::fs::create_dir_all(&self.cache_dir).unwrap();
+    }
+
+    let manifest = self.manifest.clone().into_iter().collect::<HashMap<_, _>>();
+    let manifest_file_path = &self.cache_dir.join(FARM_CACHE_MANIFEST_FILE);
+    std::fs::write(
+      manifest_file_path,
+      serde_json::to_string(&manifest).unwrap(),
+    )
+    .unwrap();
+  }
+
+  fn read_cache(&self, name: &str) -> Option<Vec<u8>> {
+    if !self.manifest.contains_key(name) {
+      return None;
+    }
+
+    let cache_key = self.manifest.get(name).unwrap().value().clone();
+    let cache_file = self.cache_dir.join(cache_key);
+
+    if cache_file.exists() && cache_file.is_file() {
+      return Some(std::fs::read(cache_file).unwrap());
+    }
+
+    None
+  }
+}
@@ -0,0 +1,402 @@
+//! Cache store that shares the caches through a http content-addressed store.
+use std::{
+  collections::HashMap,
+  io::Read,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+  time::Duration,
+};
+
+use dashmap::DashMap;
+use farmfe_utils::hash::sha256;
+use parking_lot::Mutex;
+
+use crate::{
+  config::persistent_cache::RemoteCacheConfig, context::log_store::LogStore,
+  error::diagnostic::Diagnostic,
+};
+
+use super::{
+  disk_cache_store::DiskCacheStore, CacheStore, CacheStoreKey, FARM_CACHE_MANIFEST_FILE,
+};
+
+/// A cache is read by `GET <url>/<object key>` and written by `PUT <url>/<object key>`. The object key is the hash of
+/// the store path and the cache key, so the stores of different namespaces and modes never share an object.
+/// The name -> cache key manifest is stored under the hash of the store path and the manifest file name,
+/// so that the stores of other machines can find it.
+///
+/// The caches are always written to the disk store. The caches that are missing in the disk store are read from the
+/// remote store, and the reads fall back to the disk store when the remote store is not available
+pub struct RemoteCacheStore {
+  disk_store: DiskCacheStore,
+  agent: ureq::Agent,
+  url: String,
+  headers: HashMap<String, String>,
+  read_only: bool,
+  store_path: String,
+  /// name -> cache key manifest of the remote store
+  manifest: DashMap<String, String>,
+  /// the remote store is skipped after a request fails, e.g. the server is down
+  available: AtomicBool,
+  log_store: Arc<Mutex<LogStore>>,
+}
+
+impl RemoteCacheStore {
+  pub fn new(
+    disk_store: DiskCacheStore,
+    config: &RemoteCacheConfig,
+    store_path: &str,
+    log_store: Arc<Mutex<LogStore>>,
+  ) -> Self {
+    let store = Self {
+      disk_store,
+      agent: ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(30))
+        .build(),
+      url: config.url.trim_end_matches('/').to_string(),
+      headers: config.headers.clone(),
+      read_only: config.read_only,
+      store_path: store_path.to_string(),
+      manifest: DashMap::new(),
+      available: AtomicBool::new(true),
+      log_store,
+    };
+
+    if let Some(bytes) = store.get(FARM_CACHE_MANIFEST_FILE) {
+      let manifest: HashMap<String, String> = serde_json::from_slice(&bytes).unwrap_or_default();
+
+      for (name, key) in manifest {
+        store.manifest.insert(name, key);
+      }
+    }
+
+    store
+  }
+
+  fn request(&self, method: &str, key: &str) -> ureq::Request {
+    let object_key = sha256(format!("{}/{key}", self.store_path).as_bytes(), 32);
+    let mut request = self
+      .agent
+      .request(method, &format!("{}/{object_key}", self.url));
+
+    for (name, value) in &self.headers {
+      request = request.set(name, value);
+    }
+
+    request
+  }
+
+  fn get(&self, key: &str) -> Option<Vec<u8>> {
+    if !self.available.load(Ordering::Relaxed) {
+      return None;
+    }
+
+    match self.request("GET", key).call() {
+      Ok(response) => {
+        let mut bytes = vec![];
+        response.into_reader().read_to_end(&mut bytes).ok()?;
+        Some(bytes)
+      }
+      // the cache does not exist in the remote store
+      Err(ureq::Error::Status(404, _)) => None,
+      Err(e) => {
+        self.disable(&e.to_string());
+        None
+      }
+    }
+  }
+
+  fn put(&self, key: &str, bytes: &[u8]) -> bool {
+    if !self.available.load(Ordering::Relaxed) {
+      return false;
+    }
+
+    match self.request("PUT", key).send_bytes(bytes) {
+      Ok(_) => true,
+      Err(e) => {
+        self.disable(&e.to_string());
+        false
+      }
+    }
+  }
+
+  fn disable(&self, reason: &str) {
+    if self.available.swap(false, Ordering::Relaxed) {
+      self
+        .log_store
+        .lock()
+        .add_warning(Diagnostic::warning(format!(
+          "remote cache {} is not available, only the disk cache is used: {reason}",
+          self.url
+        )));
+    }
+  }
+}
+
+impl CacheStore for RemoteCacheStore {
+  fn has_cache(&self, name: &str) -> bool {
+    self.disk_store.has_cache(name) || self.manifest.contains_key(name)
+  }
+
+  fn is_cache_changed(&self, store_key: &CacheStoreKey) -> bool {
+    let remote_changed = self
+      .manifest
+      .get(&store_key.name)
+      .map_or(true, |key| key.value() != &store_key.key);
+
+    self.disk_store.is_cache_changed(store_key) && remote_changed
+  }
+
+  fn write_single_cache(&self, store_key: CacheStoreKey, bytes: Vec<u8>) -> std::io::Result<()> {
+    let remote_changed = self
+      .manifest
+      .get(&store_key.name)
+      .map_or(true, |key| key.value() != &store_key.key);
+
+    if !self.read_only && remote_changed && self.put(&store_key.key, &bytes) {
+      self
+        .manifest
+        .insert(store_key.name.clone(), store_key.key.clone());
+    }
+
+    self.disk_store.write_single_cache(store_key, bytes)
+  }
+
+  fn write_manifest(&self) {
+    self.disk_store.write_manifest();
+
+    if !self.read_only {
+      let manifest = self.manifest.clone().into_iter().collect::<HashMap<_, _>>();
+      self.put(
+        FARM_CACHE_MANIFEST_FILE,
+        serde_json::to_string(&manifest).unwrap().as_bytes(),
+      );
+    }
+  }
+
+  fn read_cache(&self, name: &str) -> Option<Vec<u8>> {
+    if !self.disk_store.has_cache(name) {
+      if let Some(key) = self.manifest.get(name) {
+        if let Some(bytes) = self.get(key.value()) {
+          return Some(bytes);
+        }
+      }
+    }
+
+    self.disk_store.read_cache(name)
+  }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{
+      atomic::{AtomicBool, Ordering},
+      Arc,
+    },
+  };
+
+  use parking_lot::Mutex;
+
+  use crate::{
+    cache::cache_store::{CacheStore, CacheStoreFactory, CacheStoreKey},
+    config::{persistent_cache::RemoteCacheConfig, Mode},
+  };
+
+  /// A stand-in of the http store that keeps the caches in memory, returns the url of the store.
+  /// The store is turned off when `stopped` is set
+  pub(crate) fn start_store(
+    caches: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    stopped: Arc<AtomicBool>,
+  ) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+      for stream in listener.incoming() {
+        if stopped.load(Ordering::SeqCst) {
+          break;
+        }
+
+        let mut stream = stream.unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        let mut parts = request_line.split_whitespace();
+        let (method, key) = (parts.next().unwrap(), &parts.next().unwrap()[1..]);
+
+        let mut content_length = 0;
+        loop {
+          let mut header = String::new();
+          reader.read_line(&mut header).unwrap();
+
+          if header.trim().is_empty() {
+            break;
+          }
+
+          if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+              content_length = value.trim().parse().unwrap();
+            }
+          }
+        }
+
+        let (status, body) = if method == "PUT" {
+          let mut body = vec![0; content_length];
+          reader.read_exact(&mut body).unwrap();
+          caches.lock().insert(key.to_string(), body);
+          ("200 OK", vec![])
+        } else {
+          match caches.lock().get(key) {
+            Some(body) => ("200 OK", body.clone()),
+            None => ("404 Not Found", vec![]),
+          }
+        };
+
+        write!(
+          stream,
+          "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+          body.len()
+        )
+        .unwrap();
+        stream.write_all(&body).unwrap();
+      }
+    });
+
+    url
+  }
+
+  pub(crate) fn create_store_factory(
+    cache_dir: &str,
+    url: &str,
+    read_only: bool,
+    mode: Mode,
+  ) -> CacheStoreFactory {
+    CacheStoreFactory::new(
+      cache_dir,
+      "farm-cache",
+      mode,
+      Some(RemoteCacheConfig {
+        url: url.to_string(),
+        read_only,
+        ..Default::default()
+      }),
+      Default::default(),
+    )
+    .unwrap()
+  }
+
+  fn create_store(cache_dir: &str, url: &str, read_only: bool) -> Box<dyn CacheStore> {
+    create_store_factory(cache_dir, url, read_only, Mode::Development).create_cache_store("custom")
+  }
+
+  fn has_object(caches: &Mutex<HashMap<String, Vec<u8>>>, bytes: &[u8]) -> bool {
+    caches.lock().values().any(|object| object == bytes)
+  }
+
+  fn store_key(name: &str, key: &str) -> CacheStoreKey {
+    CacheStoreKey {
+      name: name.to_string(),
+      key: key.to_string(),
+    }
+  }
+
+  #[test]
+  fn remote_cache_store() {
+    let caches = Arc::new(Mutex::new(HashMap::new()));
+    let url = start_store(caches.clone(), Default::default());
+    let tmp = std::env::temp_dir().join(format!("farm-remote-cache-{}", std::process::id()));
+    let cache_dir = |machine: &str| {
+      tmp
+        .join(machine)
+        .join("cache")
+        .to_string_lossy()
+        .to_string()
+    };
+
+    // the remote store is read-only by default
+    let store = create_store(&cache_dir("a"), &url, true);
+    store.write_cache(HashMap::from([(
+      store_key("a.ts", "hash-a"),
+      b"a".to_vec(),
+    )]));
+    assert!(caches.lock().is_empty());
+    assert_eq!(store.read_cache("a.ts"), Some(b"a".to_vec()));
+
+    let store = create_store(&cache_dir("b"), &url, false);
+    store.write_cache(HashMap::from([(
+      store_key("b.ts", "hash-b"),
+      b"b".to_vec(),
+    )]));
+    assert!(has_object(&caches, b"b"));
+
+    // another machine reads the caches of the remote store, the disk store is preferred
+    let store = create_store(&cache_dir("c"), &url, true);
+    assert!(store.has_cache("b.ts"));
+    assert!(!store.is_cache_changed(&store_key("b.ts", "hash-b")));
+    assert!(store.is_cache_changed(&store_key("b.ts", "hash-b2")));
+    assert_eq!(store.read_cache("b.ts"), Some(b"b".to_vec()));
+    assert_eq!(store.read_cache("a.ts"), None);
+
+    store.write_cache(HashMap::from([(
+      store_key("b.ts", "hash-b2"),
+      b"b2".to_vec(),
+    )]));
+    assert_eq!(store.read_cache("b.ts"), Some(b"b2".to_vec()));
+    assert!(has_object(&caches, b"b"));
+    assert!(!has_object(&caches, b"b2"));
+
+    // reads fall back to the disk store when the remote store is not available
+    let store = create_store(&cache_dir("a"), "http://127.0.0.1:1", true);
+    assert_eq!(store.read_cache("a.ts"), Some(b"a".to_vec()));
+    assert!(!store.has_cache("b.ts"));
+
+    std::fs::remove_dir_all(tmp).ok();
+  }
+
+  #[test]
+  fn remote_cache_store_objects_are_namespaced() {
+    let caches = Arc::new(Mutex::new(HashMap::new()));
+    let url = start_store(caches.clone(), Default::default());
+    let tmp = std::env::temp_dir().join(format!(
+      "farm-remote-cache-namespaced-{}",
+      std::process::id()
+    ));
+    let cache_dir = |machine: &str| {
+      tmp
+        .join(machine)
+        .join("cache")
+        .to_string_lossy()
+        .to_string()
+    };
+
+    // the same cache key is written by the stores of different modes
+    for (machine, mode, bytes) in [
+      ("a", Mode::Development, b"dev"),
+      ("b", Mode::Production, b"pro"),
+    ] {
+      create_store_factory(&cache_dir(machine), &url, false, mode)
+        .create_cache_store("custom")
+        .write_cache(HashMap::from([(
+          store_key("a.ts", "hash-a"),
+          bytes.to_vec(),
+        )]));
+    }
+
+    for (machine, mode, bytes) in [
+      ("c", Mode::Development, b"dev"),
+      ("d", Mode::Production, b"pro"),
+    ] {
+      let store =
+        create_store_factory(&cache_dir(machine), &url, true, mode).create_cache_store("custom");
+      assert_eq!(store.read_cache("a.ts"), Some(bytes.to_vec()));
+    }
+
+    std::fs::remove_dir_all(tmp).ok();
+  }
+}
@@ -1,8 +1,18 @@
+use std::sync::Arc;
+
 use parking_lot::Mutex;
 
-use crate::config::Mode;
+use crate::{
+  config::{persistent_cache::RemoteCacheConfig, Mode},
+  context::log_store::LogStore,
+  error::Result,
+  stats::CacheStatsCounter,
+};
 
-use self::{cache_store::CacheStore, plugin_cache::PluginCacheManager};
+use self::{
+  cache_store::{CacheStore, CacheStoreFactory},
+  plugin_cache::PluginCacheManager,
+};
 
 pub mod cache_store;
 pub mod module_cache;
@@ -18,28 +28,37 @@ pub struct CacheManager {
   pub module_cache: module_cache::ModuleCacheManager,
   pub resource_cache: resource_cache::ResourceCacheManager,
   pub plugin_cache: PluginCacheManager,
-  pub lazy_compile_store: CacheStore,
+  pub lazy_compile_store: Box<dyn CacheStore>,
   /// cache store for custom caches
-  pub custom: CacheStore,
+  pub custom: Box<dyn CacheStore>,
   /// lock for cache manager
   pub lock: Mutex<bool>,
+  /// hits and misses of module and resource caches during the current compilation
+  pub stats: CacheStatsCounter,
 }
 
 impl CacheManager {
-  pub fn new(cache_dir: &str, namespace: &str, mode: Mode) -> Self {
-    let module_cache = module_cache::ModuleCacheManager::new(cache_dir, namespace, mode.clone());
-    let resource_cache =
-      resource_cache::ResourceCacheManager::new(cache_dir, namespace, mode.clone());
+  pub fn new(
+    cache_dir: &str,
+    namespace: &str,
+    mode: Mode,
+    remote: Option<RemoteCacheConfig>,
+    log_store: Arc<Mutex<LogStore>>,
+  ) -> Result<Self> {
+    let store_factory = CacheStoreFactory::new(cache_dir, namespace, mode, remote, log_store)?;
+    let module_cache = module_cache::ModuleCacheManager::new(&store_factory);
+    let resource_cache = resource_cache::ResourceCacheManager::new(&store_factory);
 
-    Self {
+    Ok(Self {
       module_cache,
       resource_cache,
       // plugin cache is not initialized here. it will be initialized when compile starts.
-      plugin_cache: PluginCacheManager::new(cache_dir, namespace, mode.clone()),
-      custom: CacheStore::new(cache_dir, namespace, mode.clone(), "custom"),
-      lazy_compile_store: CacheStore::new(cache_dir, namespace, mode, "lazy-compilation"),
+      plugin_cache: PluginCacheManager::new(&store_factory),
+      custom: store_factory.create_cache_store("custom"),
+      lazy_compile_store: store_factory.create_cache_store("lazy-compilation"),
       lock: Mutex::new(false),
-    }
+      stats: CacheStatsCounter::default(),
+    })
   }
 
   pub fn write_cache(&self) {
@@ -2,7 +2,7 @@ use dashmap::mapref::one::{Ref, RefMut};
 
 use farmfe_macro_cache_item::cache_item;
 
-use crate::config::Mode;
+use crate::cache::cache_store::CacheStoreFactory;
 use crate::module::module_graph::ModuleGraphEdge;
 use crate::module::{Module, ModuleId};
 use crate::plugin::PluginAnalyzeDepsHookResultEntry;
@@ -76,10 +76,10 @@ impl CachedModule {
 }
 
 impl ModuleCacheManager {
-  pub fn new(cache_dir_str: &str, namespace: &str, mode: Mode) -> Self {
+  pub fn new(store_factory: &CacheStoreFactory) -> Self {
     Self {
-      mutable_modules_store: MutableModulesMemoryStore::new(cache_dir_str, namespace, mode.clone()),
-      immutable_modules_store: ImmutableModulesMemoryStore::new(cache_dir_str, namespace, mode),
+      mutable_modules_store: MutableModulesMemoryStore::new(store_factory),
+      immutable_modules_store: ImmutableModulesMemoryStore::new(store_factory),
     }
   }
 
@@ -8,10 +8,9 @@ use rkyv::Deserialize;
 
 use crate::{
   cache::{
-    cache_store::{CacheStore, CacheStoreKey},
+    cache_store::{CacheStore, CacheStoreFactory, CacheStoreKey},
     utils::cache_panic,
   },
-  config::Mode,
   module::ModuleId,
 };
 
@@ -40,7 +39,7 @@ impl CachedPackage {
 pub struct ImmutableModulesMemoryStore {
   cache_dir: String,
   /// low level cache store
-  store: CacheStore,
+  store: Box<dyn CacheStore>,
   /// ModuleId -> Cached Module
   cached_modules: DashMap<ModuleId, CachedModule>,
   /// moduleId -> PackageKey
@@ -49,8 +48,8 @@ pub struct ImmutableModulesMemoryStore {
 }
 
 impl ImmutableModulesMemoryStore {
-  pub fn new(cache_dir_str: &str, namespace: &str, mode: Mode) -> Self {
-    let store = CacheStore::new(cache_dir_str, namespace, mode, "immutable-modules");
+  pub fn new(store_factory: &CacheStoreFactory) -> Self {
+    let store = store_factory.create_cache_store("immutable-modules");
 
     let manifest_bytes = store.read_cache(MANIFEST_KEY).unwrap_or_default();
     let manifest: HashMap<String, String> =
@@ -74,22 +73,21 @@ impl ImmutableModulesMemoryStore {
       cached_modules: DashMap::new(),
       manifest: manifest.into_iter().collect(),
       manifest_reversed,
-      cache_dir: cache_dir_str.to_string(),
+      cache_dir: store_factory.cache_dir().to_string(),
     }
   }
 
-  fn read_cached_package(&self, package_key: &str) -> CachedPackage {
-    let cache = self
-      .store
-      .read_cache(package_key)
-      .expect("Cache broken, please remove node_modules/.farm and retry.");
+  /// The package may not be readable even if it's in the manifest, e.g. the remote cache store is not available,
+  /// it's treated as a cache miss
+  fn read_cached_package(&self, package_key: &str) -> Option<CachedPackage> {
+    let cache = self.store.read_cache(package_key)?;
 
-    crate::deserialize!(&cache, CachedPackage)
+    Some(crate::deserialize!(&cache, CachedPackage))
   }
 
   fn read_package(&self, module_id: &ModuleId) -> Option<()> {
     if let Some(package_key) = self.manifest.get(module_id) {
-      let package = self.read_cached_package(package_key.value());
+      let package = self.read_cached_package(package_key.value())?;
 
       for module in package.list {
         self.cached_modules.insert(module.module.id.clone(), module);
@@ -108,11 +106,8 @@ impl ModuleMemoryStore for ImmutableModulesMemoryStore {
       return true;
     }
 
-    if let Some(package_key) = self.manifest.get(key) {
-      return self.store.has_cache(package_key.value());
-    }
-
-    false
+    // read the package so that a package that can't be read is a cache miss
+    self.read_package(key).is_some() && self.cached_modules.contains_key(key)
   }
 
   fn set_cache(&self, key: crate::module::ModuleId, module: super::CachedModule) {
@@ -124,17 +119,9 @@ impl ModuleMemoryStore for ImmutableModulesMemoryStore {
       return Some(module);
     }
 
-    if self.read_package(key).is_some() {
-      return Some(
-        self
-          .cached_modules
-          .remove(key)
-          .map(|item| item.1)
-          .expect("Cache broken, please remove node_modules/.farm and retry."),
-      );
-    }
+    self.read_package(key)?;
 
-    None
+    self.cached_modules.remove(key).map(|item| item.1)
   }
 
   fn get_cache_ref(
@@ -217,16 +204,19 @@ impl ModuleMemoryStore for ImmutableModulesMemoryStore {
           let modules_in_package = self.manifest_reversed.get(&key).unwrap();
           let mut added_modules = vec![];
 
-          for module_id in modules {
-            if modules_in_package.contains(&module_id) {
+          for module_id in &modules {
+            if modules_in_package.contains(module_id) {
               continue;
             }
-            added_modules.push(module_id);
+            added_modules.push(module_id.clone());
           }
 
-          // add the new modules to the package
-          if !added_modules.is_empty() {
-            let mut package = self.read_cached_package(&key);
+          if added_modules.is_empty() {
+            return None;
+          }
+
+          // add the new modules to the package, the package is written from scratch if it can't be read
+          if let Some(mut package) = self.read_cached_package(&key) {
             package.list.extend(
               added_modules
                 .into_par_iter()
@@ -247,7 +237,6 @@ impl ModuleMemoryStore for ImmutableModulesMemoryStore {
             let package_bytes = crate::serialize!(&package);
             return Some((gen_cache_store_key(modules), package_bytes));
           }
-          return None;
         }
 
         let module_strings = modules.iter().map(|m| m.to_string()).collect::<Vec<_>>();
@@ -295,3 +284,82 @@ impl ModuleMemoryStore for ImmutableModulesMemoryStore {
     !self.cached_modules.contains_key(key)
   }
 }
+
+#[cfg(all(test, feature = "remote_cache"))]
+mod tests {
+  use std::{
+    collections::HashMap,
+    sync::{
+      atomic::{AtomicBool, Ordering},
+      Arc,
+    },
+  };
+
+  use parking_lot::Mutex;
+
+  use crate::{
+    cache::{
+      cache_store::{remote_cache_store::tests::start_store, CacheStoreFactory},
+      module_cache::{module_memory_store::ModuleMemoryStore, CachedModule},
+    },
+    config::{persistent_cache::RemoteCacheConfig, Mode},
+    context::log_store::LogStore,
+    module::{Module, ModuleId},
+  };
+
+  use super::ImmutableModulesMemoryStore;
+
+  #[test]
+  fn unavailable_package_is_cache_miss() {
+    let caches = Arc::new(Mutex::new(HashMap::new()));
+    let stopped = Arc::new(AtomicBool::new(false));
+    let url = start_store(caches, stopped.clone());
+    let tmp = std::env::temp_dir().join(format!(
+      "farm-immutable-modules-cache-{}",
+      std::process::id()
+    ));
+    let log_store = Arc::new(Mutex::new(LogStore::new()));
+    let create_store = |machine: &str, read_only: bool| {
+      ImmutableModulesMemoryStore::new(
+        &CacheStoreFactory::new(
+          &tmp.join(machine).join("cache").to_string_lossy(),
+          "farm-cache",
+          Mode::Development,
+          Some(RemoteCacheConfig {
+            url: url.clone(),
+            read_only,
+            ..Default::default()
+          }),
+          log_store.clone(),
+        )
+        .unwrap(),
+      )
+    };
+    let module_id = ModuleId::from("node_modules/react/index.js");
+
+    let store = create_store("a", false);
+    let mut module = Module::new(module_id.clone());
+    module.immutable = true;
+    module.package_name = "react".to_string();
+    module.package_version = "18.2.0".to_string();
+    store.set_cache(
+      module_id.clone(),
+      CachedModule {
+        module,
+        dependencies: vec![],
+        watch_dependencies: vec![],
+      },
+    );
+    store.write_cache();
+
+    // the manifest is loaded from the remote store, then the remote store is turned off
+    let store = create_store("b", true);
+    stopped.store(true, Ordering::SeqCst);
+
+    assert!(!store.has_cache(&module_id));
+    assert!(store.get_cache(&module_id).is_none());
+    assert_eq!(log_store.lock().warnings().len(), 1);
+
+    std::fs::remove_dir_all(tmp).ok();
+  }
+}
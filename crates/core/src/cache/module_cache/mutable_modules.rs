@@ -6,8 +6,7 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use rkyv::Deserialize;
 
 use crate::{
-  cache::cache_store::{CacheStore, CacheStoreKey},
-  config::Mode,
+  cache::cache_store::{CacheStore, CacheStoreFactory, CacheStoreKey},
   deserialize,
   module::ModuleId,
   serialize,
@@ -18,15 +17,15 @@ use super::{module_memory_store::ModuleMemoryStore, CachedModule};
 /// In memory store for mutable modules
 pub struct MutableModulesMemoryStore {
   /// low level cache store
-  store: CacheStore,
+  store: Box<dyn CacheStore>,
   /// ModuleId -> Cached Module
   cached_modules: DashMap<ModuleId, CachedModule>,
 }
 
 impl MutableModulesMemoryStore {
-  pub fn new(cache_dir_str: &str, namespace: &str, mode: Mode) -> Self {
+  pub fn new(store_factory: &CacheStoreFactory) -> Self {
     Self {
-      store: CacheStore::new(cache_dir_str, namespace, mode, "mutable-modules"),
+      store: store_factory.create_cache_store("mutable-modules"),
       cached_modules: DashMap::new(),
     }
   }
@@ -3,19 +3,16 @@ use std::collections::HashMap;
 use dashmap::{mapref::one::Ref, DashMap};
 use farmfe_utils::hash::sha256;
 
-use crate::config::Mode;
+use super::cache_store::{CacheStore, CacheStoreFactory, CacheStoreKey};
 
-use super::cache_store::{CacheStore, CacheStoreKey};
-
-#[derive(Default)]
 pub struct PluginCacheManager {
-  store: CacheStore,
+  store: Box<dyn CacheStore>,
   cache: DashMap<String, Vec<u8>>,
 }
 
 impl PluginCacheManager {
-  pub fn new(cache_dir: &str, namespace: &str, mode: Mode) -> Self {
-    let store = CacheStore::new(cache_dir, namespace, mode, "plugin");
+  pub fn new(store_factory: &CacheStoreFactory) -> Self {
+    let store = store_factory.create_cache_store("plugin");
     Self {
       store,
       cache: DashMap::new(),
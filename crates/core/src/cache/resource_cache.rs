@@ -1,4 +1,4 @@
-use crate::config::Mode;
+use super::cache_store::CacheStoreFactory;
 
 use self::resource_memory_store::{CachedResourcePot, ResourceMemoryStore};
 use self::resource_pot::ResourcePotMemoryStore;
@@ -11,9 +11,9 @@ pub struct ResourceCacheManager {
 }
 
 impl ResourceCacheManager {
-  pub fn new(cache_dir_str: &str, namespace: &str, mode: Mode) -> Self {
+  pub fn new(store_factory: &CacheStoreFactory) -> Self {
     Self {
-      resource_pot_store: ResourcePotMemoryStore::new(cache_dir_str, namespace, mode),
+      resource_pot_store: ResourcePotMemoryStore::new(store_factory),
     }
   }
 
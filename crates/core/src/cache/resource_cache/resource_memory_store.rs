@@ -11,6 +11,8 @@ pub struct CachedResourcePot {
   pub meta: ResourcePotMetaData,
   /// hash of all modules' content hash
   pub hash: String,
+  /// result of the augment_resource_hash hook, used to name entry resources that are renamed after rendering
+  pub augment_resource_hash: Option<String>,
 }
 
 pub trait ResourceMemoryStore {
@@ -5,8 +5,7 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use rkyv::Deserialize;
 
 use crate::{
-  cache::cache_store::{CacheStore, CacheStoreKey},
-  config::Mode,
+  cache::cache_store::{CacheStore, CacheStoreFactory, CacheStoreKey},
   deserialize, serialize,
 };
 
@@ -15,15 +14,15 @@ use super::resource_memory_store::{CachedResourcePot, ResourceMemoryStore};
 /// In memory store for Resource Pot
 pub struct ResourcePotMemoryStore {
   /// low level cache store
-  store: CacheStore,
+  store: Box<dyn CacheStore>,
   /// resource pot id -> Cached Resource Pot
   cached_resources: DashMap<String, CachedResourcePot>,
 }
 
 impl ResourcePotMemoryStore {
-  pub fn new(cache_dir_str: &str, namespace: &str, mode: Mode) -> Self {
+  pub fn new(store_factory: &CacheStoreFactory) -> Self {
     Self {
-      store: CacheStore::new(cache_dir_str, namespace, mode, "resource"),
+      store: store_factory.create_cache_store("resource"),
       cached_resources: DashMap::new(),
     }
   }
@@ -1,12 +1,15 @@
+use std::path::Path;
+
 use farmfe_macro_cache_item::cache_item;
 use serde_json::{Map, Value};
-use wax::Glob;
+use wax::{Glob, Pattern};
 
 #[cache_item]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ParsedSideEffects {
   Bool(bool),
+  /// normalized glob patterns relative to the package dir
   Array(Vec<String>),
 }
 
@@ -68,6 +71,33 @@ impl PackageJsonInfo {
     self.parsed_side_effects.as_ref().unwrap()
   }
 
+  /// whether the `sideEffects` field is declared in package.json
+  pub fn declares_side_effects(&self) -> bool {
+    self
+      .raw_map
+      .as_ref()
+      .is_some_and(|raw_map| raw_map.contains_key("sideEffects"))
+  }
+
+  /// whether the file of this package has side effects according to the `sideEffects` field.
+  /// the globs of `sideEffects` array are matched against the path relative to the package dir
+  pub fn has_side_effects(&self, path: &str) -> bool {
+    match self.side_effects() {
+      ParsedSideEffects::Bool(b) => *b,
+      ParsedSideEffects::Array(patterns) => {
+        let Ok(relative) = Path::new(path).strip_prefix(self.dir()) else {
+          return false;
+        };
+
+        patterns.iter().any(|pattern| {
+          Glob::new(pattern)
+            .map(|glob| glob.is_match(relative))
+            .unwrap_or(false)
+        })
+      }
+    }
+  }
+
   fn analyze_parsed_side_effects(&mut self, package_value: &serde_json::Map<String, Value>) {
     let parsed_side_effects = if let Some(side_effects) = package_value.get("sideEffects") {
       if let Value::Bool(b) = side_effects {
@@ -77,20 +107,14 @@ impl PackageJsonInfo {
 
         for item in arr {
           if let Value::String(str) = item {
-            let glob = Glob::new(str);
+            let pattern = normalize_side_effects_pattern(str);
 
-            if let Err(e) = glob {
+            if let Err(e) = Glob::new(&pattern) {
               println!("error parsing glob: {:?}", e);
               continue;
             }
-            let glob = glob.unwrap();
 
-            let paths = glob.walk(self.dir());
-
-            for p in paths.flatten() {
-              let path = p.path().to_string_lossy().to_string();
-              res.push(path);
-            }
+            res.push(pattern);
           }
         }
 
@@ -105,3 +129,14 @@ impl PackageJsonInfo {
     self.parsed_side_effects = Some(parsed_side_effects);
   }
 }
+
+/// same as webpack, a pattern without `/` matches the file in any directory, e.g. `*.css` is treated as `**/*.css`
+fn normalize_side_effects_pattern(pattern: &str) -> String {
+  let pattern = pattern.trim_start_matches("./");
+
+  if pattern.contains('/') {
+    pattern.to_string()
+  } else {
+    format!("**/{pattern}")
+  }
+}
@@ -1,7 +1,35 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
 pub struct HtmlConfig {
   pub base: Option<String>,
+  /// content security policy support for the tags injected into html entries
+  pub csp: Option<HtmlCspConfig>,
+  /// add `<link rel="preload">` for the initial scripts and the fonts and images referenced by the initial css
+  pub preload: bool,
+  /// add `<link rel="prefetch">` for the resources of the dynamic imports of the html entry
+  pub prefetch: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HtmlCspConfig {
+  /// nonce placeholder added to every injected `<script>` and `<link>`, e.g. `{{CSP_NONCE}}`.
+  /// The server should replace it with a fresh nonce per request
+  pub nonce: Option<String>,
+  /// move all inline bootstrap code of the runtime into hashed external files
+  pub external: bool,
+  /// name of the emitted json resource that maps html resources to the `sha256-` hashes of their injected scripts
+  pub hashes_filename: String,
+}
+
+impl Default for HtmlCspConfig {
+  fn default() -> Self {
+    Self {
+      nonce: None,
+      external: false,
+      hashes_filename: "csp-hashes.json".to_string(),
+    }
+  }
 }
@@ -44,13 +44,21 @@ pub struct Config {
   pub assets: AssetsConfig,
   pub css: CssConfig,
   pub html: Box<HtmlConfig>,
+  pub json: JsonConfig,
   pub sourcemap: SourcemapConfig,
   pub partial_bundling: PartialBundlingConfig,
   pub lazy_compilation: bool,
   pub core_lib_path: Option<String>,
   pub tree_shaking: bool,
+  /// concatenate esm modules of the same resource pot into one scope in production mode,
+  /// enabled by default in production, see [Config::concatenate_modules_enabled]
+  pub concatenate_modules: Option<bool>,
   pub minify: Box<BoolOrObj<serde_json::Value>>,
   pub preset_env: Box<PresetEnvConfig>,
+  /// browserslist targets shared by script down-levelling, polyfills and css prefixing,
+  /// `"browserslist"` loads them from `.browserslistrc` or the `browserslist` field of package.json
+  #[serde(skip_serializing)]
+  pub targets: Option<Targets>,
   pub record: bool,
   pub progress: bool,
   pub persistent_cache: Box<persistent_cache::PersistentCacheConfig>,
@@ -60,6 +68,12 @@ pub struct Config {
   pub custom: Box<HashMap<String, String>>,
 }
 
+impl Config {
+  pub fn concatenate_modules_enabled(&self) -> bool {
+    matches!(self.mode, Mode::Production) && self.concatenate_modules.unwrap_or(true)
+  }
+}
+
 impl Default for Config {
   fn default() -> Self {
     let root = std::env::current_dir()
@@ -79,14 +93,17 @@ impl Default for Config {
       script: Default::default(),
       css: Default::default(),
       html: Box::default(),
+      json: Default::default(),
       assets: Default::default(),
       sourcemap: Default::default(),
       partial_bundling: PartialBundlingConfig::default(),
       lazy_compilation: true,
       core_lib_path: None,
       tree_shaking: true,
+      concatenate_modules: None,
       minify: Box::new(BoolOrObj::Bool(true)),
       preset_env: Box::<PresetEnvConfig>::default(),
+      targets: None,
       record: false,
       progress: true,
       persistent_cache: Box::<persistent_cache::PersistentCacheConfig>::new(
@@ -109,6 +126,16 @@ pub struct OutputConfig {
   pub assets_filename: String,
   pub target_env: TargetEnv,
   pub format: ModuleFormat,
+  /// emit a manifest that maps entries and dynamic imports to their resources
+  pub manifest: BoolOrObj<ManifestConfig>,
+  /// the global variable name that holds the exports of the entry, only used when format is `iife` or `umd`
+  pub name: Option<String>,
+  /// external module id -> global variable name, used to find the external modules when format is `iife` or `umd`
+  pub globals: HashMap<String, String>,
+  /// add subresource integrity to the script and stylesheet resources injected into html or loaded lazily
+  pub sri: Option<SriAlgorithm>,
+  /// emit pre-compressed `.gz` and `.br` siblings of the text resources
+  pub compress: BoolOrObj<CompressConfig>,
 }
 
 impl Default for OutputConfig {
@@ -123,10 +150,65 @@ impl Default for OutputConfig {
       path: "dist".to_string(),
       target_env: TargetEnv::default(),
       format: ModuleFormat::default(),
+      manifest: BoolOrObj::Bool(false),
+      name: None,
+      globals: HashMap::new(),
+      sri: None,
+      compress: BoolOrObj::Bool(false),
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ManifestConfig {
+  /// name of the emitted manifest resource
+  pub filename: String,
+}
+
+impl Default for ManifestConfig {
+  fn default() -> Self {
+    Self {
+      filename: "manifest.json".to_string(),
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CompressConfig {
+  /// emit `.gz` siblings
+  pub gzip: bool,
+  /// gzip compression level, from 0 to 9
+  pub gzip_level: u32,
+  /// emit `.br` siblings
+  pub brotli: bool,
+  /// brotli compression quality, from 0 to 11
+  pub brotli_level: u32,
+  /// resources smaller than this size in bytes are not compressed
+  pub threshold: usize,
+}
+
+impl Default for CompressConfig {
+  fn default() -> Self {
+    Self {
+      gzip: true,
+      gzip_level: 9,
+      brotli: true,
+      brotli_level: 11,
+      threshold: 1024,
     }
   }
 }
 
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum SriAlgorithm {
+  Sha256,
+  Sha384,
+  Sha512,
+}
+
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, Default)]
 pub enum TargetEnv {
   #[serde(rename = "browser")]
@@ -143,6 +225,26 @@ pub enum ModuleFormat {
   EsModule,
   #[serde(rename = "cjs")]
   CommonJs,
+  /// wrap the entry in an immediately invoked function, for loading via a plain `<script>` tag
+  #[serde(rename = "iife")]
+  Iife,
+  #[serde(rename = "umd")]
+  Umd,
+}
+
+impl ModuleFormat {
+  /// iife and umd resources are loaded without a module loader, so the entry has to be self-contained
+  pub fn is_iife_or_umd(&self) -> bool {
+    matches!(self, Self::Iife | Self::Umd)
+  }
+
+  pub fn entry_ext(&self) -> &'static str {
+    match self {
+      Self::EsModule => "mjs",
+      Self::CommonJs => "cjs",
+      Self::Iife | Self::Umd => "js",
+    }
+  }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -174,6 +276,12 @@ pub struct CssModulesConfig {
   /// The paths regex to match css modules
   pub paths: Vec<String>,
   pub indent_name: String,
+  /// how the class names are exposed as the keys of the exports
+  pub locals_convention: LocalsConvention,
+  /// export the class names that are valid identifiers as named exports, so that they can be tree shaken
+  pub named_exports: bool,
+  /// write `*.d.ts` typings of the exported class names
+  pub dts: BoolOrObj<CssModulesDtsConfig>,
 }
 
 impl Default for CssModulesConfig {
@@ -181,10 +289,35 @@ impl Default for CssModulesConfig {
     Self {
       paths: vec![String::from("\\.module\\.(css|less|sass|scss)$")],
       indent_name: String::from("[name]-[hash]"),
+      locals_convention: LocalsConvention::default(),
+      named_exports: false,
+      dts: BoolOrObj::Bool(false),
     }
   }
 }
 
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum LocalsConvention {
+  /// keep the class names as they are
+  #[default]
+  AsIs,
+  /// export both the original and the camel cased class names
+  CamelCase,
+  /// only export the camel cased class names
+  CamelCaseOnly,
+  /// export both the original class names and the names with only dashes camel cased
+  Dashes,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CssModulesDtsConfig {
+  /// write the typings into this directory (relative to root) instead of next to the css files,
+  /// keeping the directory structure of the css files
+  pub out_dir: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase", default)]
 pub struct CssPrefixerConfig {
@@ -294,10 +427,30 @@ impl Default for RuntimeConfig {
 #[serde(rename_all = "camelCase", default)]
 pub struct AssetsConfig {
   pub include: Vec<String>,
+  /// assets smaller than this size in bytes are inlined as data urls, 0 means never inline
+  pub inline_limit: usize,
   /// Used internally, this option will be not exposed to user.
   pub public_dir: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JsonConfig {
+  /// emit `JSON.parse('...')` instead of an object literal for json files that are not smaller than
+  /// `stringify_threshold` bytes, which is faster to parse. Only the default export is emitted in this case
+  pub stringify: bool,
+  pub stringify_threshold: usize,
+}
+
+impl Default for JsonConfig {
+  fn default() -> Self {
+    Self {
+      stringify: false,
+      stringify_threshold: 10 * 1024,
+    }
+  }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SourcemapConfig {
   /// Generate inline sourcemap instead of a separate file for mutable resources.
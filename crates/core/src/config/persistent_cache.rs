@@ -49,6 +49,7 @@ impl PersistentCacheConfig {
       // build dependencies are set by node side
       build_dependencies: vec![],
       envs: HashMap::new(),
+      remote: None,
     })
   }
 
@@ -132,6 +133,29 @@ pub struct PersistentCacheConfigObj {
   /// Note that farm will resolve the config file dependencies from node side
   pub build_dependencies: Vec<String>,
   pub envs: HashMap<String, String>,
+  /// Share the caches through a remote http store, e.g. between CI machines
+  pub remote: Option<Box<RemoteCacheConfig>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RemoteCacheConfig {
+  /// Base url of the store, a cache is read by `GET <url>/<cache key>` and written by `PUT <url>/<cache key>`
+  pub url: String,
+  /// Headers sent with every request, e.g. the authorization token of the store
+  pub headers: HashMap<String, String>,
+  /// Only read the caches from the remote store, the caches are still written to the disk. True by default
+  pub read_only: bool,
+}
+
+impl Default for RemoteCacheConfig {
+  fn default() -> Self {
+    Self {
+      url: String::new(),
+      headers: HashMap::new(),
+      read_only: true,
+    }
+  }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -66,8 +66,15 @@ impl Default for ScriptDecoratorsConfig {
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase", default)]
 pub struct ScriptConfig {
-  pub target: EsVersion,
+  /// derived from `targets` when it's not configured, see [ScriptConfig::target]
+  pub target: Option<EsVersion>,
   pub parser: ScriptParserConfig,
   pub plugins: Vec<ScriptConfigPlugin>,
   pub decorators: ScriptDecoratorsConfig,
 }
+
+impl ScriptConfig {
+  pub fn target(&self) -> EsVersion {
+    self.target.unwrap_or_default()
+  }
+}
@@ -1,7 +1,9 @@
+use crate::error::diagnostic::Diagnostic;
+
 #[derive(Debug, Default)]
 pub struct LogStore {
-  warnings: Vec<String>,
-  errors: Vec<String>,
+  warnings: Vec<Diagnostic>,
+  errors: Vec<Diagnostic>,
 }
 
 impl LogStore {
@@ -12,19 +14,19 @@ impl LogStore {
     }
   }
 
-  pub fn add_warning(&mut self, warning: String) {
+  pub fn add_warning(&mut self, warning: Diagnostic) {
     self.warnings.push(warning);
   }
 
-  pub fn add_error(&mut self, error: String) {
+  pub fn add_error(&mut self, error: Diagnostic) {
     self.errors.push(error);
   }
 
-  pub fn warnings(&self) -> &Vec<String> {
+  pub fn warnings(&self) -> &Vec<Diagnostic> {
     &self.warnings
   }
 
-  pub fn errors(&self) -> &Vec<String> {
+  pub fn errors(&self) -> &Vec<Diagnostic> {
     &self.errors
   }
 
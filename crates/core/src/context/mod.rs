@@ -3,7 +3,7 @@ use std::{any::Any, path::Path, sync::Arc};
 use dashmap::DashMap;
 use parking_lot::{Mutex, RwLock};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use swc_common::Globals;
 
 use crate::{
@@ -36,14 +36,22 @@ pub struct CompilationContext {
   pub cache_manager: Box<CacheManager>,
   pub meta: Box<ContextMetaData>,
   pub record_manager: Box<RecordManager>,
-  pub log_store: Box<Mutex<LogStore>>,
+  /// shared with the remote cache store, which reports the warnings when the remote store is not available
+  pub log_store: Arc<Mutex<LogStore>>,
   pub resolve_cache: Box<Mutex<HashMap<PluginResolveHookParam, PluginResolveHookResult>>>,
+  /// importers that failed to resolve some of their dependencies and the failed sources, they are rebuilt when a matching file is added
+  pub failed_resolve_importers: Box<Mutex<HashMap<ModuleId, HashSet<String>>>>,
   pub custom: Box<DashMap<String, Box<dyn Any + Send + Sync>>>,
 }
 
 impl CompilationContext {
   pub fn new(mut config: Config, plugins: Vec<Arc<dyn Plugin>>) -> Result<Self> {
     let (cache_dir, namespace) = Self::normalize_persistent_cache_config(&mut config);
+    let remote_cache = match config.persistent_cache.as_ref() {
+      PersistentCacheConfig::Obj(obj) => obj.remote.as_deref().cloned(),
+      PersistentCacheConfig::Bool(_) => None,
+    };
+    let log_store = Arc::new(Mutex::new(LogStore::new()));
 
     Ok(Self {
       watch_graph: Box::new(RwLock::new(WatchGraph::new())),
@@ -56,12 +64,15 @@ impl CompilationContext {
         &cache_dir,
         &namespace,
         config.mode.clone(),
-      )),
+        remote_cache,
+        log_store.clone(),
+      )?),
       config: Box::new(config),
       meta: Box::new(ContextMetaData::new()),
       record_manager: Box::new(RecordManager::new()),
-      log_store: Box::new(Mutex::new(LogStore::new())),
+      log_store,
       resolve_cache: Box::new(Mutex::new(HashMap::new())),
+      failed_resolve_importers: Box::new(Mutex::new(HashMap::new())),
       custom: Box::new(DashMap::new()),
     })
   }
@@ -0,0 +1,222 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// lines shown before and after the error line in the code frame
+const CODE_FRAME_CONTEXT_LINES: usize = 2;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiagnosticSeverity {
+  #[default]
+  Error,
+  Warning,
+}
+
+/// 1-based line and 0-based column, the same as rollup's `loc`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticPosition {
+  pub line: usize,
+  pub column: usize,
+}
+
+/// A warning or error with its location in the source file. It's serialized as json when passing to js side,
+/// `id`, `loc` and `frame` of rollup errors are accepted when deserializing
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Diagnostic {
+  pub severity: DiagnosticSeverity,
+  pub message: String,
+  pub plugin: Option<String>,
+  #[serde(alias = "id")]
+  pub file: Option<String>,
+  #[serde(alias = "loc")]
+  pub start: Option<DiagnosticPosition>,
+  pub end: Option<DiagnosticPosition>,
+  #[serde(alias = "frame")]
+  pub code_frame: Option<String>,
+  pub hints: Vec<String>,
+}
+
+impl Diagnostic {
+  pub fn error(message: impl Into<String>) -> Self {
+    Self {
+      message: message.into(),
+      ..Default::default()
+    }
+  }
+
+  pub fn warning(message: impl Into<String>) -> Self {
+    Self {
+      severity: DiagnosticSeverity::Warning,
+      message: message.into(),
+      ..Default::default()
+    }
+  }
+
+  pub fn with_plugin(mut self, plugin: impl Into<String>) -> Self {
+    self.plugin = Some(plugin.into());
+    self
+  }
+
+  pub fn with_file(mut self, file: impl Into<String>) -> Self {
+    self.file = Some(file.into());
+    self
+  }
+
+  pub fn with_span(mut self, start: DiagnosticPosition, end: DiagnosticPosition) -> Self {
+    self.start = Some(start);
+    self.end = Some(end);
+    self
+  }
+
+  pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+    self.hints.push(hint.into());
+    self
+  }
+
+  /// Generate the code frame of the span from the content of the file, do nothing if the span is not set
+  pub fn with_code_frame(mut self, source: &str) -> Self {
+    if let Some(start) = self.start {
+      self.code_frame = Some(code_frame(source, start, self.end.unwrap_or(start)));
+    }
+
+    self
+  }
+}
+
+impl Display for Diagnostic {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if let Some(plugin) = &self.plugin {
+      write!(f, "[{plugin}] ")?;
+    }
+
+    write!(f, "{}", self.message)?;
+
+    if let Some(file) = &self.file {
+      write!(f, "\n  --> {file}")?;
+
+      if let Some(start) = &self.start {
+        write!(f, ":{}:{}", start.line, start.column + 1)?;
+      }
+    }
+
+    if let Some(code_frame) = &self.code_frame {
+      write!(f, "\n{code_frame}")?;
+    }
+
+    for hint in &self.hints {
+      write!(f, "\n  = hint: {hint}")?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Render the lines around `start` with line numbers, and mark the span with `^`. e.g.
+/// ```text
+///   1 | const a = 1;
+/// > 2 | const b = ;
+///     |           ^
+///   3 | console.log(a);
+/// ```
+pub fn code_frame(source: &str, start: DiagnosticPosition, end: DiagnosticPosition) -> String {
+  let lines = source.lines().collect::<Vec<_>>();
+
+  if lines.is_empty() {
+    return String::new();
+  }
+
+  // errors at the end of the file may point to the line after the last one
+  let start = DiagnosticPosition {
+    line: start.line.clamp(1, lines.len()),
+    column: start.column,
+  };
+  let first = start.line.saturating_sub(CODE_FRAME_CONTEXT_LINES).max(1);
+  let last = (start.line + CODE_FRAME_CONTEXT_LINES).min(lines.len());
+  let gutter_width = last.to_string().len();
+
+  let mut frame = vec![];
+
+  for line_number in first..=last {
+    let line = lines[line_number - 1];
+
+    if line_number != start.line {
+      frame.push(format!("  {line_number:>gutter_width$} | {line}"));
+      continue;
+    }
+
+    frame.push(format!("> {line_number:>gutter_width$} | {line}"));
+
+    let line_len = line.chars().count();
+    let column = start.column.min(line_len);
+    // mark to the end of the line if the span covers multiple lines
+    let end_column = if end.line == start.line {
+      end.column.min(line_len)
+    } else {
+      line_len
+    };
+    let marker_len = end_column.saturating_sub(column).max(1);
+    frame.push(format!(
+      "  {} | {}{}",
+      " ".repeat(gutter_width),
+      " ".repeat(column),
+      "^".repeat(marker_len)
+    ));
+  }
+
+  frame.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{code_frame, Diagnostic, DiagnosticPosition};
+
+  #[test]
+  fn render_code_frame() {
+    let source = "const a = 1;\nconst b = ;\nconsole.log(a);\nconsole.log(b);\n";
+    let start = DiagnosticPosition {
+      line: 2,
+      column: 10,
+    };
+
+    assert_eq!(
+      code_frame(source, start, start),
+      "  1 | const a = 1;\n> 2 | const b = ;\n    |           ^\n  3 | console.log(a);\n  4 | console.log(b);"
+    );
+    assert_eq!(
+      code_frame(source, start, DiagnosticPosition { line: 3, column: 0 }),
+      code_frame(
+        source,
+        start,
+        DiagnosticPosition {
+          line: 2,
+          column: 11
+        }
+      )
+    );
+  }
+
+  #[test]
+  fn deserialize_rollup_error() {
+    let diagnostic: Diagnostic = serde_json::from_str(
+      r#"{"message":"failed","plugin":"vite:vue","id":"/a.vue","loc":{"file":"/a.vue","line":1,"column":2},"frame":"frame"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      diagnostic,
+      Diagnostic {
+        code_frame: Some("frame".to_string()),
+        start: Some(DiagnosticPosition { line: 1, column: 2 }),
+        ..Diagnostic::error("failed")
+          .with_plugin("vite:vue")
+          .with_file("/a.vue")
+      }
+    );
+    assert_eq!(
+      diagnostic.to_string(),
+      "[vite:vue] failed\n  --> /a.vue:1:3\nframe"
+    );
+  }
+}
@@ -4,6 +4,10 @@ use thiserror::Error;
 
 use crate::resource::resource_pot::ResourcePotType;
 
+use self::diagnostic::Diagnostic;
+
+pub mod diagnostic;
+
 #[derive(Debug, Error)]
 pub enum CompilationError {
   #[error("Can not resolve `{src}` from {importer}.\nOriginal error: {source:?}.\n\nPotential Causes:\n1.The file that `{src}` points to does not exist.\n2.Install it first if `{src}` is an dependency from node_modules, if you are using pnpm refer to [https://pnpm.io/faq#pnpm-does-not-work-with-your-project-here] for solutions.\n3. If `{src}` is a alias, make sure your alias config is correct.\n")]
@@ -83,6 +87,10 @@ pub enum CompilationError {
     source: Option<Box<dyn Error + Send + Sync>>,
   },
 
+  /// errors with source locations, e.g. syntax errors and errors reported by plugins
+  #[error("{}", display_diagnostics(.0))]
+  Diagnostics(Vec<Diagnostic>),
+
   #[error("generate sourcemap for module `{id}` failed")]
   GenerateSourceMapError {
     id: String,
@@ -93,6 +101,27 @@ pub enum CompilationError {
 
 pub type Result<T> = core::result::Result<T, CompilationError>;
 
+impl CompilationError {
+  /// Messages passed to js side. Diagnostics are serialized as json objects so that the location and code frame can be displayed
+  pub fn to_messages(&self) -> Vec<String> {
+    match self {
+      CompilationError::Diagnostics(diagnostics) => diagnostics
+        .iter()
+        .map(|d| serde_json::to_string(d).unwrap())
+        .collect(),
+      _ => vec![self.to_string()],
+    }
+  }
+}
+
+fn display_diagnostics(diagnostics: &[Diagnostic]) -> String {
+  diagnostics
+    .iter()
+    .map(|d| d.to_string())
+    .collect::<Vec<_>>()
+    .join("\n\n")
+}
+
 pub trait ToResolveError
 where
   Self: Error + Sized + Send + Sync + 'static,
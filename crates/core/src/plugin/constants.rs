@@ -1 +1,3 @@
 pub const PLUGIN_BUILD_STAGE_META_RESOLVE_KIND: &str = "__PLUGIN_BUILD_STAGE_META_RESOLVE_KIND";
+/// [super::ResolveKind::Custom] of `new URL('./xxx.png', import.meta.url)`
+pub const RESOLVE_KIND_NEW_URL: &str = "new-url";
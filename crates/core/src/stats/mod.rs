@@ -1 +1,117 @@
-pub struct Stats {}
+use std::{
+  collections::HashMap,
+  sync::atomic::{AtomicUsize, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{module::module_graph::ModuleGraph, resource::Resource};
+
+/// Statistics of a compilation, passed to the `finish` hook of all plugins.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Stats {
+  /// time cost of each compilation stage
+  pub timings: StageTimings,
+  /// count of modules in the module graph, keyed by [crate::module::ModuleType]
+  pub module_counts: HashMap<String, usize>,
+  /// persistent cache hits and misses
+  pub cache: CacheStats,
+  /// name, type and size of every generated resource
+  pub resources: Vec<ResourceStats>,
+}
+
+impl Stats {
+  pub fn set_module_counts(&mut self, module_graph: &ModuleGraph) {
+    self.module_counts.clear();
+
+    for module in module_graph.modules() {
+      *self
+        .module_counts
+        .entry(module.module_type.to_string())
+        .or_insert(0) += 1;
+    }
+  }
+
+  pub fn set_resources<'a>(&mut self, resources: impl Iterator<Item = &'a Resource>) {
+    self.resources = resources
+      .map(|resource| ResourceStats {
+        name: resource.name.clone(),
+        resource_type: resource.resource_type.to_string(),
+        size: resource.bytes.len(),
+      })
+      .collect();
+    self.resources.sort_by(|a, b| a.name.cmp(&b.name));
+  }
+}
+
+/// Time cost of each compilation stage, in milliseconds
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StageTimings {
+  pub build: f64,
+  pub optimize: f64,
+  pub partial_bundling: f64,
+  pub render: f64,
+  pub finalize: f64,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheStats {
+  pub module_cache_hits: usize,
+  pub module_cache_misses: usize,
+  pub resource_cache_hits: usize,
+  pub resource_cache_misses: usize,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceStats {
+  pub name: String,
+  pub resource_type: String,
+  pub size: usize,
+}
+
+/// Thread safe counters of cache hits and misses, owned by [crate::cache::CacheManager]
+#[derive(Debug, Default)]
+pub struct CacheStatsCounter {
+  module_cache_hits: AtomicUsize,
+  module_cache_misses: AtomicUsize,
+  resource_cache_hits: AtomicUsize,
+  resource_cache_misses: AtomicUsize,
+}
+
+impl CacheStatsCounter {
+  pub fn module_cache_hit(&self) {
+    self.module_cache_hits.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn module_cache_miss(&self) {
+    self.module_cache_misses.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn resource_cache_hit(&self) {
+    self.resource_cache_hits.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn resource_cache_miss(&self) {
+    self.resource_cache_misses.fetch_add(1, Ordering::Relaxed);
+  }
+
+  pub fn reset(&self) {
+    self.module_cache_hits.store(0, Ordering::Relaxed);
+    self.module_cache_misses.store(0, Ordering::Relaxed);
+    self.resource_cache_hits.store(0, Ordering::Relaxed);
+    self.resource_cache_misses.store(0, Ordering::Relaxed);
+  }
+
+  pub fn snapshot(&self) -> CacheStats {
+    CacheStats {
+      module_cache_hits: self.module_cache_hits.load(Ordering::Relaxed),
+      module_cache_misses: self.module_cache_misses.load(Ordering::Relaxed),
+      resource_cache_hits: self.resource_cache_hits.load(Ordering::Relaxed),
+      resource_cache_misses: self.resource_cache_misses.load(Ordering::Relaxed),
+    }
+  }
+}
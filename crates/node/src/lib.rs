@@ -1,7 +1,5 @@
 #![deny(clippy::all)]
 #![allow(clippy::redundant_allocation)]
-#[cfg(feature = "file_watcher")]
-use std::path::PathBuf;
 use std::{collections::HashMap, path::Path, sync::Arc};
 
 use farmfe_compiler::Compiler;
@@ -20,20 +18,17 @@ use farmfe_core::{
 
 #[cfg(feature = "file_watcher")]
 use farmfe_core::resource::Resource;
-#[cfg(feature = "file_watcher")]
-use napi::threadsafe_function::ThreadSafeCallContext;
 
 use napi::{
-  bindgen_prelude::{Buffer, FromNapiValue},
-  threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  bindgen_prelude::{Buffer, Either, FromNapiValue},
+  threadsafe_function::{
+    ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+  },
   Env, JsFunction, JsObject, JsUndefined, JsUnknown, NapiRaw, Status,
 };
 
 #[cfg(feature = "file_watcher")]
-use notify::{
-  event::{AccessKind, ModifyKind},
-  EventKind, RecommendedWatcher, Watcher,
-};
+use farmfe_compiler::watcher::FsWatcher;
 use plugin_adapters::{js_plugin_adapter::JsPluginAdapter, rust_plugin_adapter::RustPluginAdapter};
 
 // pub use farmfe_toolkit_plugin;
@@ -47,6 +42,34 @@ pub struct WatchDiffResult {
   pub remove: Vec<String>,
 }
 
+/// A changed file passed to `update`, `updateType` is one of `added`, `updated` and `removed`, defaults to `updated`
+#[napi(object)]
+pub struct JsUpdateEvent {
+  pub path: String,
+  pub update_type: Option<String>,
+}
+
+fn update_type_from_str(update_type: Option<&str>) -> napi::Result<UpdateType> {
+  match update_type {
+    None | Some("updated") => Ok(UpdateType::Updated),
+    Some("added") => Ok(UpdateType::Added),
+    Some("removed") => Ok(UpdateType::Removed),
+    Some(ty) => Err(napi::Error::new(
+      Status::InvalidArg,
+      format!("unknown update type `{ty}`, expected `added`, `updated` or `removed`"),
+    )),
+  }
+}
+
+#[cfg(feature = "file_watcher")]
+fn update_type_to_str(update_type: &UpdateType) -> &'static str {
+  match update_type {
+    UpdateType::Added => "added",
+    UpdateType::Updated => "updated",
+    UpdateType::Removed => "removed",
+  }
+}
+
 #[napi(object)]
 pub struct JsUpdateResult {
   pub added: Vec<String>,
@@ -261,19 +284,37 @@ impl JsCompiler {
     Ok(())
   }
 
-  /// TODO: usage example
+  /// Update the compiler with the changed files, a path string means the file is updated.
+  /// e.g. `compiler.update(['/root/a.ts', { path: '/root/b.ts', updateType: 'added' }], callback, true, true)`
   #[napi]
   pub fn update(
     &self,
     e: Env,
-    paths: Vec<String>,
+    paths: Vec<Either<String, JsUpdateEvent>>,
     callback: JsFunction,
     sync: bool,
     generate_update_resource: bool,
   ) -> napi::Result<JsObject> {
+    let paths = paths
+      .into_iter()
+      .map(|p| match p {
+        Either::A(path) => Ok((path, UpdateType::Updated)),
+        Either::B(event) => Ok((
+          event.path,
+          update_type_from_str(event.update_type.as_deref())?,
+        )),
+      })
+      .collect::<napi::Result<Vec<_>>>()?;
     let context = self.compiler.context().clone();
-    let thread_safe_callback: ThreadsafeFunction<(), ErrorStrategy::Fatal> =
-      callback.create_threadsafe_function(0, |ctx| ctx.env.get_undefined().map(|v| vec![v]))?;
+    // the callback receives the error message if the resources fail to regenerate after the update resolved
+    let thread_safe_callback: ThreadsafeFunction<Option<String>, ErrorStrategy::Fatal> = callback
+      .create_threadsafe_function(
+      0,
+      |ctx: ThreadSafeCallContext<Option<String>>| match ctx.value {
+        Some(err) => ctx.env.create_string(&err).map(|v| vec![v.into_unknown()]),
+        None => ctx.env.get_undefined().map(|v| vec![v.into_unknown()]),
+      },
+    )?;
 
     let (promise, result) =
       e.create_deferred::<JsUpdateResult, Box<dyn FnOnce(Env) -> napi::Result<JsUpdateResult>>>()?;
@@ -282,15 +323,15 @@ impl JsCompiler {
     self.compiler.thread_pool.spawn(move || {
       match compiler
         .update(
-          paths
-            .into_iter()
-            .map(|p| (p, UpdateType::Updated))
-            .collect(),
-          move || {
-            thread_safe_callback.call((), ThreadsafeFunctionCallMode::Blocking);
+          paths,
+          move |result| {
+            thread_safe_callback.call(
+              result.err().map(|e| e.to_string()),
+              ThreadsafeFunctionCallMode::Blocking,
+            );
           },
           sync,
-          generate_update_resource
+          generate_update_resource,
         )
         .map_err(|e| napi::Error::new(Status::GenericFailure, format!("{}", e)))
       {
@@ -384,6 +425,12 @@ impl JsCompiler {
       || !module_ids_by_file.is_empty()
   }
 
+  /// Whether the added file may be a dependency that some modules failed to resolve
+  #[napi]
+  pub fn is_missing_dependency(&self, resolved_path: String) -> bool {
+    self.compiler.is_missing_dependency(&resolved_path)
+  }
+
   #[napi]
   pub fn get_parent_files(&self, resolved_path: String) -> Vec<String> {
     let context = self.compiler.context();
@@ -623,117 +670,6 @@ impl JsCompiler {
   }
 }
 
-#[cfg(feature = "file_watcher")]
-pub struct FsWatcher {
-  watcher: notify::RecommendedWatcher,
-  watched_paths: Vec<PathBuf>,
-}
-#[cfg(feature = "file_watcher")]
-impl FsWatcher {
-  pub fn new<F>(mut callback: F) -> notify::Result<Self>
-  where
-    F: FnMut(Vec<String>) + Send + Sync + 'static,
-  {
-    // TODO support other kind of events
-    let watcher = RecommendedWatcher::new(
-      move |result: std::result::Result<notify::Event, notify::Error>| {
-        let event = result.unwrap();
-        let get_paths = || {
-          event
-            .paths
-            .iter()
-            .map(|p| p.to_str().unwrap().to_string())
-            .collect::<Vec<_>>()
-        };
-        // println!("{:?} {:?}", event.kind, event);
-        if cfg!(target_os = "macos") {
-          if matches!(event.kind, EventKind::Modify(ModifyKind::Data(_))) {
-            callback(get_paths());
-          }
-        } else if cfg!(target_os = "linux") {
-          // a close event is always followed by a modify event
-          if matches!(event.kind, EventKind::Access(AccessKind::Close(_))) {
-            callback(get_paths());
-          }
-        } else if event.kind.is_modify() {
-          callback(get_paths());
-        }
-      },
-      Default::default(),
-    )?;
-
-    Ok(Self {
-      watcher,
-      watched_paths: vec![],
-    })
-  }
-
-  #[cfg(any(target_os = "macos", target_os = "windows"))]
-  pub fn watch(&mut self, paths: Vec<&Path>) -> notify::Result<()> {
-    if paths.is_empty() {
-      return Ok(());
-    }
-    // find the longest common prefix
-    let mut prefix_comps = vec![];
-    let first_item = &paths[0];
-    let rest = &paths[1..];
-
-    for (index, comp) in first_item.components().enumerate() {
-      if rest.iter().all(|item| {
-        let mut item_comps = item.components();
-
-        if index >= item.components().count() {
-          return false;
-        }
-
-        item_comps.nth(index).unwrap() == comp
-      }) {
-        prefix_comps.push(comp);
-      }
-    }
-
-    let watch_path = PathBuf::from_iter(prefix_comps.iter());
-
-    if self
-      .watched_paths
-      .iter()
-      .any(|item| watch_path.starts_with(item))
-    {
-      return Ok(());
-    } else {
-      self.watched_paths.push(watch_path.clone());
-    }
-
-    // println!("watch path {:?}", watch_path);
-
-    self
-      .watcher
-      .watch(watch_path.as_path(), notify::RecursiveMode::Recursive)
-  }
-
-  #[cfg(target_os = "linux")]
-  pub fn watch(&mut self, paths: Vec<&Path>) -> notify::Result<()> {
-    for path in paths {
-      if self.watched_paths.contains(&path.to_path_buf()) {
-        continue;
-      }
-
-      self
-        .watcher
-        .watch(path, notify::RecursiveMode::NonRecursive)
-        .ok();
-
-      self.watched_paths.push(path.to_path_buf());
-    }
-
-    Ok(())
-  }
-
-  pub fn unwatch(&mut self, path: &str) -> notify::Result<()> {
-    self.watcher.unwatch(Path::new(path))
-  }
-}
-
 #[cfg(feature = "file_watcher")]
 #[napi(js_name = "JsFileWatcher")]
 pub struct FileWatcher {
@@ -743,18 +679,27 @@ pub struct FileWatcher {
 #[cfg(feature = "file_watcher")]
 #[napi]
 impl FileWatcher {
+  /// `callback` is called with the changed paths and their update types, see [JsUpdateEvent]
   #[napi(constructor)]
   pub fn new(_: Env, callback: JsFunction) -> napi::Result<Self> {
-    let thread_safe_callback: ThreadsafeFunction<Vec<String>, ErrorStrategy::Fatal> = callback
-      .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<Vec<String>>| {
-        let mut array = ctx.env.create_array_with_length(ctx.value.len())?;
-
-        for (i, v) in ctx.value.iter().enumerate() {
-          array.set_element(i as u32, ctx.env.create_string(v)?)?;
-        }
+    let thread_safe_callback: ThreadsafeFunction<Vec<(String, UpdateType)>, ErrorStrategy::Fatal> =
+      callback.create_threadsafe_function(
+        0,
+        |ctx: ThreadSafeCallContext<Vec<(String, UpdateType)>>| {
+          let mut paths = ctx.env.create_array_with_length(ctx.value.len())?;
+          let mut update_types = ctx.env.create_array_with_length(ctx.value.len())?;
+
+          for (i, (path, update_type)) in ctx.value.iter().enumerate() {
+            paths.set_element(i as u32, ctx.env.create_string(path)?)?;
+            update_types.set_element(
+              i as u32,
+              ctx.env.create_string(update_type_to_str(update_type))?,
+            )?;
+          }
 
-        Ok(vec![array])
-      })?;
+          Ok(vec![paths, update_types])
+        },
+      )?;
 
     let watcher = FsWatcher::new(move |paths| {
       thread_safe_callback.call(paths, ThreadsafeFunctionCallMode::Blocking);
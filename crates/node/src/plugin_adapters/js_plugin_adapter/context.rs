@@ -15,9 +15,11 @@ use napi::{
 
 use farmfe_core::{
   context::{CompilationContext, EmitFileParams},
+  error::diagnostic::{Diagnostic, DiagnosticSeverity},
   module::ModuleId,
   // swc_ecma_ast::EsVersion,
   plugin::{PluginHookContext, PluginResolveHookParam},
+  serde_json,
 };
 
 const RESOLVE: &str = "resolve";
@@ -27,6 +29,8 @@ const GET_WATCH_FILES: &str = "getWatchFiles";
 const WARN: &str = "warn";
 const ERROR: &str = "error";
 const SOURCE_MAP_ENABLED: &str = "sourceMapEnabled";
+/// property of the hook executors that records the name of the plugin they belong to
+pub const JS_PLUGIN_NAME: &str = "farm_js_plugin_name";
 
 /// These functions are used to make farm js plugin compatible with Vite plugin
 use super::context_methods::vite_get_importers::{vite_get_importers, VITE_GET_IMPORTERS};
@@ -42,10 +46,14 @@ pub struct GetModulesByFileResultItem {
   pub ty: String,
 }
 
-/// create a js object context that wraps [Arc<CompilationContext>]
+/// create a js object context that wraps [Arc<CompilationContext>], `plugin_name` is reported in warnings and errors
 /// # Safety
 /// calling [napi_create_object]
-pub unsafe fn create_js_context(raw_env: napi_env, ctx: Arc<CompilationContext>) -> JsObject {
+pub unsafe fn create_js_context(
+  raw_env: napi_env,
+  ctx: Arc<CompilationContext>,
+  plugin_name: Option<String>,
+) -> JsObject {
   let mut js_context_ptr = ptr::null_mut();
   let mut js_context = {
     napi_create_object(raw_env, &mut js_context_ptr);
@@ -61,8 +69,6 @@ pub unsafe fn create_js_context(raw_env: napi_env, ctx: Arc<CompilationContext>)
     (ADD_WATCH_FILE, add_watch_file),
     (EMIT_FILE, emit_file),
     (GET_WATCH_FILES, get_watch_files),
-    (WARN, warn),
-    (ERROR, error),
     (SOURCE_MAP_ENABLED, source_map_enabled),
     (VITE_GET_IMPORTERS, vite_get_importers),
     (VITE_GET_MODULES_BY_FILE, vite_get_modules_by_file),
@@ -73,17 +79,35 @@ pub unsafe fn create_js_context(raw_env: napi_env, ctx: Arc<CompilationContext>)
     js_context = attach_context_method(raw_env, js_context, name, Some(cb), ctx.clone());
   }
 
+  let diagnostic_methods = vec![
+    (
+      WARN,
+      warn as unsafe extern "C" fn(napi_env, napi_callback_info) -> napi_value,
+    ),
+    (ERROR, error),
+  ];
+
+  for (name, cb) in diagnostic_methods {
+    js_context = attach_context_method(
+      raw_env,
+      js_context,
+      name,
+      Some(cb),
+      (ctx.clone(), plugin_name.clone()),
+    );
+  }
+
   js_context
 }
 
 /// Create a js resolve function based on [farmfe_core::context::CompilationContext]
 /// and attach it to the js context object
-fn attach_context_method(
+fn attach_context_method<T>(
   env: napi_env,
   mut context: JsObject,
   name: &str,
   cb: napi_callback,
-  ctx: Arc<CompilationContext>,
+  data: T,
 ) -> JsObject {
   let len = name.len();
   let s = CString::new(name).unwrap();
@@ -95,7 +119,7 @@ fn attach_context_method(
       s.as_ptr(),
       len,
       cb,
-      Box::into_raw(Box::new(ctx)) as *mut c_void,
+      Box::into_raw(Box::new(data)) as *mut c_void,
       &mut func,
     );
 
@@ -232,30 +256,74 @@ unsafe extern "C" fn get_watch_files(env: napi_env, info: napi_callback_info) ->
   Env::from_raw(env).to_js_value(&modules).unwrap().raw()
 }
 
-unsafe extern "C" fn warn(env: napi_env, info: napi_callback_info) -> napi_value {
-  let ArgvAndContext { argv, ctx } = get_argv_and_context_from_cb_info(env, info);
+/// Same as [get_argv_and_context_from_cb_info], for the methods that are attached with the plugin name
+unsafe fn get_argv_context_and_plugin_name_from_cb_info(
+  env: napi_env,
+  info: napi_callback_info,
+) -> (napi_value, Arc<CompilationContext>, Option<String>) {
+  let mut argv: [napi_value; 1] = [ptr::null_mut(); 1];
+  let mut data = ptr::null_mut();
+  napi_get_cb_info(
+    env,
+    info,
+    &mut 1,
+    argv.as_mut_ptr(),
+    ptr::null_mut(),
+    &mut data,
+  );
 
-  let message: String = Env::from_raw(env)
-    .from_js_value(JsUnknown::from_napi_value(env, argv[0]).unwrap())
-    .expect("Argument 0 should be a string when calling warn");
+  let (ctx, plugin_name) = &*data.cast::<(Arc<CompilationContext>, Option<String>)>();
+
+  (argv[0], ctx.clone(), plugin_name.clone())
+}
+
+unsafe extern "C" fn warn(env: napi_env, info: napi_callback_info) -> napi_value {
+  let (arg, ctx, plugin_name) = get_argv_context_and_plugin_name_from_cb_info(env, info);
 
-  ctx.log_store.lock().add_warning(message);
+  let diagnostic = diagnostic_from_js(env, arg, DiagnosticSeverity::Warning, plugin_name);
+  ctx.log_store.lock().add_warning(diagnostic);
 
   Env::from_raw(env).get_undefined().unwrap().raw()
 }
 
 unsafe extern "C" fn error(env: napi_env, info: napi_callback_info) -> napi_value {
-  let ArgvAndContext { argv, ctx } = get_argv_and_context_from_cb_info(env, info);
-
-  let message: String = Env::from_raw(env)
-    .from_js_value(JsUnknown::from_napi_value(env, argv[0]).unwrap())
-    .expect("Argument 0 should be a string when calling error");
+  let (arg, ctx, plugin_name) = get_argv_context_and_plugin_name_from_cb_info(env, info);
 
-  ctx.log_store.lock().add_error(message);
+  let diagnostic = diagnostic_from_js(env, arg, DiagnosticSeverity::Error, plugin_name);
+  ctx.log_store.lock().add_error(diagnostic);
 
   Env::from_raw(env).get_undefined().unwrap().raw()
 }
 
+/// `warn` and `error` accept a message, a [Diagnostic] object or its json string.
+/// Rollup errors and warnings that are serialized by the vite plugin adapter are accepted too.
+/// The plugin that reports it is filled unless it's specified
+unsafe fn diagnostic_from_js(
+  env: napi_env,
+  value: napi_value,
+  severity: DiagnosticSeverity,
+  plugin_name: Option<String>,
+) -> Diagnostic {
+  let value: serde_json::Value = Env::from_raw(env)
+    .from_js_value(JsUnknown::from_napi_value(env, value).unwrap())
+    .expect("Argument 0 should be a string or an object when calling warn or error");
+
+  let diagnostic = match value {
+    serde_json::Value::String(message) => serde_json::from_str::<Diagnostic>(&message)
+      .ok()
+      .filter(|d| !d.message.is_empty())
+      .unwrap_or_else(|| Diagnostic::error(message)),
+    value => serde_json::from_value::<Diagnostic>(value.clone())
+      .unwrap_or_else(|_| Diagnostic::error(value.to_string())),
+  };
+
+  Diagnostic {
+    severity,
+    plugin: diagnostic.plugin.or(plugin_name),
+    ..diagnostic
+  }
+}
+
 unsafe extern "C" fn source_map_enabled(env: napi_env, info: napi_callback_info) -> napi_value {
   let ArgvAndContext { argv, ctx } = get_argv_and_context_from_cb_info(env, info);
 
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use farmfe_core::{
+  context::CompilationContext,
+  error::Result,
+  module::{ModuleId, ModuleType},
+  plugin::{PluginAnalyzeDepsHookParam, PluginAnalyzeDepsHookResultEntry, ResolveKind},
+  serde::{Deserialize, Serialize},
+};
+use napi::{bindgen_prelude::FromNapiValue, NapiRaw};
+
+use crate::{
+  new_js_plugin_hook,
+  plugin_adapters::js_plugin_adapter::{
+    hooks::transform::{JsPluginTransformHookFilters, PluginTransformHookFilters},
+    thread_safe_js_plugin_hook::ThreadSafeJsPluginHook,
+  },
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsPluginAnalyzeDepsHookResultEntry {
+  pub source: String,
+  pub kind: ResolveKind,
+}
+
+/// The returned entries replace the deps analyzed by previous plugins
+pub type JsPluginAnalyzeDepsHookResult = Vec<JsPluginAnalyzeDepsHookResultEntry>;
+
+impl From<&PluginAnalyzeDepsHookResultEntry> for JsPluginAnalyzeDepsHookResultEntry {
+  fn from(value: &PluginAnalyzeDepsHookResultEntry) -> Self {
+    Self {
+      source: value.source.clone(),
+      kind: value.kind.clone(),
+    }
+  }
+}
+
+impl From<JsPluginAnalyzeDepsHookResultEntry> for PluginAnalyzeDepsHookResultEntry {
+  fn from(value: JsPluginAnalyzeDepsHookResultEntry) -> Self {
+    Self {
+      source: value.source,
+      kind: value.kind,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsPluginAnalyzeDepsHookParams {
+  pub module_id: String,
+  pub module_type: ModuleType,
+  pub content: Arc<String>,
+  /// deps analyzed by previous plugins
+  pub deps: Vec<JsPluginAnalyzeDepsHookResultEntry>,
+}
+
+impl<'a> From<&PluginAnalyzeDepsHookParam<'a>> for JsPluginAnalyzeDepsHookParams {
+  fn from(value: &PluginAnalyzeDepsHookParam<'a>) -> Self {
+    Self {
+      module_id: value.module.id.to_string(),
+      module_type: value.module.module_type.clone(),
+      content: value.module.content.clone(),
+      deps: value.deps.iter().map(|dep| dep.into()).collect(),
+    }
+  }
+}
+
+pub struct JsPluginAnalyzeDepsHook {
+  tsfn: ThreadSafeJsPluginHook,
+  filters: PluginTransformHookFilters,
+}
+
+impl JsPluginAnalyzeDepsHook {
+  new_js_plugin_hook!(
+    PluginTransformHookFilters,
+    JsPluginTransformHookFilters,
+    JsPluginAnalyzeDepsHookParams,
+    JsPluginAnalyzeDepsHookResult
+  );
+
+  pub fn call(
+    &self,
+    param: JsPluginAnalyzeDepsHookParams,
+    ctx: Arc<CompilationContext>,
+  ) -> Result<Option<JsPluginAnalyzeDepsHookResult>> {
+    if self.filters.is_match(
+      &ModuleId::from(param.module_id.as_str()),
+      &param.module_type,
+      &ctx.config.root,
+    ) {
+      self.tsfn.call(param, ctx, None)
+    } else {
+      Ok(None)
+    }
+  }
+}
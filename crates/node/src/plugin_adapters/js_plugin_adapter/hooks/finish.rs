@@ -1,3 +1,31 @@
-use crate::define_empty_params_js_plugin_hook;
+use std::sync::Arc;
 
-define_empty_params_js_plugin_hook!(JsPluginFinishHook);
+use farmfe_core::{
+  context::CompilationContext, error::Result, plugin::EmptyPluginHookResult, stats::Stats,
+};
+
+use crate::plugin_adapters::js_plugin_adapter::thread_safe_js_plugin_hook::ThreadSafeJsPluginHook;
+
+pub struct JsPluginFinishHook {
+  tsfn: ThreadSafeJsPluginHook,
+}
+
+impl JsPluginFinishHook {
+  pub fn new(env: &napi::Env, obj: napi::JsObject) -> Self {
+    let func = obj
+      .get_named_property::<napi::JsFunction>("executor")
+      .expect("executor should be checked in js side");
+
+    Self {
+      tsfn: ThreadSafeJsPluginHook::new::<Stats, EmptyPluginHookResult>(env, func),
+    }
+  }
+
+  pub fn call(
+    &self,
+    param: Stats,
+    ctx: Arc<CompilationContext>,
+  ) -> Result<Option<EmptyPluginHookResult>> {
+    self.tsfn.call(param, ctx, None)
+  }
+}
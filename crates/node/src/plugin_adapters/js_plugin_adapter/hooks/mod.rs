@@ -1,10 +1,13 @@
+pub mod analyze_deps;
 pub mod augment_resource_hash;
 pub mod build_end;
 pub mod build_start;
 pub mod finalize_resources;
 pub mod finish;
 pub mod load;
+pub mod optimize_resource_pot;
 pub mod plugin_cache_loaded;
+pub mod process_module;
 pub mod render_resource_pot;
 pub mod render_start;
 pub mod resolve;
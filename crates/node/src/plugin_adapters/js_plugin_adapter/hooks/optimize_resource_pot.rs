@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use farmfe_core::{
+  context::CompilationContext,
+  error::Result,
+  plugin::{PluginRenderResourcePotHookParam, PluginRenderResourcePotHookResult},
+  resource::resource_pot::{ResourcePot, ResourcePotInfo},
+};
+use napi::{bindgen_prelude::FromNapiValue, NapiRaw};
+
+use crate::{
+  new_js_plugin_hook,
+  plugin_adapters::js_plugin_adapter::{
+    hooks::render_resource_pot::{
+      JsPluginRenderResourcePotHookFilters, PluginRenderResourcePotHookFilters,
+    },
+    thread_safe_js_plugin_hook::ThreadSafeJsPluginHook,
+  },
+};
+
+/// Same as renderResourcePot, but receives the final rendered content of the resource pot
+pub struct JsPluginOptimizeResourcePotHook {
+  tsfn: ThreadSafeJsPluginHook,
+  filters: PluginRenderResourcePotHookFilters,
+}
+
+impl JsPluginOptimizeResourcePotHook {
+  new_js_plugin_hook!(
+    PluginRenderResourcePotHookFilters,
+    JsPluginRenderResourcePotHookFilters,
+    PluginRenderResourcePotHookParam,
+    PluginRenderResourcePotHookResult
+  );
+
+  pub fn call(
+    &self,
+    resource_pot: &mut ResourcePot,
+    ctx: Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    let param = PluginRenderResourcePotHookParam {
+      content: resource_pot.meta.rendered_content.clone(),
+      source_map_chain: resource_pot.meta.rendered_map_chain.clone(),
+      resource_pot_info: ResourcePotInfo::new(resource_pot),
+    };
+
+    if !self.filters.is_match(&param.resource_pot_info) {
+      return Ok(None);
+    }
+
+    let Some(result) = self
+      .tsfn
+      .call::<_, PluginRenderResourcePotHookResult>(param, ctx, None)?
+    else {
+      return Ok(None);
+    };
+
+    resource_pot.meta.rendered_content = Arc::new(result.content);
+
+    if let Some(source_map) = result.source_map {
+      resource_pot
+        .meta
+        .rendered_map_chain
+        .push(Arc::new(source_map));
+    }
+
+    Ok(Some(()))
+  }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use farmfe_core::{
+  context::CompilationContext,
+  error::Result,
+  module::{ModuleId, ModuleType},
+  plugin::{EmptyPluginHookResult, PluginProcessModuleHookParam},
+  serde::{Deserialize, Serialize},
+};
+use napi::{bindgen_prelude::FromNapiValue, NapiRaw};
+
+use crate::{
+  new_js_plugin_hook,
+  plugin_adapters::js_plugin_adapter::{
+    hooks::transform::{JsPluginTransformHookFilters, PluginTransformHookFilters},
+    thread_safe_js_plugin_hook::ThreadSafeJsPluginHook,
+  },
+};
+
+/// The ast of the module can not be passed to js side, so only the transformed content is exposed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase")]
+pub struct JsPluginProcessModuleHookParams {
+  pub module_id: String,
+  pub module_type: ModuleType,
+  pub content: Arc<String>,
+}
+
+impl<'a> From<&PluginProcessModuleHookParam<'a>> for JsPluginProcessModuleHookParams {
+  fn from(value: &PluginProcessModuleHookParam<'a>) -> Self {
+    Self {
+      module_id: value.module_id.to_string(),
+      module_type: value.module_type.clone(),
+      content: value.content.clone(),
+    }
+  }
+}
+
+/// The js `processModule` hook is read-only, the content can not be parsed back into the ast,
+/// so the returned value of the hook is ignored.
+pub struct JsPluginProcessModuleHook {
+  tsfn: ThreadSafeJsPluginHook,
+  filters: PluginTransformHookFilters,
+}
+
+impl JsPluginProcessModuleHook {
+  new_js_plugin_hook!(
+    PluginTransformHookFilters,
+    JsPluginTransformHookFilters,
+    JsPluginProcessModuleHookParams,
+    EmptyPluginHookResult
+  );
+
+  pub fn call(
+    &self,
+    param: JsPluginProcessModuleHookParams,
+    ctx: Arc<CompilationContext>,
+  ) -> Result<Option<EmptyPluginHookResult>> {
+    if self.filters.is_match(
+      &ModuleId::from(param.module_id.as_str()),
+      &param.module_type,
+      &ctx.config.root,
+    ) {
+      self.tsfn.call(param, ctx, None)
+    } else {
+      Ok(None)
+    }
+  }
+}
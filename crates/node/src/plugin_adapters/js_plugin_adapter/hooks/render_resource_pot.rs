@@ -5,7 +5,7 @@ use farmfe_core::{
   context::CompilationContext,
   error::Result,
   plugin::{PluginRenderResourcePotHookParam, PluginRenderResourcePotHookResult},
-  resource::resource_pot::ResourcePotType,
+  resource::resource_pot::{ResourcePotInfo, ResourcePotType},
 };
 use napi::{bindgen_prelude::FromNapiValue, NapiRaw};
 
@@ -31,6 +31,21 @@ pub struct JsPluginRenderResourcePotHook {
   filters: PluginRenderResourcePotHookFilters,
 }
 
+impl PluginRenderResourcePotHookFilters {
+  /// matches if the resource pot type or any of the module ids matches
+  pub fn is_match(&self, resource_pot_info: &ResourcePotInfo) -> bool {
+    self
+      .resource_pot_types
+      .contains(&resource_pot_info.resource_pot_type)
+      || self.module_ids.iter().any(|f| {
+        resource_pot_info
+          .module_ids
+          .iter()
+          .any(|id| f.is_match(&id.to_string()))
+      })
+  }
+}
+
 impl From<JsPluginRenderResourcePotHookFilters> for PluginRenderResourcePotHookFilters {
   fn from(f: JsPluginRenderResourcePotHookFilters) -> Self {
     Self {
@@ -61,18 +76,7 @@ impl JsPluginRenderResourcePotHook {
     param: PluginRenderResourcePotHookParam,
     ctx: Arc<CompilationContext>,
   ) -> Result<Option<PluginRenderResourcePotHookResult>> {
-    if self
-      .filters
-      .resource_pot_types
-      .contains(&param.resource_pot_info.resource_pot_type)
-      || self.filters.module_ids.iter().any(|f| {
-        param
-          .resource_pot_info
-          .module_ids
-          .iter()
-          .any(|id| f.is_match(&id.to_string()))
-      })
-    {
+    if self.filters.is_match(&param.resource_pot_info) {
       self.tsfn.call(param, ctx, None)
     } else {
       Ok(None)
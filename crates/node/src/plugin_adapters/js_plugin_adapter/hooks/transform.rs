@@ -31,16 +31,11 @@ impl JsPluginTransformHook {
     param: PluginTransformHookParam,
     ctx: Arc<CompilationContext>,
   ) -> Result<Option<PluginTransformHookResult>> {
-    if self.filters.resolved_paths.iter().any(|f| {
-      f.is_match(
-        &ModuleId::from(param.module_id.as_str()).resolved_path_with_query(&ctx.config.root),
-      )
-    }) || self
-      .filters
-      .module_types
-      .iter()
-      .any(|ty| &param.module_type == ty)
-    {
+    if self.filters.is_match(
+      &ModuleId::from(param.module_id.as_str()),
+      &param.module_type,
+      &ctx.config.root,
+    ) {
       self
         .tsfn
         .call::<PluginTransformHookParam, PluginTransformHookResult>(param, ctx, None)
@@ -62,6 +57,17 @@ pub struct PluginTransformHookFilters {
   pub module_types: Vec<ModuleType>,
 }
 
+impl PluginTransformHookFilters {
+  /// matches if any of the resolved paths or module types matches
+  pub fn is_match(&self, module_id: &ModuleId, module_type: &ModuleType, root: &str) -> bool {
+    self
+      .resolved_paths
+      .iter()
+      .any(|f| f.is_match(&module_id.resolved_path_with_query(root)))
+      || self.module_types.iter().any(|ty| module_type == ty)
+  }
+}
+
 impl From<JsPluginTransformHookFilters> for PluginTransformHookFilters {
   fn from(f: JsPluginTransformHookFilters) -> Self {
     Self {
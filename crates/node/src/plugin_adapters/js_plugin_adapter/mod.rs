@@ -7,22 +7,26 @@ use farmfe_core::{
   error::{CompilationError, Result},
   module::ModuleType,
   plugin::{
-    EmptyPluginHookParam, Plugin, PluginFinalizeResourcesHookParams, PluginHookContext,
-    PluginLoadHookParam, PluginLoadHookResult, PluginResolveHookParam, PluginResolveHookResult,
-    PluginTransformHookParam, PluginTransformHookResult, UpdateType, DEFAULT_PRIORITY,
+    EmptyPluginHookParam, Plugin, PluginAnalyzeDepsHookParam, PluginFinalizeResourcesHookParams,
+    PluginHookContext, PluginLoadHookParam, PluginLoadHookResult, PluginProcessModuleHookParam,
+    PluginResolveHookParam, PluginResolveHookResult, PluginTransformHookParam,
+    PluginTransformHookResult, UpdateType, DEFAULT_PRIORITY,
   },
-  resource::{Resource, ResourceOrigin, ResourceType},
+  resource::{resource_pot::ResourcePot, Resource, ResourceOrigin, ResourceType},
 };
 use napi::{bindgen_prelude::FromNapiValue, Env, JsObject, JsUnknown, NapiRaw};
 
 use self::hooks::{
+  analyze_deps::JsPluginAnalyzeDepsHook,
   augment_resource_hash::JsPluginAugmentResourceHashHook,
   build_end::JsPluginBuildEndHook,
   build_start::JsPluginBuildStartHook,
   finalize_resources::JsPluginFinalizeResourcesHook,
   finish::JsPluginFinishHook,
   load::JsPluginLoadHook,
+  optimize_resource_pot::JsPluginOptimizeResourcePotHook,
   plugin_cache_loaded::JsPluginPluginCacheLoadedHook,
+  process_module::JsPluginProcessModuleHook,
   render_resource_pot::JsPluginRenderResourcePotHook,
   render_start::JsPluginRenderStartHook,
   resolve::JsPluginResolveHook,
@@ -34,6 +38,8 @@ use self::hooks::{
   write_plugin_cache::JsPluginWritePluginCacheHook,
 };
 
+use self::context::JS_PLUGIN_NAME;
+
 pub mod context;
 mod context_methods;
 mod hooks;
@@ -46,6 +52,8 @@ pub struct JsPluginAdapter {
   js_resolve_hook: Option<JsPluginResolveHook>,
   js_load_hook: Option<JsPluginLoadHook>,
   js_transform_hook: Option<JsPluginTransformHook>,
+  js_process_module_hook: Option<JsPluginProcessModuleHook>,
+  js_analyze_deps_hook: Option<JsPluginAnalyzeDepsHook>,
   js_build_end_hook: Option<JsPluginBuildEndHook>,
   js_finish_hook: Option<JsPluginFinishHook>,
   js_update_modules_hook: Option<JsPluginUpdateModulesHook>,
@@ -54,13 +62,14 @@ pub struct JsPluginAdapter {
   js_render_resource_pot_hook: Option<JsPluginRenderResourcePotHook>,
   js_render_start_hook: Option<JsPluginRenderStartHook>,
   js_augment_resource_hash_hook: Option<JsPluginAugmentResourceHashHook>,
+  js_optimize_resource_pot_hook: Option<JsPluginOptimizeResourcePotHook>,
   js_finalize_resources_hook: Option<JsPluginFinalizeResourcesHook>,
   js_transform_html_hook: Option<JsPluginTransformHtmlHook>,
 }
 
 impl JsPluginAdapter {
   pub fn new(env: &Env, js_plugin_object: JsObject) -> Result<Self> {
-    let name = get_named_property(env, &js_plugin_object, "name")?;
+    let name = get_named_property::<String>(env, &js_plugin_object, "name")?;
     let priority =
       get_named_property::<i32>(env, &js_plugin_object, "priority").unwrap_or(DEFAULT_PRIORITY);
 
@@ -70,6 +79,10 @@ impl JsPluginAdapter {
     let load_hook_obj = get_named_property::<JsObject>(env, &js_plugin_object, "load").ok();
     let transform_hook_obj =
       get_named_property::<JsObject>(env, &js_plugin_object, "transform").ok();
+    let process_module_hook_obj =
+      get_named_property::<JsObject>(env, &js_plugin_object, "processModule").ok();
+    let analyze_deps_hook_obj =
+      get_named_property::<JsObject>(env, &js_plugin_object, "analyzeDeps").ok();
     let build_end_hook_obj =
       get_named_property::<JsObject>(env, &js_plugin_object, "buildEnd").ok();
     let finish_hook_obj = get_named_property::<JsObject>(env, &js_plugin_object, "finish").ok();
@@ -85,11 +98,39 @@ impl JsPluginAdapter {
       get_named_property::<JsObject>(env, &js_plugin_object, "renderStart").ok();
     let augment_resource_hash_obj =
       get_named_property::<JsObject>(env, &js_plugin_object, "augmentResourceHash").ok();
+    let optimize_resource_pot_obj =
+      get_named_property::<JsObject>(env, &js_plugin_object, "optimizeResourcePot").ok();
     let finalize_resources_obj =
       get_named_property::<JsObject>(env, &js_plugin_object, "finalizeResources").ok();
     let transform_html_obj =
       get_named_property::<JsObject>(env, &js_plugin_object, "transformHtml").ok();
 
+    // the js context created for the executors reports the plugin name in warnings and errors
+    for hook_obj in [
+      &build_start_hook_obj,
+      &resolve_hook_obj,
+      &load_hook_obj,
+      &transform_hook_obj,
+      &process_module_hook_obj,
+      &analyze_deps_hook_obj,
+      &build_end_hook_obj,
+      &finish_hook_obj,
+      &update_modules_hook_obj,
+      &plugin_cache_loaded_obj,
+      &write_plugin_cache_obj,
+      &render_resource_pot_obj,
+      &render_start_obj,
+      &augment_resource_hash_obj,
+      &optimize_resource_pot_obj,
+      &finalize_resources_obj,
+      &transform_html_obj,
+    ]
+    .into_iter()
+    .flatten()
+    {
+      set_executor_plugin_name(env, hook_obj, &name)?;
+    }
+
     Ok(Self {
       name,
       priority,
@@ -97,6 +138,9 @@ impl JsPluginAdapter {
       js_resolve_hook: resolve_hook_obj.map(|obj| JsPluginResolveHook::new(env, obj)),
       js_load_hook: load_hook_obj.map(|obj| JsPluginLoadHook::new(env, obj)),
       js_transform_hook: transform_hook_obj.map(|obj| JsPluginTransformHook::new(env, obj)),
+      js_process_module_hook: process_module_hook_obj
+        .map(|obj| JsPluginProcessModuleHook::new(env, obj)),
+      js_analyze_deps_hook: analyze_deps_hook_obj.map(|obj| JsPluginAnalyzeDepsHook::new(env, obj)),
       js_build_end_hook: build_end_hook_obj.map(|obj| JsPluginBuildEndHook::new(env, obj)),
       js_finish_hook: finish_hook_obj.map(|obj| JsPluginFinishHook::new(env, obj)),
       js_update_modules_hook: update_modules_hook_obj
@@ -110,6 +154,8 @@ impl JsPluginAdapter {
       js_render_start_hook: render_start_obj.map(|obj| JsPluginRenderStartHook::new(env, obj)),
       js_augment_resource_hash_hook: augment_resource_hash_obj
         .map(|obj| JsPluginAugmentResourceHashHook::new(env, obj)),
+      js_optimize_resource_pot_hook: optimize_resource_pot_obj
+        .map(|obj| JsPluginOptimizeResourcePotHook::new(env, obj)),
       js_finalize_resources_hook: finalize_resources_obj
         .map(|obj| JsPluginFinalizeResourcesHook::new(env, obj)),
       js_transform_html_hook: transform_html_obj
@@ -237,6 +283,43 @@ impl Plugin for JsPluginAdapter {
     }
   }
 
+  fn process_module(
+    &self,
+    param: &mut PluginProcessModuleHookParam,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    if self.is_internal_virtual_module(&param.module_id.to_string()) {
+      return Ok(None);
+    }
+
+    if let Some(js_process_module_hook) = &self.js_process_module_hook {
+      js_process_module_hook.call((&*param).into(), context.clone())?;
+      Ok(Some(()))
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn analyze_deps(
+    &self,
+    param: &mut PluginAnalyzeDepsHookParam,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    if self.is_internal_virtual_module(&param.module.id.to_string()) {
+      return Ok(None);
+    }
+
+    if let Some(js_analyze_deps_hook) = &self.js_analyze_deps_hook {
+      if let Some(deps) = js_analyze_deps_hook.call((&*param).into(), context.clone())? {
+        param.deps = deps.into_iter().map(|dep| dep.into()).collect();
+      }
+
+      Ok(Some(()))
+    } else {
+      Ok(None)
+    }
+  }
+
   fn build_end(&self, context: &Arc<CompilationContext>) -> Result<Option<()>> {
     if let Some(js_build_end_hook) = &self.js_build_end_hook {
       js_build_end_hook.call(EmptyPluginHookParam {}, context.clone())?;
@@ -276,11 +359,11 @@ impl Plugin for JsPluginAdapter {
 
   fn finish(
     &self,
-    _stat: &farmfe_core::stats::Stats,
+    stat: &farmfe_core::stats::Stats,
     context: &Arc<CompilationContext>,
   ) -> Result<Option<()>> {
     if let Some(js_finish_hook) = &self.js_finish_hook {
-      js_finish_hook.call(EmptyPluginHookParam {}, context.clone())?;
+      js_finish_hook.call(stat.clone(), context.clone())?;
       Ok(Some(()))
     } else {
       Ok(None)
@@ -337,6 +420,18 @@ impl Plugin for JsPluginAdapter {
     }
   }
 
+  fn optimize_resource_pot(
+    &self,
+    resource_pot: &mut ResourcePot,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    if let Some(js_optimize_resource_pot_hook) = &self.js_optimize_resource_pot_hook {
+      js_optimize_resource_pot_hook.call(resource_pot, context.clone())
+    } else {
+      Ok(None)
+    }
+  }
+
   fn finalize_resources(
     &self,
     params: &mut PluginFinalizeResourcesHookParams,
@@ -381,6 +476,20 @@ impl Plugin for JsPluginAdapter {
   }
 }
 
+fn set_executor_plugin_name(env: &Env, hook_obj: &JsObject, name: &str) -> Result<()> {
+  let mut executor = get_named_property::<JsObject>(env, hook_obj, "executor")?;
+
+  env
+    .create_string(name)
+    .and_then(|name| executor.set_named_property(JS_PLUGIN_NAME, name))
+    .map_err(|e| {
+      CompilationError::NAPIError(format!(
+        "Set plugin name {} of executor failed. {:?}",
+        name, e
+      ))
+    })
+}
+
 pub fn get_named_property<T: FromNapiValue>(env: &Env, obj: &JsObject, field: &str) -> Result<T> {
   if obj.has_named_property(field).map_err(|e| {
     CompilationError::NAPIError(format!(
@@ -37,7 +37,7 @@ use napi::{
   Env, JsFunction, JsObject, JsUnknown, NapiRaw, NapiValue, ValueType,
 };
 
-use super::context::create_js_context;
+use super::context::{create_js_context, JS_PLUGIN_NAME};
 
 pub struct ThreadSafeJsPluginHook {
   raw_tsfn: napi_threadsafe_function,
@@ -147,7 +147,11 @@ unsafe extern "C" fn call_js_cb<P: Serialize, T: DeserializeOwned + Debug + Send
     .unwrap()
     == ValueType::Undefined
   {
-    let new_js_context = create_js_context(raw_env, ctx);
+    let plugin_name = js_func
+      .get_named_property::<JsUnknown>(JS_PLUGIN_NAME)
+      .and_then(|name| Env::from_raw(raw_env).from_js_value::<Option<String>, _>(name))
+      .unwrap_or_default();
+    let new_js_context = create_js_context(raw_env, ctx, plugin_name);
     js_func
       .set_named_property("farm_js_plugin_context", new_js_context)
       .unwrap();
@@ -0,0 +1,121 @@
+#![deny(clippy::all)]
+
+use std::{io::Write, sync::Arc};
+
+use farmfe_core::{
+  config::{CompressConfig, Config},
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  plugin::{Plugin, PluginFinalizeResourcesHookParams},
+  rayon::prelude::{IntoParallelIterator, ParallelIterator},
+  resource::{Resource, ResourceType},
+};
+use flate2::{write::GzEncoder, Compression};
+
+/// Emit pre-compressed `.gz` and `.br` siblings of the js, css, html, svg and json resources,
+/// so the server can serve them directly instead of compressing on the fly.
+pub struct FarmPluginCompress {
+  options: CompressConfig,
+}
+
+impl FarmPluginCompress {
+  pub fn new(config: &Config) -> Self {
+    Self {
+      options: config.output.compress.clone().unwrap_or_default(),
+    }
+  }
+
+  fn should_compress(&self, resource: &Resource) -> bool {
+    if resource.emitted || resource.bytes.len() < self.options.threshold {
+      return false;
+    }
+
+    match &resource.resource_type {
+      ResourceType::Js | ResourceType::Css | ResourceType::Html => true,
+      _ => resource.name.ends_with(".svg") || resource.name.ends_with(".json"),
+    }
+  }
+
+  fn compress(&self, resource: &Resource) -> Result<Vec<Resource>> {
+    let mut compressed = vec![];
+
+    if self.options.gzip {
+      compressed.push(("gz", gzip(&resource.bytes, self.options.gzip_level)?));
+    }
+
+    if self.options.brotli {
+      compressed.push(("br", brotli(&resource.bytes, self.options.brotli_level)?));
+    }
+
+    Ok(
+      compressed
+        .into_iter()
+        // the compressed sibling is useless if it's not smaller than the original
+        .filter(|(_, bytes)| bytes.len() < resource.bytes.len())
+        .map(|(ext, bytes)| Resource {
+          name: format!("{}.{ext}", resource.name),
+          bytes,
+          emitted: false,
+          resource_type: ResourceType::Custom(ext.to_string()),
+          origin: resource.origin.clone(),
+          info: None,
+        })
+        .collect(),
+    )
+  }
+}
+
+impl Plugin for FarmPluginCompress {
+  fn name(&self) -> &str {
+    "FarmPluginCompress"
+  }
+
+  /// Make sure the resources are compressed after all resources are finalized, including the manifest
+  fn priority(&self) -> i32 {
+    -100
+  }
+
+  fn finalize_resources(
+    &self,
+    params: &mut PluginFinalizeResourcesHookParams,
+    _context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    let resources = params
+      .resources_map
+      .values()
+      .filter(|r| self.should_compress(r))
+      .collect::<Vec<_>>();
+
+    let compressed = resources
+      .into_par_iter()
+      .map(|resource| self.compress(resource))
+      .collect::<Result<Vec<_>>>()?;
+
+    for resource in compressed.into_iter().flatten() {
+      params.resources_map.insert(resource.name.clone(), resource);
+    }
+
+    Ok(Some(()))
+  }
+}
+
+fn gzip(bytes: &[u8], level: u32) -> Result<Vec<u8>> {
+  let mut encoder = GzEncoder::new(vec![], Compression::new(level.min(9)));
+  encoder
+    .write_all(bytes)
+    .and_then(|_| encoder.finish())
+    .map_err(|e| CompilationError::GenericError(format!("Failed to gzip resource: {e}")))
+}
+
+fn brotli(bytes: &[u8], level: u32) -> Result<Vec<u8>> {
+  let mut compressed = vec![];
+
+  {
+    let mut writer = brotli::CompressorWriter::new(&mut compressed, 4096, level.min(11), 22);
+    writer
+      .write_all(bytes)
+      .map_err(|e| CompilationError::GenericError(format!("Failed to brotli resource: {e}")))?;
+  }
+
+  Ok(compressed)
+}
@@ -0,0 +1,245 @@
+use std::path::PathBuf;
+
+use farmfe_core::{
+  config::{CssModulesConfig, LocalsConvention},
+  error::CompilationError,
+  serde_json::Value,
+  swc_ecma_ast::Ident,
+};
+use farmfe_utils::relative;
+
+/// the class names exported by a css module, `(export key, class names)` pairs
+pub struct CssModulesExports {
+  exports: Vec<(String, String)>,
+}
+
+impl CssModulesExports {
+  /// Apply `locals_convention` to the class names, `classes` is the space separated class names after renaming.
+  /// The names should be sorted so that the generated code is stable. Class names converted to the same key
+  /// are merged like css-loader does, e.g. `.foo-bar` and `.fooBar` are both exported by `fooBar` with `camelCaseOnly`
+  pub fn new(names: Vec<(String, String)>, locals_convention: LocalsConvention) -> Self {
+    let mut exports: Vec<(String, String)> = vec![];
+
+    let mut push = |key: String, classes: &String| {
+      let Some((_, existing)) = exports.iter_mut().find(|(k, _)| k == &key) else {
+        exports.push((key, classes.clone()));
+        return;
+      };
+
+      for class in classes.split_whitespace() {
+        if !existing.split_whitespace().any(|c| c == class) {
+          existing.push(' ');
+          existing.push_str(class);
+        }
+      }
+    };
+
+    for (name, classes) in &names {
+      match locals_convention {
+        LocalsConvention::AsIs => push(name.clone(), classes),
+        LocalsConvention::CamelCase => {
+          push(name.clone(), classes);
+          push(camel_case(name, &['-', '_']), classes);
+        }
+        LocalsConvention::CamelCaseOnly => push(camel_case(name, &['-', '_']), classes),
+        LocalsConvention::Dashes => {
+          push(name.clone(), classes);
+          push(camel_case(name, &['-']), classes);
+        }
+      }
+    }
+
+    Self { exports }
+  }
+
+  fn is_named_export(key: &str) -> bool {
+    key != "default" && Ident::verify_symbol(key).is_ok()
+  }
+
+  /// Generate the exports of the js module. Class names that are valid identifiers are exported as
+  /// `export const` when `named_exports` is true, and the default export references them
+  pub fn to_js(&self, named_exports: bool) -> String {
+    let mut code = vec![];
+    let mut default_props = vec![];
+
+    for (key, classes) in &self.exports {
+      let quoted_key = Value::String(key.clone()).to_string();
+
+      if named_exports && Self::is_named_export(key) {
+        code.push(format!("export const {key} = `{classes}`;"));
+        default_props.push(format!("{quoted_key}: {key}"));
+      } else {
+        default_props.push(format!("{quoted_key}: `{classes}`"));
+      }
+    }
+
+    code.push(format!("export default {{{}}}", default_props.join(",")));
+    code.join("\n")
+  }
+
+  /// Generate the typings of the exports
+  pub fn to_dts(&self, named_exports: bool) -> String {
+    let mut dts = vec![
+      "// This file is generated by farm from the css module, do not edit it manually".to_string(),
+      "declare const styles: {".to_string(),
+    ];
+
+    for (key, _) in &self.exports {
+      dts.push(format!(
+        "  readonly {}: string;",
+        Value::String(key.clone())
+      ));
+    }
+
+    dts.push("};".to_string());
+    dts.push("export default styles;".to_string());
+
+    if named_exports {
+      for (key, _) in &self.exports {
+        if Self::is_named_export(key) {
+          dts.push(format!("export declare const {key}: string;"));
+        }
+      }
+    }
+
+    dts.push(String::new());
+    dts.join("\n")
+  }
+}
+
+/// Remove the separators and upper case the letter after them, e.g. `foo-bar_baz` -> `fooBarBaz`
+fn camel_case(name: &str, separators: &[char]) -> String {
+  let mut result = String::with_capacity(name.len());
+  let mut upper_next = false;
+
+  for c in name.chars() {
+    if separators.contains(&c) {
+      upper_next = true;
+    } else if upper_next {
+      result.extend(c.to_uppercase());
+      upper_next = false;
+    } else {
+      result.push(c);
+    }
+  }
+
+  result
+}
+
+/// Write `<css file>.d.ts` next to the css file or into `dts.outDir`. The file is only written when the content changes,
+/// so that the watchers of the typings are not triggered by every update. Css modules in node_modules are skipped
+pub fn write_css_modules_dts(
+  resolved_path: &str,
+  root: &str,
+  config: &CssModulesConfig,
+  dts: &str,
+) -> farmfe_core::error::Result<()> {
+  if resolved_path.contains("node_modules") {
+    return Ok(());
+  }
+
+  let dts_path = match config.dts.clone().unwrap_or_default().out_dir {
+    Some(out_dir) => PathBuf::from(root)
+      .join(out_dir)
+      .join(format!("{}.d.ts", relative(root, resolved_path))),
+    None => PathBuf::from(format!("{resolved_path}.d.ts")),
+  };
+
+  if std::fs::read_to_string(&dts_path).is_ok_and(|content| content == dts) {
+    return Ok(());
+  }
+
+  let write = || {
+    if let Some(parent) = dts_path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(&dts_path, dts)
+  };
+
+  write().map_err(|e| {
+    CompilationError::GenericError(format!(
+      "failed to write css modules typings {}: {e}",
+      dts_path.display()
+    ))
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use farmfe_core::config::LocalsConvention;
+
+  use super::CssModulesExports;
+
+  fn names() -> Vec<(String, String)> {
+    vec![
+      ("foo-bar".to_string(), "foo-bar-1".to_string()),
+      ("foo_baz".to_string(), "foo_baz-1".to_string()),
+    ]
+  }
+
+  #[test]
+  fn locals_convention() {
+    let keys = |convention| {
+      CssModulesExports::new(names(), convention)
+        .exports
+        .into_iter()
+        .map(|(k, _)| k)
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(keys(LocalsConvention::AsIs), vec!["foo-bar", "foo_baz"]);
+    assert_eq!(
+      keys(LocalsConvention::CamelCase),
+      vec!["foo-bar", "fooBar", "foo_baz", "fooBaz"]
+    );
+    assert_eq!(
+      keys(LocalsConvention::CamelCaseOnly),
+      vec!["fooBar", "fooBaz"]
+    );
+    assert_eq!(
+      keys(LocalsConvention::Dashes),
+      vec!["foo-bar", "fooBar", "foo_baz"]
+    );
+  }
+
+  #[test]
+  fn colliding_keys() {
+    let names = vec![
+      ("foo-bar".to_string(), "foo-bar-1".to_string()),
+      ("fooBar".to_string(), "fooBar-1 base-1".to_string()),
+    ];
+
+    assert_eq!(
+      CssModulesExports::new(names.clone(), LocalsConvention::CamelCaseOnly).exports,
+      vec![(
+        "fooBar".to_string(),
+        "foo-bar-1 fooBar-1 base-1".to_string()
+      )]
+    );
+    assert_eq!(
+      CssModulesExports::new(names, LocalsConvention::CamelCase).exports,
+      vec![
+        ("foo-bar".to_string(), "foo-bar-1".to_string()),
+        (
+          "fooBar".to_string(),
+          "foo-bar-1 fooBar-1 base-1".to_string()
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn named_exports() {
+    let exports = CssModulesExports::new(names(), LocalsConvention::Dashes);
+
+    assert_eq!(
+      exports.to_js(true),
+      "export const fooBar = `foo-bar-1`;\nexport const foo_baz = `foo_baz-1`;\nexport default {\"foo-bar\": `foo-bar-1`,\"fooBar\": fooBar,\"foo_baz\": foo_baz}"
+    );
+    assert_eq!(
+      exports.to_dts(true),
+      "// This file is generated by farm from the css module, do not edit it manually\ndeclare const styles: {\n  readonly \"foo-bar\": string;\n  readonly \"fooBar\": string;\n  readonly \"foo_baz\": string;\n};\nexport default styles;\nexport declare const fooBar: string;\nexport declare const foo_baz: string;\n"
+    );
+  }
+}
@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 use std::{path::PathBuf, sync::Arc};
 
+use css_modules_exports::{write_css_modules_dts, CssModulesExports};
 use dep_analyzer::DepAnalyzer;
 use farmfe_core::config::minify::MinifyOptions;
 use farmfe_core::module::CommentsMetaData;
@@ -62,6 +63,7 @@ lazy_static! {
     Regex::new(&format!("(?:\\?|&){FARM_CSS_MODULES}")).unwrap();
 }
 
+mod css_modules_exports;
 mod dep_analyzer;
 mod source_replacer;
 pub mod transform_css_to_script;
@@ -277,19 +279,14 @@ impl Plugin for FarmPluginCss {
           ),
         )?;
 
+        let css_modules_config = context.config.css.modules.as_ref().unwrap();
+
         // js code for css modules
         // next, get ident from ast and export through JS
         let stylesheet = compile(
           &mut css_stylesheet,
           CssModuleRename {
-            indent_name: context
-              .config
-              .css
-              .modules
-              .as_ref()
-              .unwrap()
-              .indent_name
-              .clone(),
+            indent_name: css_modules_config.indent_name.clone(),
             hash: sha256(css_modules_module_id.to_string().as_bytes(), 8),
           },
         );
@@ -327,16 +324,30 @@ impl Plugin for FarmPluginCss {
               }
             }
           }
-          export_names.push((name, after_transform_classes));
+          export_names.push((
+            name.to_string(),
+            after_transform_classes.join(" ").trim().to_string(),
+          ));
         }
 
-        export_names.sort_by_key(|e| e.0);
+        export_names.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let exports = CssModulesExports::new(export_names, css_modules_config.locals_convention);
+
+        if css_modules_config.dts.enabled() {
+          write_css_modules_dts(
+            param.resolved_path,
+            &context.config.root,
+            css_modules_config,
+            &exports.to_dts(css_modules_config.named_exports),
+          )?;
+        }
 
         let code = format!(
           r#"
     import "{}";
     {}
-    export default {{{}}}
+    {}
     "#,
           css_modules_module_id.to_string(),
           dynamic_import_of_composes
@@ -346,11 +357,7 @@ impl Plugin for FarmPluginCss {
               acc
             })
             .join(";\n"),
-          export_names
-            .iter()
-            .map(|(name, classes)| format!("\"{}\": `{}`", name, classes.join(" ").trim()))
-            .collect::<Vec<String>>()
-            .join(",")
+          exports.to_js(css_modules_config.named_exports)
         );
 
         // collapse sourcemap chain
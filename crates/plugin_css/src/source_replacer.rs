@@ -1,11 +1,12 @@
 use std::collections::HashMap;
 
 use farmfe_core::{
-  module::{module_graph::ModuleGraph, ModuleId},
+  module::{module_graph::ModuleGraph, Module, ModuleId},
   plugin::ResolveKind,
   resource::{Resource, ResourceOrigin},
   swc_common::DUMMY_SP,
   swc_css_ast::{AtRulePrelude, ImportHref, Rule, Str, Stylesheet, Url, UrlValue},
+  swc_ecma_ast::{ExportDefaultExpr, Expr, Lit, ModuleDecl, ModuleItem},
 };
 use farmfe_toolkit::swc_css_visit::{VisitMut, VisitMutWith};
 
@@ -70,6 +71,15 @@ impl<'a> VisitMut for SourceReplacer<'a> {
               }
             }
 
+            // the asset is inlined as a data url by `assets.inlineLimit`
+            if let Some(data_url) = self
+              .module_graph
+              .module(&dep_module)
+              .and_then(get_inlined_asset_url)
+            {
+              return data_url;
+            }
+
             panic!(
               "can not find resource: resolving {:?} for {:?}. dep: {:?}",
               source, self.module_id, dep_module
@@ -136,3 +146,28 @@ impl<'a> VisitMut for SourceReplacer<'a> {
     stylesheet.visit_mut_children_with(self);
   }
 }
+
+/// data url of the inlined asset module, e.g. `export default "data:image/png;base64,xxx"`
+fn get_inlined_asset_url(module: &Module) -> Option<String> {
+  if !module.module_type.is_script() {
+    return None;
+  }
+
+  module
+    .meta
+    .as_script()
+    .ast
+    .body
+    .iter()
+    .find_map(|item| match item {
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+        expr: box Expr::Lit(Lit::Str(str)),
+        ..
+      }))
+        if str.value.starts_with("data:") =>
+      {
+        Some(str.value.to_string())
+      }
+      _ => None,
+    })
+}
@@ -0,0 +1,163 @@
+use farmfe_core::{
+  swc_common::{Mark, SyntaxContext, DUMMY_SP},
+  swc_ecma_ast::{
+    Expr, Ident, Lit, MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, Str, UnaryExpr, UnaryOp,
+    UpdateExpr,
+  },
+};
+use farmfe_toolkit::swc_ecma_visit::{VisitMut, VisitMutWith};
+
+/// A define key that can be matched against the ast, e.g. `process.env.NODE_ENV`, `import.meta.env.MODE`, `__DEV__` or `typeof window`
+pub struct DefineExpr {
+  pub key: String,
+  /// segments of the member expression, `import.meta` is treated as a single segment
+  pub segments: Vec<String>,
+  /// true if the key starts with `typeof `
+  pub is_typeof: bool,
+  /// the value of the define, the spans are dropped
+  pub value: Box<Expr>,
+}
+
+impl DefineExpr {
+  /// Parse the define key, return None if the key is not a identifier or a member expression
+  pub fn parse_key(key: &str) -> Option<(Vec<String>, bool)> {
+    let (key, is_typeof) = match key.strip_prefix("typeof ") {
+      Some(key) => (key.trim(), true),
+      None => (key, false),
+    };
+
+    let segments = match key.strip_prefix(IMPORT_META) {
+      Some("") => vec![IMPORT_META.to_string()],
+      Some(rest) => [IMPORT_META]
+        .into_iter()
+        .chain(rest.strip_prefix('.')?.split('.'))
+        .map(|s| s.to_string())
+        .collect(),
+      None => key.split('.').map(|s| s.to_string()).collect(),
+    };
+
+    let is_ident = |s: &str| {
+      !s.is_empty()
+        && !s.starts_with(|c: char| c.is_ascii_digit())
+        && s
+          .chars()
+          .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    };
+
+    if segments
+      .iter()
+      .enumerate()
+      .all(|(i, s)| is_ident(s) || (i == 0 && s == IMPORT_META))
+    {
+      Some((segments, is_typeof))
+    } else {
+      None
+    }
+  }
+}
+
+const IMPORT_META: &str = "import.meta";
+
+/// Replace the references of define keys in the script module,
+/// only global identifiers are replaced so that local variables that shadow the key are left untouched.
+pub struct DefineReplacer<'a> {
+  defines: &'a [DefineExpr],
+  unresolved_mark: Mark,
+  /// whether any expression is replaced
+  pub replaced: bool,
+}
+
+impl<'a> DefineReplacer<'a> {
+  pub fn new(defines: &'a [DefineExpr], unresolved_mark: Mark) -> Self {
+    Self {
+      defines,
+      unresolved_mark,
+      replaced: false,
+    }
+  }
+
+  /// get segments of `a.b.c` or `import.meta.a`, None if the root identifier is a local variable
+  fn segments_of(&self, expr: &Expr) -> Option<Vec<String>> {
+    match expr {
+      Expr::Ident(Ident { span, sym, .. }) if span.ctxt.outer() == self.unresolved_mark => {
+        Some(vec![sym.to_string()])
+      }
+      Expr::MetaProp(MetaPropExpr {
+        kind: MetaPropKind::ImportMeta,
+        ..
+      }) => Some(vec![IMPORT_META.to_string()]),
+      Expr::Member(MemberExpr { obj, prop, .. }) => {
+        let prop = match prop {
+          MemberProp::Ident(ident) => ident.sym.to_string(),
+          MemberProp::Computed(computed) => match &*computed.expr {
+            Expr::Lit(Lit::Str(Str { value, .. })) => value.to_string(),
+            _ => return None,
+          },
+          MemberProp::PrivateName(_) => return None,
+        };
+        let mut segments = self.segments_of(obj)?;
+        segments.push(prop);
+        Some(segments)
+      }
+      Expr::Paren(paren) => self.segments_of(&paren.expr),
+      _ => None,
+    }
+  }
+
+  fn find_define(&self, expr: &Expr, is_typeof: bool) -> Option<Box<Expr>> {
+    let segments = self.segments_of(expr)?;
+
+    self
+      .defines
+      .iter()
+      .find(|define| define.is_typeof == is_typeof && define.segments == segments)
+      .map(|define| {
+        let mut value = define.value.clone();
+        // identifiers in the value are references to globals
+        value.visit_mut_with(&mut UnresolvedCtxtApplier {
+          ctxt: SyntaxContext::empty().apply_mark(self.unresolved_mark),
+        });
+        value
+      })
+  }
+}
+
+impl<'a> VisitMut for DefineReplacer<'a> {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    let replaced = match expr {
+      Expr::Unary(UnaryExpr {
+        op: UnaryOp::TypeOf,
+        arg,
+        ..
+      }) => self.find_define(arg, true),
+      _ => None,
+    }
+    .or_else(|| self.find_define(expr, false));
+
+    if let Some(replaced) = replaced {
+      *expr = *replaced;
+      self.replaced = true;
+    } else {
+      expr.visit_mut_children_with(self);
+    }
+  }
+
+  fn visit_mut_update_expr(&mut self, update_expr: &mut UpdateExpr) {
+    // `process.env.COUNT++` can not be replaced
+    if self.segments_of(&update_expr.arg).is_none() {
+      update_expr.arg.visit_mut_with(self);
+    }
+  }
+}
+
+struct UnresolvedCtxtApplier {
+  ctxt: SyntaxContext,
+}
+
+impl VisitMut for UnresolvedCtxtApplier {
+  fn visit_mut_ident(&mut self, ident: &mut Ident) {
+    if ident.span.ctxt == SyntaxContext::empty() {
+      ident.span = DUMMY_SP.with_ctxt(self.ctxt);
+    }
+  }
+}
@@ -1,13 +1,27 @@
 #![feature(path_file_prefix)]
 
+use std::sync::Arc;
+
+use define_replacer::{DefineExpr, DefineReplacer};
 use farmfe_core::{
   config::Config,
+  context::CompilationContext,
   parking_lot::RwLock,
-  plugin::Plugin,
+  plugin::{Plugin, PluginProcessModuleHookParam},
   regex::Regex,
-  serde_json::{self, Value},
+  serde_json,
+  swc_common::{BytePos, Mark, GLOBALS},
+  swc_ecma_ast::Expr,
+};
+use farmfe_toolkit::{
+  lazy_static::lazy_static,
+  swc_ecma_parser::{Parser, StringInput, Syntax},
+  swc_ecma_transforms::optimization::simplify::{dead_branch_remover, expr_simplifier},
+  swc_ecma_utils::DropSpan,
+  swc_ecma_visit::VisitMutWith,
 };
-use farmfe_toolkit::lazy_static::lazy_static;
+
+mod define_replacer;
 
 // Default supported static assets: png, jpg, jpeg, gif, svg, webp, mp4, webm, wav, mp3, wma, m4a, aac, ico, ttf, woff, woff2
 lazy_static! {
@@ -22,13 +36,16 @@ const REGEX_PREFIX: &str = "$__farm_regex:";
 
 pub struct FarmPluginDefine {
   /// Sort define by key len desc
-  sorted_define: RwLock<Vec<(String, Value)>>,
+  sorted_define: RwLock<Vec<(String, String)>>,
+  /// Defines that are replaced by ast in script modules
+  define_exprs: RwLock<Vec<DefineExpr>>,
 }
 
 impl FarmPluginDefine {
   pub fn new(_: &Config) -> Self {
     Self {
       sorted_define: RwLock::new(vec![]),
+      define_exprs: RwLock::new(vec![]),
     }
   }
 }
@@ -48,9 +65,33 @@ impl Plugin for FarmPluginDefine {
     sorted_define.sort_by_key(|b| std::cmp::Reverse(b.0.len()));
 
     let mut self_sorted_define = self.sorted_define.write();
+    let mut define_exprs = self.define_exprs.write();
+
+    for (key, value) in sorted_define {
+      let value = match value {
+        serde_json::Value::Null => "null".to_string(),
+        serde_json::Value::Bool(b) => (if b { "true" } else { "false" }).to_string(),
+        serde_json::Value::Number(num) => num.to_string(),
+        serde_json::Value::String(str) => str,
+        serde_json::Value::Array(arr) => serde_json::to_string(&arr).unwrap(),
+        serde_json::Value::Object(obj) => serde_json::to_string(&obj).unwrap(),
+      };
+
+      // keys like `process.env.NODE_ENV` are replaced by ast in script modules, fallback to text replacement if the value is not a valid expression
+      if !key.starts_with(REGEX_PREFIX) {
+        if let (Some((segments, is_typeof)), Some(value)) =
+          (DefineExpr::parse_key(&key), parse_define_value(&value))
+        {
+          define_exprs.push(DefineExpr {
+            key: key.clone(),
+            segments,
+            is_typeof,
+            value,
+          });
+        }
+      }
 
-    for item in sorted_define {
-      self_sorted_define.push(item);
+      self_sorted_define.push((key, value));
     }
 
     Ok(Some(()))
@@ -62,31 +103,29 @@ impl Plugin for FarmPluginDefine {
     _context: &std::sync::Arc<farmfe_core::context::CompilationContext>,
   ) -> farmfe_core::error::Result<Option<farmfe_core::plugin::PluginTransformHookResult>> {
     let define = self.sorted_define.read();
+    let define_exprs = self.define_exprs.read();
+    let is_script = param.module_type.is_script();
+    let define = define
+      .iter()
+      .filter(|(key, _)| !is_script || define_exprs.iter().all(|d| &d.key != key))
+      .collect::<Vec<_>>();
 
     if !define.is_empty() {
       let mut content = String::new();
 
-      for (key, value) in &*define {
-        let value = match value {
-          serde_json::Value::Null => "null".to_string(),
-          serde_json::Value::Bool(b) => (if *b { "true" } else { "false" }).to_string(),
-          serde_json::Value::Number(num) => num.to_string(),
-          serde_json::Value::String(str) => str.to_string(),
-          serde_json::Value::Array(arr) => serde_json::to_string(arr).unwrap(),
-          serde_json::Value::Object(obj) => serde_json::to_string(obj).unwrap(),
-        };
+      for (key, value) in define {
         if let Some(reg) = key.strip_prefix(REGEX_PREFIX) {
           let regex = Regex::new(reg).unwrap();
           if content.is_empty() {
-            content = regex.replace_all(&param.content, &value).to_string();
+            content = regex.replace_all(&param.content, value).to_string();
           } else {
-            content = regex.replace_all(&content, &value).to_string();
+            content = regex.replace_all(&content, value).to_string();
           }
         } else {
           if content.is_empty() {
-            content = param.content.replace(key, &value);
+            content = param.content.replace(key, value);
           } else {
-            content = content.replace(key, &value);
+            content = content.replace(key, value);
           }
         };
       }
@@ -100,4 +139,58 @@ impl Plugin for FarmPluginDefine {
 
     Ok(None)
   }
+
+  fn process_module(
+    &self,
+    param: &mut PluginProcessModuleHookParam,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<()>> {
+    let define_exprs = self.define_exprs.read();
+
+    if !param.module_type.is_script() || define_exprs.is_empty() {
+      return Ok(None);
+    }
+
+    let script = param.meta.as_script_mut();
+    let unresolved_mark = Mark::from_u32(script.unresolved_mark);
+
+    GLOBALS.set(&context.meta.script.globals, || {
+      let mut replacer = DefineReplacer::new(&define_exprs, unresolved_mark);
+      script.ast.visit_mut_with(&mut replacer);
+
+      if replacer.replaced {
+        // fold constants like `"production" !== "production"` and remove the dead branches,
+        // so that the modules only used in the dead branches can be dropped by the tree shaker
+        script
+          .ast
+          .visit_mut_with(&mut expr_simplifier(unresolved_mark, Default::default()));
+        script
+          .ast
+          .visit_mut_with(&mut dead_branch_remover(unresolved_mark));
+      }
+    });
+
+    Ok(None)
+  }
+}
+
+/// parse the define value as a expression, the spans are dropped as the value does not belong to any module
+fn parse_define_value(value: &str) -> Option<Box<Expr>> {
+  // wrap the value with parentheses to make sure the whole value is a single expression
+  let value = format!("({value})");
+  let input = StringInput::new(&value, BytePos::DUMMY, BytePos::DUMMY);
+  let mut parser = Parser::new(Syntax::Es(Default::default()), input, None);
+  let mut expr = match *parser.parse_expr().ok()? {
+    Expr::Paren(paren) => paren.expr,
+    _ => return None,
+  };
+
+  if !parser.take_errors().is_empty() {
+    return None;
+  }
+
+  expr.visit_mut_with(&mut DropSpan {
+    preserve_ctxt: false,
+  });
+  Some(expr)
 }
@@ -27,11 +27,12 @@ use farmfe_toolkit::common::{create_swc_source_map, PathFilter, Source};
 use farmfe_toolkit::minify::minify_html_module;
 use farmfe_toolkit::{
   fs::read_file_utf8,
-  get_dynamic_resources_map::get_dynamic_resources_map,
+  get_dynamic_resources_map::{get_dynamic_resources_map, get_resources_integrity},
   html::{codegen_html_document, parse_html_document},
   script::{module_type_from_id, swc_try_with::try_with},
 };
 use resources_injector::{ResourcesInjector, ResourcesInjectorOptions};
+use utils::get_css_url_assets;
 
 mod absolute_path_handler;
 mod deps_analyzer;
@@ -463,13 +464,37 @@ impl Plugin for FarmPluginTransformHtml {
         &params.resources_map,
       );
 
+      // resources of the dynamic imports of the html entry, they are likely to be loaded next
+      let mut prefetch_resources = vec![];
+
+      if context.config.html.prefetch {
+        for mg_id in module_group_graph.dependencies_ids(&module_group_id) {
+          for resource in dynamic_resources_map.get(&mg_id).into_iter().flatten() {
+            if !dep_resources.contains(&resource.0)
+              && !prefetch_resources
+                .iter()
+                .any(|(name, _)| name == &resource.0)
+            {
+              prefetch_resources.push(resource.clone());
+            }
+          }
+        }
+      }
+
       resources_to_inject.insert(
         html_entry_resource.unwrap(),
-        (dep_resources, dynamic_resources_map),
+        (dep_resources, dynamic_resources_map, prefetch_resources),
       );
     }
 
-    for (html_resource_name, (dep_resources, dynamic_resources_map)) in resources_to_inject {
+    // html resource name -> `sha256-` hashes of the injected scripts
+    let mut csp_hashes = std::collections::BTreeMap::new();
+    let resources_integrity =
+      get_resources_integrity(params.resources_map, context.config.output.sri);
+
+    for (html_resource_name, (dep_resources, dynamic_resources_map, prefetch_resources)) in
+      resources_to_inject
+    {
       let mut resource_pot_map = context.resource_pot_map.write();
       let mut script_resources: Vec<String> = vec![];
       let mut css_resources: Vec<String> = vec![];
@@ -484,9 +509,19 @@ impl Plugin for FarmPluginTransformHtml {
         }
       }
 
-      let html_resource = params.resources_map.get_mut(&html_resource_name).unwrap();
-
       let module_graph = context.module_graph.read();
+      let preload_assets = if context.config.html.preload {
+        get_css_url_assets(
+          &css_resources,
+          params.resources_map,
+          &resource_pot_map,
+          &module_graph,
+        )
+      } else {
+        vec![]
+      };
+
+      let html_resource = params.resources_map.get_mut(&html_resource_name).unwrap();
       let current_html_id = resource_pot_map
         .resource_pot(html_resource.origin.as_resource_pot())
         .unwrap()
@@ -519,6 +554,9 @@ impl Plugin for FarmPluginTransformHtml {
           namespace: context.config.runtime.namespace.clone(),
           current_html_id: current_html_id.clone(),
           context: context.clone(),
+          resources_integrity: resources_integrity.clone(),
+          preload_assets,
+          prefetch_resources,
         },
       );
 
@@ -540,6 +578,24 @@ impl Plugin for FarmPluginTransformHtml {
       html_resource.bytes = code.bytes().collect();
 
       resources_injector.update_resource(params.resources_map);
+      csp_hashes.insert(html_resource_name, resources_injector.csp_hashes().clone());
+    }
+
+    if let Some(csp) = &context.config.html.csp {
+      let bytes = farmfe_core::serde_json::to_vec_pretty(&csp_hashes)
+        .map_err(|e| CompilationError::GenericError(e.to_string()))?;
+
+      params.resources_map.insert(
+        csp.hashes_filename.clone(),
+        Resource {
+          name: csp.hashes_filename.clone(),
+          bytes,
+          emitted: false,
+          resource_type: ResourceType::Custom("json".to_string()),
+          origin: ResourceOrigin::ResourcePot(csp.hashes_filename.clone()),
+          info: None,
+        },
+      );
     }
 
     Ok(None)
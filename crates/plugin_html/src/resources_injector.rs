@@ -9,6 +9,7 @@ use farmfe_core::{
 };
 use farmfe_toolkit::{
   get_dynamic_resources_map::get_dynamic_resources_code,
+  hash::{integrity, sha256_base64},
   html::{create_element, get_farm_global_this},
   swc_html_visit::{VisitMut, VisitMutWith},
 };
@@ -24,6 +25,12 @@ pub struct ResourcesInjectorOptions {
   pub namespace: String,
   pub current_html_id: ModuleId,
   pub context: Arc<CompilationContext>,
+  /// resource name -> subresource integrity, empty if `output.sri` is not configured
+  pub resources_integrity: HashMap<String, String>,
+  /// fonts and images referenced by the initial css, preloaded if `html.preload` is true
+  pub preload_assets: Vec<String>,
+  /// resources of the dynamic imports of the html entry, prefetched if `html.prefetch` is true
+  pub prefetch_resources: Vec<(String, ResourceType)>,
 }
 
 /// inject resources into the html ast
@@ -36,6 +43,8 @@ pub struct ResourcesInjector {
   dynamic_resources_map: HashMap<ModuleId, Vec<(String, ResourceType)>>,
   options: ResourcesInjectorOptions,
   farm_global_this: String,
+  /// `sha256-` hashes of the injected scripts, only collected when `html.csp` is configured
+  csp_hashes: Vec<String>,
 }
 pub const FARM_RUNTIME_INJECT_RESOURCE: &str = "farm_runtime_resource";
 
@@ -58,6 +67,7 @@ impl ResourcesInjector {
       dynamic_resources_map,
       farm_global_this: get_farm_global_this(&options.namespace),
       options,
+      csp_hashes: vec![],
     }
   }
 
@@ -72,34 +82,172 @@ impl ResourcesInjector {
     }
   }
 
-  // Support isolate runtime resource (https://github.com/farm-fe/farm/issues/434)
-  fn inject_runtime_resources(&mut self, element: &mut Element) {
-    if get_config_runtime_isolate(&self.options.context) {
+  pub fn csp_hashes(&self) -> &Vec<String> {
+    &self.csp_hashes
+  }
+
+  fn is_csp_external(&self) -> bool {
+    matches!(&self.options.context.config.html.csp, Some(csp) if csp.external)
+  }
+
+  /// add the csp nonce placeholder to the attributes of the injected tag if configured
+  fn with_csp_nonce<'a>(&'a self, mut attrs: Vec<(&'a str, &'a str)>) -> Vec<(&'a str, &'a str)> {
+    if let Some(nonce) = self
+      .options
+      .context
+      .config
+      .html
+      .csp
+      .as_ref()
+      .and_then(|csp| csp.nonce.as_ref())
+    {
+      attrs.push(("nonce", nonce));
+    }
+
+    attrs
+  }
+
+  /// add `integrity` and `crossorigin` to the attributes of the injected tag if `output.sri` is configured
+  fn with_integrity<'a>(
+    &'a self,
+    integrity: Option<&'a String>,
+    mut attrs: Vec<(&'a str, &'a str)>,
+  ) -> Vec<(&'a str, &'a str)> {
+    if let Some(integrity) = integrity {
+      attrs.push(("integrity", integrity));
+      attrs.push(("crossorigin", "anonymous"));
+    }
+
+    attrs
+  }
+
+  /// inject `<link rel="preload">` for the initial scripts and css assets, and `<link rel="prefetch">` for the dynamic resources.
+  /// The scripts are preloaded with `as="script"` instead of `modulepreload` as they are classic scripts executed by the farm runtime
+  fn inject_resource_hints(&self, element: &mut Element) {
+    let mut hints = vec![];
+
+    if self.options.context.config.html.preload {
+      for script in &self.script_resources {
+        hints.push(("preload", script, "script", None));
+      }
+
+      for asset in &self.options.preload_assets {
+        let ext = asset.rsplit('.').next().unwrap_or_default();
+
+        match ext {
+          "woff2" | "woff" | "ttf" | "otf" => {
+            hints.push(("preload", asset, "font", Some(format!("font/{ext}"))))
+          }
+          "png" | "jpg" | "jpeg" | "gif" | "svg" | "webp" | "avif" | "ico" | "bmp" => {
+            hints.push(("preload", asset, "image", None))
+          }
+          _ => {}
+        }
+      }
+    }
+
+    for (resource, resource_type) in &self.options.prefetch_resources {
+      let as_value = if matches!(resource_type, ResourceType::Css) {
+        "style"
+      } else {
+        "script"
+      };
+      hints.push(("prefetch", resource, as_value, None));
+    }
+
+    for (rel, name, as_value, font_type) in hints {
+      let href = format!("{}{}", self.options.public_path, name);
+      let attrs = vec![("rel", rel), ("href", href.as_str()), ("as", as_value)];
+      // only js and css resources have integrity, fonts are always fetched in cors mode
+      let mut attrs = self.with_integrity(self.options.resources_integrity.get(name), attrs);
+
+      if let Some(font_type) = &font_type {
+        attrs.push(("type", font_type));
+        attrs.push(("crossorigin", "anonymous"));
+      }
+
+      element.children.push(Child::Element(create_element(
+        "link",
+        None,
+        self.with_csp_nonce(attrs),
+      )));
+    }
+  }
+
+  fn record_csp_hash(&mut self, bytes: &[u8]) -> String {
+    let hash = format!("sha256-{}", sha256_base64(bytes));
+
+    if self.options.context.config.html.csp.is_some() && !self.csp_hashes.contains(&hash) {
+      self.csp_hashes.push(hash.clone());
+    }
+
+    hash
+  }
+
+  /// inject the code as a `<script>`, it's emitted as a hashed external file named by `resource_name` if `external` is true
+  fn inject_script(
+    &mut self,
+    element: &mut Element,
+    code: String,
+    resource_name: &str,
+    external: bool,
+  ) {
+    if external {
       let resource = create_farm_runtime_output_resource(
-        self.runtime_code.clone().into_bytes(),
-        FARM_RUNTIME_INJECT_RESOURCE,
+        code.into_bytes(),
+        resource_name,
         &self.options.context,
       );
-      let script_element = create_element(
+      let hash = self.record_csp_hash(&resource.bytes);
+      // csp hash sources only match external scripts that carry the same integrity
+      let integrity = [
+        self.options.context.config.html.csp.as_ref().map(|_| hash),
+        self
+          .options
+          .context
+          .config
+          .output
+          .sri
+          .map(|algorithm| integrity(&resource.bytes, algorithm)),
+      ]
+      .into_iter()
+      .flatten()
+      .collect::<Vec<_>>()
+      .join(" ");
+      let src = format!("/{}", resource.name);
+      let attrs = self.with_integrity(
+        (!integrity.is_empty()).then_some(&integrity),
+        vec![(FARM_ENTRY, "true"), ("src", src.as_str())],
+      );
+
+      element.children.push(Child::Element(create_element(
         "script",
         None,
-        vec![
-          (FARM_ENTRY, "true"),
-          ("src", &format!("/{}", resource.name)),
-        ],
-      );
-      element.children.push(Child::Element(script_element));
-      self.additional_inject_resources.push(resource.clone());
+        self.with_csp_nonce(attrs),
+      )));
+      self.additional_inject_resources.push(resource);
     } else {
+      self.record_csp_hash(code.as_bytes());
       element.children.push(Child::Element(create_element(
         "script",
-        Some(&self.runtime_code),
-        vec![(FARM_ENTRY, "true")],
+        Some(&code),
+        self.with_csp_nonce(vec![(FARM_ENTRY, "true")]),
       )));
     }
   }
 
-  fn inject_initial_loaded_resources(&self, element: &mut Element) {
+  // Support isolate runtime resource (https://github.com/farm-fe/farm/issues/434)
+  fn inject_runtime_resources(&mut self, element: &mut Element) {
+    let external = get_config_runtime_isolate(&self.options.context) || self.is_csp_external();
+    self.inject_script(
+      element,
+      self.runtime_code.clone(),
+      FARM_RUNTIME_INJECT_RESOURCE,
+      external,
+    );
+  }
+
+  fn inject_initial_loaded_resources(&mut self, element: &mut Element) {
     let mut initial_resources = vec![];
     initial_resources.extend(self.script_resources.clone());
     initial_resources.extend(self.css_resources.clone());
@@ -111,50 +259,30 @@ impl ResourcesInjector {
       .collect::<Vec<_>>()
       .join(",");
 
-    element.children.push(Child::Element(create_element(
-      "script",
-      Some(&format!(
-        r#"{}.{}.setInitialLoadedResources([{}]);"#,
-        self.farm_global_this, FARM_MODULE_SYSTEM, initial_resources_code
-      )),
-      vec![(FARM_ENTRY, "true")],
-    )));
+    let code = format!(
+      r#"{}.{}.setInitialLoadedResources([{}]);"#,
+      self.farm_global_this, FARM_MODULE_SYSTEM, initial_resources_code
+    );
+    let external = self.is_csp_external();
+    self.inject_script(element, code, "initial_loaded_resources", external);
   }
 
   fn inject_dynamic_resources_map(&mut self, element: &mut Element) {
-    let dynamic_resources_code =
-      get_dynamic_resources_code(&self.dynamic_resources_map, self.options.mode.clone());
+    let dynamic_resources_code = get_dynamic_resources_code(
+      &self.dynamic_resources_map,
+      self.options.mode.clone(),
+      &self.options.resources_integrity,
+    );
 
     let finalize_code = format!(
       r#"{}.{}.setDynamicModuleResourcesMap({});"#,
       self.farm_global_this, FARM_MODULE_SYSTEM, dynamic_resources_code
     );
-
-    if get_config_runtime_isolate(&self.options.context) {
-      let resource = create_farm_runtime_output_resource(
-        finalize_code.into_bytes(),
-        "dynamic_resources_map",
-        &self.options.context,
-      );
-      element.children.push(Child::Element(create_element(
-        "script",
-        None,
-        vec![
-          ("FARM_ENTRY", "true"),
-          ("src", &format!("/{}", resource.name)),
-        ],
-      )));
-      self.additional_inject_resources.push(resource);
-    } else {
-      element.children.push(Child::Element(create_element(
-        "script",
-        Some(&finalize_code),
-        vec![(FARM_ENTRY, "true")],
-      )));
-    }
+    let external = get_config_runtime_isolate(&self.options.context) || self.is_csp_external();
+    self.inject_script(element, finalize_code, "dynamic_resources_map", external);
   }
 
-  fn inject_global_this(&self, element: &mut Element) {
+  fn inject_global_this(&mut self, element: &mut Element) {
     let code = format!(
       r#"
 {FARM_GLOBAL_THIS} = {{}};
@@ -163,42 +291,41 @@ impl ResourcesInjector {
 }};"#,
       FARM_GLOBAL_THIS = self.farm_global_this,
     );
-
-    element.children.push(Child::Element(create_element(
-      "script",
-      Some(&code),
-      vec![(FARM_ENTRY, "true")],
-    )));
+    let external = self.is_csp_external();
+    self.inject_script(element, code, "global_this", external);
   }
 
-  fn inject_other_entry_file(&self, element: &mut Element) {
-    element.children.push(Child::Element(create_element(
-      "script",
-      Some(&format!(
+  fn inject_other_entry_file(&mut self, element: &mut Element) {
+    self.inject_script(
+      element,
+      format!(
         r#"{}.{}.setPublicPaths(['{}']);"#,
         self.farm_global_this, FARM_MODULE_SYSTEM, self.options.public_path
-      )),
-      vec![(FARM_ENTRY, "true")],
-    )));
+      ),
+      "public_paths",
+      false,
+    );
 
-    element.children.push(Child::Element(create_element(
-      "script",
-      Some(&format!(
+    self.inject_script(
+      element,
+      format!(
         r#"{}.{}.bootstrap();"#,
         self.farm_global_this, FARM_MODULE_SYSTEM
-      )),
-      vec![(FARM_ENTRY, "true")],
-    )));
+      ),
+      "bootstrap",
+      false,
+    );
 
-    for entry in &self.script_entries {
-      element.children.push(Child::Element(create_element(
-        "script",
-        Some(&format!(
+    for entry in self.script_entries.clone() {
+      self.inject_script(
+        element,
+        format!(
           r#"{}.{}.require("{}")"#,
           self.farm_global_this, FARM_MODULE_SYSTEM, entry
-        )),
-        vec![(FARM_ENTRY, "true")],
-      )));
+        ),
+        "require_entry",
+        false,
+      );
     }
   }
 
@@ -218,22 +345,8 @@ impl ResourcesInjector {
         self.farm_global_this, FARM_MODULE_SYSTEM, entry
       ))
     }
-    // create resource
-    let resource = create_farm_runtime_output_resource(
-      finalize_code.into_bytes(),
-      "farm_module_system",
-      &self.options.context,
-    );
-    // inject script
-    element.children.push(Child::Element(create_element(
-      "script",
-      None,
-      vec![
-        (FARM_ENTRY, "true"),
-        ("src", &format!("/{}", resource.name)),
-      ],
-    )));
-    self.additional_inject_resources.push(resource);
+
+    self.inject_script(element, finalize_code, "farm_module_system", true);
   }
 }
 
@@ -267,15 +380,20 @@ impl VisitMut for ResourcesInjector {
     }
 
     if element.tag_name.to_string() == "head" {
+      // inject resource hints <link>
+      self.inject_resource_hints(element);
+
       // inject css <link>
       for css in &self.css_resources {
+        let href = format!("{}{}", self.options.public_path, css);
+        let attrs = self.with_integrity(
+          self.options.resources_integrity.get(css),
+          vec![("rel", "stylesheet"), ("href", &href)],
+        );
         element.children.push(Child::Element(create_element(
           "link",
           None,
-          vec![
-            ("rel", "stylesheet"),
-            ("href", &format!("{}{}", self.options.public_path, css)),
-          ],
+          self.with_csp_nonce(attrs),
         )));
       }
 
@@ -286,20 +404,22 @@ impl VisitMut for ResourcesInjector {
       self.inject_runtime_resources(element);
     } else if element.tag_name.to_string() == "body" {
       for script in &self.script_resources {
+        let src = format!("{}{}", self.options.public_path, script);
+        let attrs = self.with_integrity(
+          self.options.resources_integrity.get(script),
+          vec![("src", &src), (FARM_RESOURCE, "true")],
+        );
         element.children.push(Child::Element(create_element(
           "script",
           None,
-          vec![
-            ("src", &format!("{}{}", self.options.public_path, script)),
-            (FARM_RESOURCE, "true"),
-          ],
+          self.with_csp_nonce(attrs),
         )));
       }
 
       self.inject_initial_loaded_resources(element);
       self.inject_dynamic_resources_map(element);
 
-      if get_config_runtime_isolate(&self.options.context) {
+      if get_config_runtime_isolate(&self.options.context) || self.is_csp_external() {
         self.inject_resource_separate_file(element);
       } else {
         self.inject_other_entry_file(element);
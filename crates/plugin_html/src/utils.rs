@@ -1,10 +1,10 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use farmfe_core::{
-  config::ModuleFormat,
   context::CompilationContext,
-  module::ModuleId,
-  resource::{Resource, ResourceOrigin, ResourceType},
+  module::{module_graph::ModuleGraph, ModuleId},
+  plugin::ResolveKind,
+  resource::{resource_pot_map::ResourcePotMap, Resource, ResourceOrigin, ResourceType},
   swc_html_ast::Element,
 };
 use farmfe_toolkit::fs::transform_output_entry_filename;
@@ -92,10 +92,7 @@ pub fn create_farm_runtime_output_resource(
     resource_name,
     resource_name,
     &bytes,
-    match context.config.output.format {
-      ModuleFormat::EsModule => "mjs",
-      ModuleFormat::CommonJs => "cjs",
-    },
+    context.config.output.format.entry_ext(),
   );
   Resource {
     name: name.clone(),
@@ -106,3 +103,42 @@ pub fn create_farm_runtime_output_resource(
     info: None,
   }
 }
+
+/// Get the asset resources that are referenced by `url()` of the css resources, sorted by name.
+pub fn get_css_url_assets(
+  css_resources: &[String],
+  resources_map: &HashMap<String, Resource>,
+  resource_pot_map: &ResourcePotMap,
+  module_graph: &ModuleGraph,
+) -> Vec<String> {
+  let mut assets = vec![];
+
+  for css in css_resources {
+    let Some(rp) = resources_map
+      .get(css)
+      .and_then(|r| resource_pot_map.resource_pot(r.origin.as_resource_pot()))
+    else {
+      continue;
+    };
+
+    for module_id in rp.modules() {
+      for (dep, edge) in module_graph.dependencies(module_id) {
+        if !edge.iter().any(|item| item.kind == ResolveKind::CssUrl) {
+          continue;
+        }
+
+        for resource in resources_map.values() {
+          if matches!(&resource.origin, ResourceOrigin::Module(m) if m == &dep)
+            && matches!(resource.resource_type, ResourceType::Asset(_))
+            && !assets.contains(&resource.name)
+          {
+            assets.push(resource.name.clone());
+          }
+        }
+      }
+    }
+  }
+
+  assets.sort();
+  assets
+}
@@ -3,7 +3,8 @@ use farmfe_core::{
   error::CompilationError,
   module::ModuleType,
   plugin::{Plugin, PluginLoadHookResult},
-  serde_json,
+  serde_json::{self, Value},
+  swc_ecma_ast::Ident,
 };
 use farmfe_toolkit::fs;
 
@@ -23,6 +24,29 @@ fn match_json_file(file_name: &str) -> bool {
   file_name.ends_with(".json")
 }
 
+/// Transform json to esm. Top-level keys that are valid identifiers are exported as named exports,
+/// so that the unused keys can be removed by tree shaking. The default export references the named exports.
+fn json_to_esm(json: &Value) -> String {
+  let Value::Object(object) = json else {
+    return format!("export default {json};");
+  };
+
+  let mut named_exports = vec![];
+  let mut default_props = vec![];
+
+  for (key, value) in object {
+    if Ident::verify_symbol(key).is_ok() {
+      named_exports.push(format!("export const {key} = {value};"));
+      default_props.push(key.to_string());
+    } else {
+      default_props.push(format!("{}: {value}", Value::String(key.to_string())));
+    }
+  }
+
+  named_exports.push(format!("export default {{{}}};", default_props.join(",")));
+  named_exports.join("\n")
+}
+
 impl Plugin for FarmPluginJson {
   fn name(&self) -> &str {
     "FarmPluginJson"
@@ -48,12 +72,22 @@ impl Plugin for FarmPluginJson {
   fn transform(
     &self,
     param: &farmfe_core::plugin::PluginTransformHookParam,
-    _context: &std::sync::Arc<farmfe_core::context::CompilationContext>,
+    context: &std::sync::Arc<farmfe_core::context::CompilationContext>,
   ) -> farmfe_core::error::Result<Option<farmfe_core::plugin::PluginTransformHookResult>> {
     if matches!(param.module_type, ModuleType::Custom(ref suffix) if suffix == "json") {
       // if json value can not be parsed, means it's handled by other plugins
-      if let Ok(json) = serde_json::from_str::<serde_json::Value>(&param.content) {
-        let js = format!("module.exports = {}", json);
+      if let Ok(json) = serde_json::from_str::<Value>(&param.content) {
+        let json_config = &context.config.json;
+        let js = if json_config.stringify && param.content.len() >= json_config.stringify_threshold
+        {
+          // JSON.parse is faster than evaluating a large object literal
+          format!(
+            "export default JSON.parse({});",
+            Value::String(json.to_string())
+          )
+        } else {
+          json_to_esm(&json)
+        };
 
         return Ok(Some(farmfe_core::plugin::PluginTransformHookResult {
           content: js,
@@ -11,7 +11,13 @@ use farmfe_testing_helpers::fixture;
 fn generate_transform_fn(
   file: &Path,
 ) -> farmfe_core::error::Result<Option<farmfe_core::plugin::PluginTransformHookResult>> {
-  let config = Config::default();
+  generate_transform_fn_with_config(file, Config::default())
+}
+
+fn generate_transform_fn_with_config(
+  file: &Path,
+  config: Config,
+) -> farmfe_core::error::Result<Option<farmfe_core::plugin::PluginTransformHookResult>> {
   let json_plugin = farmfe_plugin_json::FarmPluginJson::new(&config);
   let id = file.to_string_lossy().to_string();
 
@@ -51,10 +57,34 @@ fn transform_json() {
   fixture!("tests/fixtures/transform/transform.json", |file, _| {
     let result = generate_transform_fn(&file).unwrap().unwrap();
 
-    assert!(result.content.starts_with("module.exports ="));
+    assert_eq!(
+      result.content,
+      r#"export const hello = "word";
+export const person = {"name":"shulan","age":18,"address":"earth","phone":1234567890,"hobby":["coding","reading","swimming"]};
+export default {hello,person};"#
+    );
     assert_eq!(result.module_type.unwrap(), ModuleType::Js);
   });
 
+  fixture!(
+    "tests/fixtures/transform/invalid_identifier.json",
+    |file, _| {
+      let result = generate_transform_fn(&file).unwrap().unwrap();
+
+      assert_eq!(
+        result.content,
+        r#"export const valid_$ = true;
+export default {"default":1,"foo-bar":[1,2],valid_$};"#
+      );
+    }
+  );
+
+  fixture!("tests/fixtures/transform/array.json", |file, _| {
+    let result = generate_transform_fn(&file).unwrap().unwrap();
+
+    assert_eq!(result.content, "export default [1,\"2\",{\"a\":3}];");
+  });
+
   fixture!(
     "tests/fixtures/transform/contain_comment.json",
     |file, _| {
@@ -64,3 +94,28 @@ fn transform_json() {
     }
   );
 }
+
+#[test]
+fn transform_json_stringify() {
+  fixture!("tests/fixtures/transform/transform.json", |file, _| {
+    let mut config = Config::default();
+    config.json.stringify = true;
+    config.json.stringify_threshold = 0;
+    let result = generate_transform_fn_with_config(&file, config.clone())
+      .unwrap()
+      .unwrap();
+
+    assert!(result
+      .content
+      .starts_with(r#"export default JSON.parse("{\"hello\":\"word\","#));
+    assert!(!result.content.contains("export const"));
+
+    // smaller than the threshold
+    config.json.stringify_threshold = 1024;
+    let result = generate_transform_fn_with_config(&file, config)
+      .unwrap()
+      .unwrap();
+
+    assert!(result.content.starts_with("export const hello"));
+  });
+}
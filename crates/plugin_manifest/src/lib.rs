@@ -0,0 +1,160 @@
+#![deny(clippy::all)]
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use farmfe_core::{
+  config::Config,
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  module::{module_graph::ModuleGraph, module_group::ModuleGroup},
+  plugin::{Plugin, PluginFinalizeResourcesHookParams},
+  resource::{resource_pot_map::ResourcePotMap, Resource, ResourceOrigin, ResourceType},
+  serde::Serialize,
+  serde_json,
+};
+
+/// One item of the manifest, describes the resources that a module group(an entry or a dynamic import) needs
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase", crate = "farmfe_core::serde")]
+pub struct ManifestItem {
+  /// the module id of the entry module of this module group
+  pub module_id: String,
+  pub is_entry: bool,
+  pub is_dynamic_entry: bool,
+  /// html resource of the html entry
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub html: Option<String>,
+  /// js resources, in execution order
+  pub js: Vec<String>,
+  /// css resources, in execution order
+  pub css: Vec<String>,
+  /// keys of the dynamic imported module groups in this manifest
+  pub dynamic_imports: Vec<String>,
+}
+
+/// Emit a json resource that maps every entry and dynamic import to the resources it needs.
+/// Entries are keyed by the entry name of `config.input` and dynamic imports are keyed by module id.
+pub struct FarmPluginManifest {
+  filename: String,
+}
+
+impl FarmPluginManifest {
+  pub fn new(config: &Config) -> Self {
+    Self {
+      filename: config.output.manifest.clone().unwrap_or_default().filename,
+    }
+  }
+}
+
+impl Plugin for FarmPluginManifest {
+  fn name(&self) -> &str {
+    "FarmPluginManifest"
+  }
+
+  /// Make sure the manifest is generated after all resources are finalized
+  fn priority(&self) -> i32 {
+    -99
+  }
+
+  fn finalize_resources(
+    &self,
+    params: &mut PluginFinalizeResourcesHookParams,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    let module_graph = context.module_graph.read();
+    let module_group_graph = context.module_group_graph.read();
+    let resource_pot_map = context.resource_pot_map.read();
+
+    let key_of = |module_group: &ModuleGroup| {
+      module_graph
+        .entries
+        .get(&module_group.id)
+        .cloned()
+        .unwrap_or_else(|| module_group.id.to_string())
+    };
+
+    let mut manifest = BTreeMap::new();
+
+    for module_group in module_group_graph.module_groups() {
+      let is_entry = module_graph.entries.contains_key(&module_group.id);
+      let mut item = ManifestItem {
+        module_id: module_group.id.to_string(),
+        is_entry,
+        is_dynamic_entry: !is_entry,
+        ..Default::default()
+      };
+
+      for resource_name in sorted_resources(module_group, &module_graph, &resource_pot_map) {
+        let Some(resource) = params.resources_map.get(&resource_name) else {
+          continue;
+        };
+
+        match resource.resource_type {
+          ResourceType::Js | ResourceType::Runtime => item.js.push(resource_name),
+          ResourceType::Css => item.css.push(resource_name),
+          ResourceType::Html => item.html = Some(resource_name),
+          _ => {}
+        }
+      }
+
+      let mut dynamic_imports = module_group_graph
+        .dependencies(&module_group.id)
+        .into_iter()
+        .map(key_of)
+        .collect::<Vec<_>>();
+      dynamic_imports.sort();
+      item.dynamic_imports = dynamic_imports;
+
+      manifest.insert(key_of(module_group), item);
+    }
+
+    let bytes = serde_json::to_vec_pretty(&manifest).map_err(|e| {
+      CompilationError::GenericError(format!("Failed to serialize manifest: {:?}", e))
+    })?;
+
+    params.resources_map.insert(
+      self.filename.clone(),
+      Resource {
+        name: self.filename.clone(),
+        bytes,
+        emitted: false,
+        resource_type: ResourceType::Custom("json".to_string()),
+        origin: ResourceOrigin::ResourcePot(self.filename.clone()),
+        info: None,
+      },
+    );
+
+    Ok(Some(()))
+  }
+}
+
+/// resources of the module group, sorted by the minimal execution order of the modules in its resource pot
+fn sorted_resources(
+  module_group: &ModuleGroup,
+  module_graph: &ModuleGraph,
+  resource_pot_map: &ResourcePotMap,
+) -> Vec<String> {
+  let mut resource_pots = module_group
+    .resource_pots()
+    .iter()
+    .filter_map(|id| resource_pot_map.resource_pot(id))
+    .map(|rp| {
+      let order = rp
+        .modules()
+        .iter()
+        .filter_map(|m| module_graph.module(m))
+        .map(|m| m.execution_order)
+        .min()
+        .unwrap_or(0);
+
+      (order, rp)
+    })
+    .collect::<Vec<_>>();
+  resource_pots.sort_by(|(a_order, a), (b_order, b)| a_order.cmp(b_order).then(a.id.cmp(&b.id)));
+
+  resource_pots
+    .into_iter()
+    .flat_map(|(_, rp)| rp.resources())
+    .cloned()
+    .collect()
+}
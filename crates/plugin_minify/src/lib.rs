@@ -102,7 +102,7 @@ impl FarmPluginMinify {
 
       let minified_content = codegen_module(
         &ast,
-        context.config.script.target.clone(),
+        context.config.script.target(),
         cm.clone(),
         if sourcemap_enabled {
           Some(&mut src_map)
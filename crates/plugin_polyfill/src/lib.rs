@@ -29,6 +29,12 @@ pub struct FarmPluginPolyfill {
 
 impl FarmPluginPolyfill {
   pub fn new(config: &Config) -> Self {
+    // fallback to `ie >= 9` when no targets are configured
+    let default_targets = config
+      .targets
+      .clone()
+      .unwrap_or_else(|| Targets::Query(Query::Single("ie >= 9".to_string())));
+
     let (config, include, exclude, assumptions) = match &*config.preset_env {
       PresetEnvConfig::Bool(_) => {
         let PresetEnvConfigObj {
@@ -41,7 +47,7 @@ impl FarmPluginPolyfill {
         (
           swc_ecma_preset_env::Config {
             mode: Some(Mode::Usage),
-            targets: Some(Targets::Query(Query::Single("ie >= 9".to_string()))),
+            targets: Some(default_targets),
             ..Default::default()
           },
           include,
@@ -54,9 +60,7 @@ impl FarmPluginPolyfill {
         let mut user_config: swc_ecma_preset_env::Config =
           serde_json::from_value(*options.clone()).unwrap();
         user_config.mode = user_config.mode.or(Some(Mode::Usage));
-        user_config.targets = user_config
-          .targets
-          .or(Some(Targets::Query(Query::Single("ie >= 9".to_string()))));
+        user_config.targets = user_config.targets.or(Some(default_targets));
         let user_assumption: Assumptions =
           serde_json::from_value(*obj.assumptions.clone()).unwrap();
         (
@@ -5,8 +5,10 @@ use farmfe_core::{
   context::CompilationContext,
   error::Result,
   farm_profile_function, farm_profile_scope,
+  module::ModuleId,
   plugin::{
-    Plugin, PluginHookContext, PluginResolveHookParam, PluginResolveHookResult, ResolveKind,
+    Plugin, PluginFinalizeModuleHookParam, PluginHookContext, PluginResolveHookParam,
+    PluginResolveHookResult, PluginUpdateModulesHookParams, ResolveKind, UpdateType,
   },
 };
 
@@ -109,4 +111,59 @@ impl Plugin for FarmPluginResolve {
 
     Ok(resolve_result)
   }
+
+  fn finalize_module(
+    &self,
+    param: &mut PluginFinalizeModuleHookParam,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    // bare sources may be resolved by tsconfig paths, watch the tsconfig files so the module is updated when they change
+    let has_bare_dep = param
+      .deps
+      .iter()
+      .any(|dep| !dep.source.starts_with('.') && !Path::new(&dep.source).is_absolute());
+
+    if !has_bare_dep {
+      return Ok(None);
+    }
+
+    let resolved_path = param.module.id.resolved_path(&context.config.root);
+    let Some(dir) = Path::new(&resolved_path).parent() else {
+      return Ok(None);
+    };
+
+    if let Some(tsconfig) = self.resolver.load_tsconfig(dir.to_path_buf(), context) {
+      if tsconfig.has_resolve_options() {
+        context.add_watch_files(
+          param.module.id.clone(),
+          tsconfig
+            .files
+            .iter()
+            .map(|file| ModuleId::new(file, "", &context.config.root))
+            .collect(),
+        )?;
+      }
+    }
+
+    Ok(None)
+  }
+
+  fn update_modules(
+    &self,
+    params: &mut PluginUpdateModulesHookParams,
+    context: &Arc<CompilationContext>,
+  ) -> Result<Option<()>> {
+    // the resolved results may be changed when tsconfig changes or files are added or removed
+    let files_changed = params
+      .paths
+      .iter()
+      .any(|(_, ty)| !matches!(ty, UpdateType::Updated));
+
+    if self.resolver.tsconfig_loader().invalidate_if_changed() || files_changed {
+      self.resolver.clear_cache();
+      context.resolve_cache.lock().clear();
+    }
+
+    Ok(None)
+  }
 }
@@ -10,6 +10,7 @@ use farmfe_core::{
   common::PackageJsonInfo,
   config::TargetEnv,
   context::CompilationContext,
+  error::diagnostic::Diagnostic,
   farm_profile_function,
   parking_lot::Mutex,
   plugin::{PluginResolveHookResult, ResolveKind},
@@ -17,7 +18,11 @@ use farmfe_core::{
   serde_json::{from_str, Map, Value},
 };
 
-use farmfe_toolkit::resolve::{follow_symlinks, load_package_json, package_json_loader::Options};
+use farmfe_toolkit::resolve::{
+  follow_symlinks, load_package_json,
+  package_json_loader::Options,
+  tsconfig_loader::{TsConfigInfo, TsConfigLoader},
+};
 
 use crate::resolver::browser::try_browser_map;
 use crate::resolver::exports::resolve_exports_or_imports;
@@ -44,6 +49,8 @@ pub struct ResolveCacheKey {
 pub struct Resolver {
   /// the key is (source, base_dir) and the value is the resolved result
   resolve_cache: Mutex<HashMap<ResolveCacheKey, Option<PluginResolveHookResult>>>,
+  /// closest tsconfig of the importer, used to resolve `compilerOptions.paths` and `compilerOptions.baseUrl`
+  tsconfig_loader: TsConfigLoader,
 }
 
 const NODE_MODULES: &str = "node_modules";
@@ -53,9 +60,41 @@ impl Resolver {
   pub fn new() -> Self {
     Self {
       resolve_cache: Mutex::new(HashMap::new()),
+      tsconfig_loader: TsConfigLoader::new(),
+    }
+  }
+
+  pub fn tsconfig_loader(&self) -> &TsConfigLoader {
+    &self.tsconfig_loader
+  }
+
+  /// Load the closest tsconfig of base_dir, None if there is no tsconfig or base_dir is in node_modules.
+  /// A tsconfig that fails to load is reported as a warning once and ignored
+  pub fn load_tsconfig(
+    &self,
+    base_dir: PathBuf,
+    context: &Arc<CompilationContext>,
+  ) -> Option<Arc<TsConfigInfo>> {
+    if base_dir.components().any(|c| c.as_os_str() == NODE_MODULES) {
+      return None;
+    }
+
+    match self.tsconfig_loader.load(base_dir) {
+      Ok(tsconfig) => tsconfig,
+      Err(e) => {
+        context
+          .log_store
+          .lock()
+          .add_warning(Diagnostic::warning(e.to_string()));
+        None
+      }
     }
   }
 
+  pub fn clear_cache(&self) {
+    self.resolve_cache.lock().clear();
+  }
+
   pub fn resolve(
     &self,
     source: &str,
@@ -92,6 +131,7 @@ impl Resolver {
   /// * **Relative Path**: './xxx' or '../xxx'
   /// * **Absolute Path**: '/root/xxx' or 'c:\\root\\xxx'
   /// * **Configured Alias**: '@/pages/xxx'
+  /// * **Tsconfig Paths**: `compilerOptions.paths` and `compilerOptions.baseUrl` of the closest tsconfig.json
   /// * **Package**:
   ///   * **exports**: refer to [exports](https://nodejs.org/api/packages.html#packages_conditional_exports), if source is end with '.js', also try to find '.ts' file
   ///   * **browser**: refer to [package-browser-field-spec](https://github.com/defunctzombie/package-browser-field-spec)
@@ -118,6 +158,7 @@ impl Resolver {
     self
       .try_alias(source, base_dir.clone(), kind, context)
       .or_else(|| self.try_relative_or_absolute_path(source, base_dir.clone(), kind, context))
+      .or_else(|| self.try_tsconfig_paths(source, base_dir.clone(), kind, context))
       .or_else(|| {
         self.try_browser(
           BrowserMapType::Source(source.to_string()),
@@ -426,6 +467,63 @@ impl Resolver {
     None
   }
 
+  /// Resolve bare source by `paths` of the closest tsconfig, then try `baseUrl`.
+  /// If multiple patterns match, the one with the longest prefix before `*` wins like typescript does.
+  fn try_tsconfig_paths(
+    &self,
+    source: &str,
+    base_dir: PathBuf,
+    kind: &ResolveKind,
+    context: &Arc<CompilationContext>,
+  ) -> Option<PluginResolveHookResult> {
+    if is_source_relative(source) || is_source_absolute(source) {
+      return None;
+    }
+
+    farm_profile_function!("try_tsconfig_paths".to_string());
+    let tsconfig = self.load_tsconfig(base_dir.clone(), context)?;
+    let try_path = |path: PathBuf| {
+      self.try_relative_or_absolute_path(&path.to_string_lossy(), base_dir.clone(), kind, context)
+    };
+
+    let matched = tsconfig
+      .paths
+      .iter()
+      .filter_map(|(pattern, targets)| match pattern.split_once('*') {
+        Some((prefix, suffix)) => {
+          if source.len() >= prefix.len() + suffix.len()
+            && source.starts_with(prefix)
+            && source.ends_with(suffix)
+          {
+            let star = &source[prefix.len()..source.len() - suffix.len()];
+            Some((prefix.len(), star, targets))
+          } else {
+            None
+          }
+        }
+        // exact match is preferred
+        None if pattern == source => Some((usize::MAX, "", targets)),
+        None => None,
+      })
+      .max_by_key(|(prefix_len, ..)| *prefix_len);
+
+    if let Some((_, star, targets)) = matched {
+      let base = Path::new(&tsconfig.paths_base_dir);
+      let result = targets.iter().find_map(|target| {
+        try_path(RelativePath::new(&target.replacen('*', star, 1)).to_logical_path(base))
+      });
+
+      if result.is_some() {
+        return result;
+      }
+    }
+
+    tsconfig
+      .base_url
+      .as_ref()
+      .and_then(|base_url| try_path(RelativePath::new(source).to_logical_path(base_url)))
+  }
+
   fn try_node_modules(
     &self,
     source: &str,
@@ -714,9 +812,6 @@ impl Resolver {
     resolved_path: &str,
   ) -> bool {
     farm_profile_function!("is_module_side_effects".to_string());
-    match package_json_info.side_effects() {
-      farmfe_core::common::ParsedSideEffects::Bool(b) => *b,
-      farmfe_core::common::ParsedSideEffects::Array(arr) => arr.iter().any(|s| s == resolved_path),
-    }
+    package_json_info.has_side_effects(resolved_path)
   }
 }
@@ -0,0 +1 @@
+import { button } from '@/components/button';
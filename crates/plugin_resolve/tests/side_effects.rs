@@ -84,3 +84,24 @@ fn resolve_side_effects_bool() {
     assert_eq!(resolved.side_effects, true);
   });
 }
+
+#[test]
+fn resolve_side_effects_glob() {
+  fixture!("tests/fixtures/side_effects/index.ts", |file, _| {
+    let resolver = Resolver::new();
+    let cwd = file.parent().unwrap().to_path_buf();
+    let context = Arc::new(CompilationContext::default());
+    let side_effects = |source: &str| {
+      resolver
+        .resolve(source, cwd.clone(), &ResolveKind::Import, &context)
+        .unwrap()
+        .side_effects
+    };
+
+    // `*.css` matches css files in nested directories
+    assert!(side_effects("array/styles/nested.css"));
+    // `./src/some-side-effectful-file.js`
+    assert!(side_effects("array/src/some-side-effectful-file.js"));
+    assert!(!side_effects("array/src/pure.js"));
+  });
+}
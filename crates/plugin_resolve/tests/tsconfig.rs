@@ -0,0 +1,147 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use farmfe_core::{context::CompilationContext, plugin::ResolveKind};
+use farmfe_plugin_resolve::resolver::Resolver;
+use farmfe_testing_helpers::fixture;
+use farmfe_toolkit::resolve::tsconfig_loader::TsConfigLoader;
+
+fn path_of(dir: &Path, segments: &[&str]) -> String {
+  segments
+    .iter()
+    .fold(dir.to_path_buf(), |p, s| p.join(s))
+    .to_string_lossy()
+    .to_string()
+}
+
+#[test]
+fn load_tsconfig_extends() {
+  fixture!("tests/fixtures/tsconfig-paths/tsconfig.json", |file, _| {
+    let cwd = file.parent().unwrap().to_path_buf();
+    let loader = TsConfigLoader::new();
+
+    let info = loader.load(cwd.join("src").join("utils")).unwrap().unwrap();
+    assert_eq!(
+      info.files,
+      vec![
+        path_of(&cwd, &["tsconfig.json"]),
+        path_of(&cwd, &["tsconfig.base.json"])
+      ]
+    );
+    assert_eq!(info.base_url, Some(path_of(&cwd, &["src"])));
+    assert_eq!(info.paths_base_dir, path_of(&cwd, &["src"]));
+    assert_eq!(info.paths.len(), 3);
+    assert_eq!(info.paths["@utils"], vec!["./utils/index.ts".to_string()]);
+
+    // every visited dir is cached
+    assert!(loader
+      .cache()
+      .contains_key(&path_of(&cwd, &["src", "utils"])));
+    assert!(loader.cache().contains_key(&path_of(&cwd, &["src"])));
+
+    let nested = loader
+      .load(cwd.join("packages").join("nested"))
+      .unwrap()
+      .unwrap();
+    assert_eq!(nested.files.len(), 3);
+    assert_eq!(
+      nested.base_url,
+      Some(path_of(&cwd, &["packages", "nested"]))
+    );
+    assert_eq!(nested.paths.len(), 1);
+  });
+}
+
+#[test]
+fn resolve_tsconfig_paths() {
+  fixture!("tests/fixtures/tsconfig-paths/tsconfig.json", |file, _| {
+    let cwd = file.parent().unwrap().to_path_buf();
+    let resolver = Resolver::new();
+    let context = Arc::new(CompilationContext::default());
+    let resolve = |source: &str, base_dir: PathBuf| {
+      resolver
+        .resolve(source, base_dir, &ResolveKind::Import, &context)
+        .map(|r| r.resolved_path)
+    };
+    let button = Some(path_of(&cwd, &["src", "components", "button.ts"]));
+
+    assert_eq!(resolve("@/components/button", cwd.join("src")), button);
+    assert_eq!(
+      resolve("@utils", cwd.join("src")),
+      Some(path_of(&cwd, &["src", "utils", "index.ts"]))
+    );
+    // try the targets in order
+    assert_eq!(resolve("@fallback/button", cwd.join("src")), button);
+    // baseUrl
+    assert_eq!(resolve("components/button", cwd.join("src")), button);
+    // fallback to node_modules
+    assert_eq!(
+      resolve("dep", cwd.join("src")),
+      Some(path_of(&cwd, &["node_modules", "dep", "index.js"]))
+    );
+
+    // paths of the closest tsconfig override the extended one
+    let nested = cwd.join("packages").join("nested");
+    assert_eq!(
+      resolve("@/foo", nested.clone()),
+      Some(path_of(&nested, &["lib", "foo.ts"]))
+    );
+    assert_eq!(resolve("@utils", nested), None);
+  });
+}
+
+#[test]
+fn invalidate_tsconfig_cache() {
+  let dir = std::env::temp_dir().join("farm-plugin-resolve-tsconfig-invalidate");
+  std::fs::create_dir_all(&dir).unwrap();
+  let tsconfig = dir.join("tsconfig.json");
+  std::fs::write(&tsconfig, r#"{ "compilerOptions": { "baseUrl": "." } }"#).unwrap();
+
+  let loader = TsConfigLoader::new();
+  let info = loader.load(dir.clone()).unwrap().unwrap();
+  assert_eq!(info.base_url, Some(dir.to_string_lossy().to_string()));
+  assert!(!loader.invalidate_if_changed());
+
+  std::fs::write(
+    &tsconfig,
+    r#"{ "compilerOptions": { "paths": { "@/*": ["./src/*"] } } }"#,
+  )
+  .unwrap();
+  assert!(loader.invalidate_if_changed());
+  assert!(loader.cache().is_empty());
+
+  let info = loader.load(dir.clone()).unwrap().unwrap();
+  assert_eq!(info.base_url, None);
+  assert_eq!(info.paths_base_dir, dir.to_string_lossy().to_string());
+
+  std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn broken_tsconfig_is_reported_once() {
+  let dir = std::env::temp_dir().join("farm-plugin-resolve-tsconfig-broken");
+  std::fs::create_dir_all(&dir).unwrap();
+  let tsconfig = dir.join("tsconfig.json");
+  std::fs::write(&tsconfig, r#"{ "compilerOptions": { "baseUrl": } }"#).unwrap();
+
+  let resolver = Resolver::new();
+  let context = Arc::new(CompilationContext::default());
+
+  assert_eq!(resolver.load_tsconfig(dir.clone(), &context), None);
+  assert_eq!(context.log_store.lock().warnings().len(), 1);
+  assert!(context.log_store.lock().warnings()[0]
+    .message
+    .contains("Failed to load tsconfig"));
+
+  // the failure is cached
+  assert_eq!(resolver.load_tsconfig(dir.join("src"), &context), None);
+  assert_eq!(context.log_store.lock().warnings().len(), 1);
+
+  std::fs::write(&tsconfig, r#"{ "compilerOptions": { "baseUrl": "." } }"#).unwrap();
+  assert!(resolver.tsconfig_loader().invalidate_if_changed());
+  assert!(resolver.load_tsconfig(dir.clone(), &context).is_some());
+
+  std::fs::remove_dir_all(&dir).unwrap();
+}
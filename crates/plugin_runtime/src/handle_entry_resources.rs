@@ -18,12 +18,16 @@ use farmfe_core::{
 use farmfe_toolkit::common::{append_source_map_comment, generate_source_map_resource};
 use farmfe_toolkit::fs::transform_output_entry_filename;
 use farmfe_toolkit::get_dynamic_resources_map::{
-  get_dynamic_resources_code, get_dynamic_resources_map,
+  get_dynamic_resources_code, get_dynamic_resources_map, get_resources_integrity,
 };
 use farmfe_toolkit::html::get_farm_global_this;
 use farmfe_toolkit::sourcemap::SourceMap;
 
 const FARM_NODE_MODULE: &str = "__farmNodeModule";
+/// the exports object returned by the iife or umd wrapper
+const FARM_ENTRY_EXPORTS: &str = "__farm_entry_exports__";
+/// the external modules object passed to the iife or umd wrapper
+const FARM_EXTERNALS: &str = "__farm_externals__";
 
 pub enum ExportInfoOfEntryModule {
   Default,
@@ -176,6 +180,9 @@ fn get_export_info_code(
         ExportInfoOfEntryModule::Default => match context.config.output.format {
           ModuleFormat::CommonJs => "module.exports = entry.default || entry;".to_string(),
           ModuleFormat::EsModule => "export default entry.default || entry;".to_string(),
+          ModuleFormat::Iife | ModuleFormat::Umd => {
+            format!("{FARM_ENTRY_EXPORTS}.default = entry.default || entry;")
+          }
         },
         ExportInfoOfEntryModule::Named { name, import_as } => {
           if let Some(import_as) = import_as {
@@ -185,17 +192,24 @@ fn get_export_info_code(
                 "var {name}=entry.{name};export {{ {} as {} }};",
                 name, import_as
               ),
+              ModuleFormat::Iife | ModuleFormat::Umd => {
+                format!("{FARM_ENTRY_EXPORTS}.{} = entry.{};", import_as, name)
+              }
             }
           } else {
             match context.config.output.format {
               ModuleFormat::CommonJs => format!("module.exports.{} = entry.{};", name, name),
               ModuleFormat::EsModule => format!("var {name}=entry.{name};export {{ {} }};", name),
+              ModuleFormat::Iife | ModuleFormat::Umd => {
+                format!("{FARM_ENTRY_EXPORTS}.{} = entry.{};", name, name)
+              }
             }
           }
         }
         ExportInfoOfEntryModule::CJS => match context.config.output.format {
           ModuleFormat::CommonJs => "module.exports = entry;".to_string(),
           ModuleFormat::EsModule => "export default entry;".to_string(),
+          ModuleFormat::Iife | ModuleFormat::Umd => format!("{FARM_ENTRY_EXPORTS} = entry;"),
         },
       })
       .collect::<Vec<String>>()
@@ -271,8 +285,12 @@ fn get_entry_resource_and_dep_resources_name(
 
   let dynamic_resources_map =
     get_dynamic_resources_map(module_group_graph, entry, &resource_pot_map, resource_map);
-  let dynamic_resources_code =
-    get_dynamic_resources_code(&dynamic_resources_map, context.config.mode.clone());
+  let resources_integrity = get_resources_integrity(resource_map, context.config.output.sri);
+  let dynamic_resources_code = get_dynamic_resources_code(
+    &dynamic_resources_map,
+    context.config.mode.clone(),
+    &resources_integrity,
+  );
 
   result.dynamic_resources_code = dynamic_resources_code;
   result
@@ -289,6 +307,8 @@ pub fn handle_entry_resources(
   let mut runtime_code = None;
   let mut runtime_resource = None;
   let mut should_inject_runtime = false;
+  // dep resources that are inlined into iife or umd entry resources
+  let mut inlined_resources = HashSet::new();
 
   for (entry, _) in &module_graph.entries {
     let module = module_graph
@@ -313,19 +333,32 @@ pub fn handle_entry_resources(
       );
       dep_resources.sort();
 
+      let is_iife_or_umd = context.config.output.format.is_iife_or_umd();
+
       if !should_inject_runtime {
-        should_inject_runtime = !dep_resources.is_empty();
+        should_inject_runtime = !dep_resources.is_empty() && !is_iife_or_umd;
       }
 
-      // 1. import 'dep' or require('dep') to entry resource if target env is node
-      let dep_resources_require_code = dep_resources
-        .iter()
-        .map(|rn| match context.config.output.format {
-          ModuleFormat::EsModule => format!("import \"./{rn}\";"),
-          ModuleFormat::CommonJs => format!("require(\"./{rn}\");"),
-        })
-        .collect::<Vec<_>>()
-        .join("");
+      // 1. import 'dep' or require('dep') to entry resource if target env is node.
+      // iife and umd resources can not import other resources, so the dep resources are inlined
+      let dep_resources_require_code = if is_iife_or_umd {
+        inlined_resources.extend(dep_resources.iter().cloned());
+
+        dep_resources
+          .iter()
+          .map(|rn| String::from_utf8_lossy(&resources_map[rn].bytes).to_string())
+          .collect::<Vec<_>>()
+          .join("\n")
+      } else {
+        dep_resources
+          .iter()
+          .map(|rn| match context.config.output.format {
+            ModuleFormat::EsModule => format!("import \"./{rn}\";"),
+            _ => format!("require(\"./{rn}\");"),
+          })
+          .collect::<Vec<_>>()
+          .join("")
+      };
 
       let farm_global_this = get_farm_global_this(&context.config.runtime.namespace);
 
@@ -378,7 +411,7 @@ pub fn handle_entry_resources(
 
           match context.config.output.format {
             ModuleFormat::EsModule => format!("import \"./{}\";", runtime_resource.name),
-            ModuleFormat::CommonJs => format!("require(\"./{}\");", runtime_resource.name),
+            _ => format!("require(\"./{}\");", runtime_resource.name),
           }
         } else {
           runtime_code.clone()
@@ -387,15 +420,28 @@ pub fn handle_entry_resources(
         entry_bundle.prepend(&pre);
       }
 
+      let set_external_modules_code = if is_iife_or_umd {
+        format!("{farm_global_this}.{FARM_MODULE_SYSTEM}.setExternalModules({FARM_EXTERNALS});")
+      } else {
+        "".to_string()
+      };
+
       for post in vec![
         set_initial_loaded_resources_code,
         set_dynamic_resources_map_code,
+        set_external_modules_code,
         call_entry_code,
         export_info_code,
       ] {
         entry_bundle.append(&post);
       }
 
+      if is_iife_or_umd {
+        let (wrapper_start, wrapper_end) = get_iife_or_umd_wrapper(&module_graph, context);
+        entry_bundle.prepend(&wrapper_start);
+        entry_bundle.append(&wrapper_end);
+      }
+
       let entry_bundle_code = entry_bundle.to_string();
       // update entry resource
       entry_js_resource.bytes = entry_bundle_code.into_bytes();
@@ -431,6 +477,87 @@ pub fn handle_entry_resources(
       resources_map.insert(runtime_resource.name.clone(), runtime_resource);
     }
   }
+
+  // inlined resources are part of the entry resource now, do not emit them
+  for name in inlined_resources {
+    if let Some(resource) = resources_map.get_mut(&name) {
+      resource.emitted = true;
+    }
+  }
+}
+
+/// Wrap the entry resource so that it can be loaded by a plain `<script>` tag(iife), or by commonjs, amd and `<script>`(umd).
+/// The wrapper passes external modules to the module system and returns the exports of the entry module.
+fn get_iife_or_umd_wrapper(
+  module_graph: &ModuleGraph,
+  context: &Arc<CompilationContext>,
+) -> (String, String) {
+  let output = &context.config.output;
+  let mut external_modules = module_graph
+    .modules()
+    .into_iter()
+    .filter(|m| m.external)
+    .map(|m| m.id.to_string())
+    .collect::<Vec<_>>();
+  external_modules.sort();
+
+  let externals_object = |get_external: &dyn Fn(usize, &String) -> String| {
+    format!(
+      "{{{}}}",
+      external_modules
+        .iter()
+        .enumerate()
+        .map(|(i, source)| format!("{source:?}:{}", get_external(i, source)))
+        .collect::<Vec<_>>()
+        .join(",")
+    )
+  };
+  let global_name_of = |source: &String| output.globals.get(source).unwrap_or(source).clone();
+
+  let factory_start = format!("function({FARM_EXTERNALS}){{var {FARM_ENTRY_EXPORTS}={{}};");
+  let factory_end = format!("return {FARM_ENTRY_EXPORTS};}}");
+
+  match output.format {
+    ModuleFormat::Iife => {
+      let global_externals = externals_object(&|_, source| {
+        format!("(globalThis||window||{{}})[{:?}]", global_name_of(source))
+      });
+      let assignment = output
+        .name
+        .as_ref()
+        .map(|name| format!("var {name}="))
+        .unwrap_or_default();
+
+      (
+        format!("{assignment}({factory_start}"),
+        format!("{factory_end})({global_externals});"),
+      )
+    }
+    ModuleFormat::Umd => {
+      let cjs_externals = externals_object(&|_, source| format!("require({source:?})"));
+      let amd_deps = format!("{external_modules:?}");
+      let amd_params = (0..external_modules.len())
+        .map(|i| format!("__farm_amd_dep_{i}"))
+        .collect::<Vec<_>>()
+        .join(",");
+      let amd_externals = externals_object(&|i, _| format!("__farm_amd_dep_{i}"));
+      let global_externals =
+        externals_object(&|_, source| format!("root[{:?}]", global_name_of(source)));
+      let global_assignment = output
+        .name
+        .as_ref()
+        .map(|name| format!("root[{name:?}]="))
+        .unwrap_or_default();
+
+      (
+        format!(
+          "(function(root,factory){{if(typeof exports==='object'&&typeof module!=='undefined'){{module.exports=factory({cjs_externals});}}else if(typeof define==='function'&&define.amd){{define({amd_deps},function({amd_params}){{return factory({amd_externals});}});}}else{{{global_assignment}factory({global_externals});}}}})(typeof globalThis!=='undefined'?globalThis:typeof self!=='undefined'?self:this,{factory_start}"
+        ),
+        format!("{factory_end});"),
+      )
+    }
+    ModuleFormat::EsModule | ModuleFormat::CommonJs => unreachable!(),
+  }
 }
 
 fn create_runtime_code(
@@ -445,6 +572,8 @@ fn create_runtime_code(
         )
       }
       ModuleFormat::CommonJs => r#"globalThis.nodeRequire = require;"#.to_string(), // _ => panic!("node only support cjs and esm format"),
+      // external modules of iife and umd are passed by the wrapper
+      ModuleFormat::Iife | ModuleFormat::Umd => "".to_string(),
     }
   } else {
     "".to_string()
@@ -481,10 +610,7 @@ fn create_farm_runtime_resource(runtime_code: &str, context: &Arc<CompilationCon
     "__farm_runtime",
     "__farm_runtime",
     &bytes,
-    match context.config.output.format {
-      ModuleFormat::EsModule => "mjs",
-      ModuleFormat::CommonJs => "cjs",
-    },
+    context.config.output.format.entry_ext(),
   );
   Resource {
     name: name.clone(),
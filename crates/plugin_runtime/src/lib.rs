@@ -399,8 +399,12 @@ impl Plugin for FarmPluginRuntime {
 
       let farm_global_this = get_farm_global_this(&context.config.runtime.namespace);
 
-      // inject global externals
-      if !external_modules.is_empty() && context.config.output.target_env == TargetEnv::Node {
+      // inject global externals.
+      // For iife and umd, external modules are passed by the wrapper of the entry resource, see handle_entry_resources
+      let inject_externals =
+        !external_modules.is_empty() && !context.config.output.format.is_iife_or_umd();
+
+      if inject_externals && context.config.output.target_env == TargetEnv::Node {
         let mut import_strings = vec![];
         let mut source_to_names = vec![];
 
@@ -438,9 +442,7 @@ impl Plugin for FarmPluginRuntime {
         ));
 
         external_modules_str = Some(prepend_str);
-      } else if !external_modules.is_empty()
-        && context.config.output.target_env == TargetEnv::Browser
-      {
+      } else if inject_externals && context.config.output.target_env == TargetEnv::Browser {
         let prepend_str = format!(
           "{farm_global_this}.{FARM_MODULE_SYSTEM}.setExternalModules({{{}}});",
           external_modules
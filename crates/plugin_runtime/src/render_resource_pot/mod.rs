@@ -1,5 +1,5 @@
 use std::{
-  collections::{HashMap, HashSet},
+  collections::{hash_map::Entry, HashMap, HashSet},
   sync::Arc,
 };
 
@@ -23,9 +23,13 @@ use farmfe_core::{
 use farmfe_toolkit::common::PathFilter;
 use farmfe_utils::hash::sha256;
 
-use self::render_module::{render_module, RenderModuleResult};
+use self::{
+  render_module::{render_module, RenderModuleResult},
+  scope_hoisting::{concatenate_modules, find_concatenation_groups, ConcatenationGroups},
+};
 
 mod render_module;
+mod scope_hoisting;
 mod source_replacer;
 mod transform_async_module;
 
@@ -70,103 +74,32 @@ pub fn resource_pot_to_runtime_object(
     minify_enabled && path_filter.execute(&module_id.resolved_path(&context.config.root))
   };
 
+  let concatenation_groups = if context.config.concatenate_modules_enabled() {
+    find_concatenation_groups(resource_pot, module_graph, async_modules, context)
+  } else {
+    ConcatenationGroups::default()
+  };
+
   resource_pot
     .modules()
     .into_par_iter()
+    .filter(|m_id| !concatenation_groups.hoisted_modules.contains(*m_id))
     .try_for_each(|m_id| {
-      let module = module_graph
-        .module(m_id)
-        .unwrap_or_else(|| panic!("Module not found: {:?}", m_id));
-
-      let mut cache_store_key = None;
-
-      // enable persistent cache
-      if context.config.persistent_cache.enabled() {
-        let content_hash = module.content_hash.clone();
-        let store_key = CacheStoreKey {
-          name: m_id.to_string() + "-resource_pot_to_runtime_object",
-          key: sha256(
-            format!(
-              "resource_pot_to_runtime_object_{}_{}_{}",
-              content_hash,
-              m_id.to_string(),
-              module.used_exports.join(",")
-            )
-            .as_bytes(),
-            32,
-          ),
-        };
-        cache_store_key = Some(store_key.clone());
-
-        // determine whether the cache exists,and store_key not change
-        if context.cache_manager.custom.has_cache(&store_key.name)
-          && !context.cache_manager.custom.is_cache_changed(&store_key)
-        {
-          if let Some(cache) = context.cache_manager.custom.read_cache(&store_key.name) {
-            let cached_rendered_script_module = deserialize!(&cache, CacheRenderedScriptModule);
-            let module = cached_rendered_script_module.to_magic_string(&context);
-
-            modules.lock().push(RenderedScriptModule {
-              module,
-              id: cached_rendered_script_module.id,
-              rendered_module: cached_rendered_script_module.rendered_module,
-              external_modules: cached_rendered_script_module.external_modules,
-            });
-            return Ok(());
-          }
-        }
-      }
-
-      let is_async_module = async_modules.contains(&m_id);
-      let RenderModuleResult {
-        rendered_module,
-        external_modules,
-        source_map_chain,
-      } = render_module(
-        module,
+      let hoisted_modules = concatenation_groups
+        .groups
+        .get(m_id)
+        .map(|group| group.as_slice())
+        .unwrap_or_default();
+      let rendered = render_script_module(
+        m_id,
+        hoisted_modules,
         module_graph,
-        is_enabled_minify,
+        async_modules,
+        &is_enabled_minify,
         &minify_options,
-        is_async_module,
         context,
       )?;
-      let code = rendered_module.rendered_content.clone();
-
-      // cache the code and sourcemap
-      if context.config.persistent_cache.enabled() {
-        let cache_rendered_script_module = CacheRenderedScriptModule::new(
-          m_id.clone(),
-          code.clone(),
-          rendered_module.clone(),
-          external_modules.clone(),
-          source_map_chain.clone(),
-        );
-        let bytes = serialize!(&cache_rendered_script_module);
-        context
-          .cache_manager
-          .custom
-          .write_single_cache(cache_store_key.unwrap(), bytes)
-          .expect("failed to write resource pot to runtime object cache");
-      }
-
-      let mut module = MagicString::new(
-        &code,
-        Some(MagicStringOptions {
-          filename: Some(m_id.resolved_path_with_query(&context.config.root)),
-          source_map_chain,
-          ..Default::default()
-        }),
-      );
-
-      module.prepend(&format!("{:?}:", m_id.id(context.config.mode.clone())));
-      module.append(",");
-
-      modules.lock().push(RenderedScriptModule {
-        id: m_id.clone(),
-        module,
-        rendered_module,
-        external_modules,
-      });
+      modules.lock().extend(rendered);
 
       Ok::<(), CompilationError>(())
     })?;
@@ -198,6 +131,20 @@ pub fn resource_pot_to_runtime_object(
     external_modules_set.extend(m.external_modules);
   }
 
+  // the hoisted modules are rendered as part of their root module
+  for m_id in concatenation_groups.hoisted_modules {
+    if let Entry::Vacant(entry) = rendered_modules.entry(m_id.clone()) {
+      let module = module_graph.module(&m_id).unwrap();
+      entry.insert(RenderedModule {
+        id: m_id,
+        rendered_content: Arc::new(String::new()),
+        rendered_map: None,
+        rendered_length: 0,
+        original_length: module.content.len(),
+      });
+    }
+  }
+
   let mut external_modules = external_modules_set.into_iter().collect::<Vec<_>>();
   external_modules.sort();
 
@@ -211,6 +158,141 @@ pub fn resource_pot_to_runtime_object(
   })
 }
 
+/// Render a module to the prop of the runtime object. If there are modules hoisted into this module, they are concatenated and rendered together,
+/// or rendered separately when they can not be concatenated.
+fn render_script_module<F: Fn(&ModuleId) -> bool>(
+  m_id: &ModuleId,
+  hoisted_modules: &[ModuleId],
+  module_graph: &ModuleGraph,
+  async_modules: &HashSet<ModuleId>,
+  is_enabled_minify: &F,
+  minify_options: &MinifyOptions,
+  context: &Arc<CompilationContext>,
+) -> Result<Vec<RenderedScriptModule>> {
+  let module = module_graph
+    .module(m_id)
+    .unwrap_or_else(|| panic!("Module not found: {:?}", m_id));
+
+  let mut cache_store_key = None;
+
+  // enable persistent cache
+  if context.config.persistent_cache.enabled() {
+    let content_hash = hoisted_modules
+      .iter()
+      .map(|id| module_graph.module(id).unwrap())
+      .chain([module])
+      .map(|m| format!("{}_{}", m.content_hash, m.used_exports.join(",")))
+      .collect::<Vec<_>>()
+      .join("_");
+    let store_key = CacheStoreKey {
+      name: m_id.to_string() + "-resource_pot_to_runtime_object",
+      key: sha256(
+        format!(
+          "resource_pot_to_runtime_object_{}_{}",
+          content_hash,
+          m_id.to_string(),
+        )
+        .as_bytes(),
+        32,
+      ),
+    };
+    cache_store_key = Some(store_key.clone());
+
+    // determine whether the cache exists,and store_key not change
+    if context.cache_manager.custom.has_cache(&store_key.name)
+      && !context.cache_manager.custom.is_cache_changed(&store_key)
+    {
+      if let Some(cache) = context.cache_manager.custom.read_cache(&store_key.name) {
+        let cached_rendered_script_module = deserialize!(&cache, CacheRenderedScriptModule);
+        let module = cached_rendered_script_module.to_magic_string(context);
+
+        return Ok(vec![RenderedScriptModule {
+          module,
+          id: cached_rendered_script_module.id,
+          rendered_module: cached_rendered_script_module.rendered_module,
+          external_modules: cached_rendered_script_module.external_modules,
+        }]);
+      }
+    }
+  }
+
+  let concatenated_module = if hoisted_modules.is_empty() {
+    None
+  } else if let Some(concatenated_module) =
+    concatenate_modules(m_id, hoisted_modules, module_graph, context)
+  {
+    Some(concatenated_module)
+  } else {
+    let mut rendered = vec![];
+
+    for id in hoisted_modules.iter().chain([m_id]) {
+      rendered.extend(render_script_module(
+        id,
+        &[],
+        module_graph,
+        async_modules,
+        is_enabled_minify,
+        minify_options,
+        context,
+      )?);
+    }
+
+    return Ok(rendered);
+  };
+
+  let is_async_module = async_modules.contains(m_id);
+  let RenderModuleResult {
+    rendered_module,
+    external_modules,
+    source_map_chain,
+  } = render_module(
+    module,
+    module_graph,
+    is_enabled_minify,
+    minify_options,
+    is_async_module,
+    concatenated_module,
+    context,
+  )?;
+  let code = rendered_module.rendered_content.clone();
+
+  // cache the code and sourcemap
+  if context.config.persistent_cache.enabled() {
+    let cache_rendered_script_module = CacheRenderedScriptModule::new(
+      m_id.clone(),
+      code.clone(),
+      rendered_module.clone(),
+      external_modules.clone(),
+      source_map_chain.clone(),
+    );
+    let bytes = serialize!(&cache_rendered_script_module);
+    context
+      .cache_manager
+      .custom
+      .write_single_cache(cache_store_key.unwrap(), bytes)
+      .expect("failed to write resource pot to runtime object cache");
+  }
+
+  let mut module = MagicString::new(
+    &code,
+    Some(MagicStringOptions {
+      filename: Some(m_id.resolved_path_with_query(&context.config.root)),
+      source_map_chain,
+      ..Default::default()
+    }),
+  );
+
+  module.prepend(&format!("{:?}:", m_id.id(context.config.mode.clone())));
+  module.append(",");
+
+  Ok(vec![RenderedScriptModule {
+    id: m_id.clone(),
+    module,
+    rendered_module,
+    external_modules,
+  }])
+}
+
 pub struct RenderedScriptModule {
   pub id: ModuleId,
   pub module: MagicString,
@@ -1,4 +1,4 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use farmfe_core::{
   config::minify::MinifyOptions,
@@ -44,6 +44,7 @@ use farmfe_core::{
 };
 
 use super::{
+  scope_hoisting::ConcatenatedModule,
   source_replacer::{ExistingCommonJsRequireVisitor, SourceReplacer},
   transform_async_module,
 };
@@ -60,19 +61,40 @@ pub fn render_module<F: Fn(&ModuleId) -> bool>(
   is_enabled_minify: F,
   minify_options: &MinifyOptions,
   is_async_module: bool,
+  concatenated_module: Option<ConcatenatedModule>,
   context: &Arc<CompilationContext>,
 ) -> farmfe_core::error::Result<RenderModuleResult> {
-  let mut cloned_module = module.meta.as_script().ast.clone();
-  let (cm, _) = create_swc_source_map(Source {
-    path: PathBuf::from(module.id.resolved_path_with_query(&context.config.root)),
-    content: module.content.clone(),
-  });
+  let mut concatenated_marks = None;
+  let mut concatenated_sources = HashMap::new();
+  // render the concatenated ast instead if there are modules hoisted into this module
+  let (mut cloned_module, cm, comments) = if let Some(concatenated_module) = concatenated_module {
+    concatenated_marks = Some((
+      concatenated_module.unresolved_mark,
+      concatenated_module.top_level_mark,
+    ));
+    concatenated_sources = concatenated_module.concatenated_sources;
+
+    (
+      concatenated_module.ast,
+      concatenated_module.cm,
+      concatenated_module.comments,
+    )
+  } else {
+    let (cm, _) = create_swc_source_map(Source {
+      path: PathBuf::from(module.id.resolved_path_with_query(&context.config.root)),
+      content: module.content.clone(),
+    });
+    let comments: SingleThreadedComments = module.meta.as_script().comments.clone().into();
+
+    (module.meta.as_script().ast.clone(), cm, comments)
+  };
   let mut external_modules = vec![];
-  let comments: SingleThreadedComments = module.meta.as_script().comments.clone().into();
   let minify_enabled = is_enabled_minify(&module.id);
 
   try_with(cm.clone(), &context.meta.script.globals, || {
-    let (unresolved_mark, top_level_mark) = if module.meta.as_script().unresolved_mark == 0
+    let (unresolved_mark, top_level_mark) = if let Some(marks) = concatenated_marks {
+      marks
+    } else if module.meta.as_script().unresolved_mark == 0
       && module.meta.as_script().top_level_mark == 0
     {
       resolve_module_mark(
@@ -113,7 +135,7 @@ pub fn render_module<F: Fn(&ModuleId) -> bool>(
           preserve_import_meta: true,
           ..Default::default()
         },
-        enable_available_feature_from_es_version(context.config.script.target),
+        enable_available_feature_from_es_version(context.config.script.target()),
         Some(&comments),
       ));
     }
@@ -125,6 +147,7 @@ pub fn render_module<F: Fn(&ModuleId) -> bool>(
       module_graph,
       module.id.clone(),
       context.config.mode.clone(),
+      concatenated_sources,
     );
     cloned_module.visit_mut_with(&mut source_replacer);
     cloned_module.visit_mut_with(&mut hygiene_with_config(HygieneConfig {
@@ -171,7 +194,7 @@ pub fn render_module<F: Fn(&ModuleId) -> bool>(
   let mut mappings = vec![];
   let code_bytes = codegen_module(
     &cloned_module,
-    context.config.script.target.clone(),
+    context.config.script.target(),
     cm.clone(),
     if sourcemap_enabled {
       Some(&mut mappings)
@@ -0,0 +1,1171 @@
+//! Scope hoisting for production builds. Esm modules that are only statically imported by modules of the same [ResourcePot]
+//! are concatenated into the module that imports them, so they are not wrapped by Farm's module system any more. For example:
+//! ```js
+//! // a.js
+//! import { b } from './b';
+//! console.log(b);
+//! // b.js
+//! export const b = 1;
+//! ```
+//! will be rendered to
+//! ```js
+//! "a.js": function(module, exports, farmRequire, farmDynamicRequire) {
+//!   const b = 1;
+//!   console.log(b);
+//! }
+//! ```
+//! Modules that must keep the runtime wrapper(entries, dynamic imported modules, commonjs modules, async modules and modules imported across resource pots)
+//! are the roots of the concatenation. The hoisted modules share the unresolved and top level marks of the root, their top level
+//! bindings that conflict with other modules of the concatenation are renamed.
+
+use std::{
+  collections::{HashMap, HashSet},
+  path::PathBuf,
+  sync::Arc,
+};
+
+use farmfe_core::{
+  context::CompilationContext,
+  module::{module_graph::ModuleGraph, Module, ModuleId, ModuleSystem},
+  plugin::ResolveKind,
+  resource::resource_pot::ResourcePot,
+  swc_common::{
+    comments::{Comments, SingleThreadedComments},
+    BytePos, FileName, Mark, SourceMap, Span, SyntaxContext, DUMMY_SP,
+  },
+  swc_ecma_ast::{
+    BindingIdent, BlockStmt, ClassDecl, Decl, DefaultDecl, ExportDefaultDecl, ExportNamedSpecifier,
+    ExportSpecifier, Expr, FnDecl, GetterProp, Id, Ident, ImportDecl, ImportNamedSpecifier,
+    ImportSpecifier, ImportStarAsSpecifier, KeyValueProp, MemberProp, Module as SwcModule,
+    ModuleDecl, ModuleExportName, ModuleItem, NamedExport, ObjectLit, Pat, Prop, PropName,
+    PropOrSpread, ReturnStmt, Stmt, Str, SuperProp, VarDecl, VarDeclKind, VarDeclarator,
+  },
+};
+use farmfe_toolkit::{
+  script::swc_try_with::{resolve_module_mark, try_with},
+  swc_atoms::JsWord,
+  swc_ecma_utils::find_pat_ids,
+  swc_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith},
+};
+
+/// The result of [find_concatenation_groups]
+#[derive(Default)]
+pub struct ConcatenationGroups {
+  /// root module -> modules that are concatenated into the root, in execution order
+  pub groups: HashMap<ModuleId, Vec<ModuleId>>,
+  /// all modules that are concatenated into a root
+  pub hoisted_modules: HashSet<ModuleId>,
+}
+
+/// The concatenated ast of a root module and the modules hoisted into it, still an esm module.
+pub struct ConcatenatedModule {
+  pub ast: SwcModule,
+  pub comments: SingleThreadedComments,
+  pub cm: Arc<SourceMap>,
+  pub unresolved_mark: Mark,
+  pub top_level_mark: Mark,
+  /// the sources of the remaining imports of the hoisted modules are replaced by module ids,
+  /// they can not be resolved from the root module, see [super::source_replacer::SourceReplacer]
+  pub concatenated_sources: HashMap<String, ModuleId>,
+}
+
+/// Find the modules that can be concatenated into other modules of the resource pot.
+pub fn find_concatenation_groups(
+  resource_pot: &ResourcePot,
+  module_graph: &ModuleGraph,
+  async_modules: &HashSet<ModuleId>,
+  context: &Arc<CompilationContext>,
+) -> ConcatenationGroups {
+  let pot_modules = resource_pot
+    .modules()
+    .into_iter()
+    .cloned()
+    .collect::<HashSet<_>>();
+  let can_be_concatenated = |module_id: &ModuleId| {
+    let module = module_graph.module(module_id).unwrap();
+
+    pot_modules.contains(module_id)
+      && module.module_type.is_script()
+      && !module.external
+      && !async_modules.contains(module_id)
+      && matches!(module.meta.as_script().module_system, ModuleSystem::EsModule)
+      // the concatenated module only has one source map chain, see [concatenate_modules]
+      && (!context.config.sourcemap.enabled(module.immutable) || module.source_map_chain.is_empty())
+  };
+
+  let mut candidates = resource_pot
+    .modules()
+    .into_iter()
+    .filter(|&module_id| {
+      let dependents = module_graph.dependents(module_id);
+
+      !module_graph.entries.contains_key(module_id)
+        && can_be_concatenated(module_id)
+        && !dependents.is_empty()
+        && dependents.iter().all(|(dependent, edge)| {
+          can_be_concatenated(dependent)
+            && edge
+              .iter()
+              .all(|item| matches!(item.kind, ResolveKind::Import | ResolveKind::ExportFrom))
+        })
+    })
+    .cloned()
+    .collect::<HashSet<_>>();
+
+  loop {
+    let mut roots = HashMap::new();
+    let mut demoted = vec![];
+
+    for candidate in &candidates {
+      match find_root(candidate, &candidates, module_graph) {
+        Some(root) => {
+          roots.insert(candidate.clone(), root);
+        }
+        None => demoted.push(candidate.clone()),
+      }
+    }
+
+    let mut groups = HashMap::new();
+
+    if demoted.is_empty() {
+      for root in roots.values().collect::<HashSet<_>>() {
+        let mut group = ConcatenationOrder::default();
+        group.visit(root, root, &roots, module_graph);
+        demoted.extend(group.demoted);
+        groups.insert(root.clone(), group.hoisted_modules);
+      }
+    }
+
+    if demoted.is_empty() {
+      return ConcatenationGroups {
+        groups,
+        hoisted_modules: candidates,
+      };
+    }
+
+    for module_id in demoted {
+      candidates.remove(&module_id);
+    }
+  }
+}
+
+/// A candidate can be concatenated only when all of its importers lead to the same root
+fn find_root(
+  candidate: &ModuleId,
+  candidates: &HashSet<ModuleId>,
+  module_graph: &ModuleGraph,
+) -> Option<ModuleId> {
+  let mut roots = HashSet::new();
+  let mut visited = HashSet::from([candidate.clone()]);
+  let mut stack = vec![candidate.clone()];
+
+  while let Some(module_id) = stack.pop() {
+    for dependent in module_graph.dependents_ids(&module_id) {
+      if candidates.contains(&dependent) {
+        if visited.insert(dependent.clone()) {
+          stack.push(dependent);
+        }
+      } else {
+        roots.insert(dependent);
+      }
+    }
+  }
+
+  if roots.len() == 1 {
+    roots.into_iter().next()
+  } else {
+    None
+  }
+}
+
+/// Compute the execution order of the hoisted modules of a root.
+///
+/// The imports that are not concatenated are transformed to `require` and hoisted to the top of the root module,
+/// so a hoisted module that has side effects can not be executed before a kept import that has side effects, otherwise the execution order changes.
+#[derive(Default)]
+struct ConcatenationOrder {
+  visited: HashSet<ModuleId>,
+  hoisted_modules: Vec<ModuleId>,
+  hoisted_with_side_effects: Vec<ModuleId>,
+  demoted: Vec<ModuleId>,
+}
+
+impl ConcatenationOrder {
+  fn visit(
+    &mut self,
+    module_id: &ModuleId,
+    root: &ModuleId,
+    roots: &HashMap<ModuleId, ModuleId>,
+    module_graph: &ModuleGraph,
+  ) {
+    self.visited.insert(module_id.clone());
+
+    for (dep, edge) in module_graph.dependencies(module_id) {
+      if self.visited.contains(&dep)
+        || !edge
+          .iter()
+          .any(|item| matches!(item.kind, ResolveKind::Import | ResolveKind::ExportFrom))
+      {
+        continue;
+      }
+
+      if roots.get(&dep) == Some(root) {
+        self.visit(&dep, root, roots, module_graph);
+      } else {
+        self.visited.insert(dep.clone());
+        let dep_module = module_graph.module(&dep).unwrap();
+
+        if dep_module.side_effects && (dep_module.external || dep_module.module_type.is_script()) {
+          self.demoted.append(&mut self.hoisted_with_side_effects);
+        }
+      }
+    }
+
+    if module_id != root {
+      self.hoisted_modules.push(module_id.clone());
+
+      if module_graph.module(module_id).unwrap().side_effects {
+        self.hoisted_with_side_effects.push(module_id.clone());
+      }
+    }
+  }
+}
+
+/// What an import binding or an export refers to
+#[derive(Clone)]
+enum BindingTarget {
+  Local(Id),
+  Export(ModuleId, JsWord),
+  Namespace(ModuleId),
+}
+
+#[derive(Default)]
+struct ModuleExports {
+  exports: HashMap<JsWord, BindingTarget>,
+  star_exports: Vec<ModuleId>,
+}
+
+/// Concatenate the hoisted modules into the root module. Return None if the modules contain syntax that can not be concatenated,
+/// in that case all the modules are rendered separately.
+pub fn concatenate_modules(
+  root: &ModuleId,
+  hoisted_modules: &[ModuleId],
+  module_graph: &ModuleGraph,
+  context: &Arc<CompilationContext>,
+) -> Option<ConcatenatedModule> {
+  let cm = Arc::new(SourceMap::default());
+  let base_pos = SourceMap::default()
+    .new_source_file(FileName::Anon, String::new())
+    .start_pos;
+  let comments = SingleThreadedComments::default();
+
+  let mut result = None;
+
+  try_with(cm.clone(), &context.meta.script.globals, || {
+    let mut concatenator = ModuleConcatenator {
+      root,
+      module_graph,
+      group: hoisted_modules
+        .iter()
+        .chain([root])
+        .cloned()
+        .collect::<HashSet<_>>(),
+      bindings: HashMap::new(),
+      exports: HashMap::new(),
+      concatenated_sources: HashMap::new(),
+    };
+    let mut items_of_modules = vec![];
+    let mut root_marks = None;
+    let mut hoisted_marks = vec![];
+
+    for module_id in hoisted_modules.iter().chain([root]) {
+      let module = module_graph.module(module_id).unwrap();
+      let source_file = cm.new_source_file_from(
+        FileName::Real(PathBuf::from(
+          module_id.resolved_path_with_query(&context.config.root),
+        )),
+        module.content.clone(),
+      );
+      let offset = source_file.start_pos.0 - base_pos.0;
+      let (ast, marks) = clone_ast_with_offset(module, offset, &comments, context);
+
+      if module_id == root {
+        root_marks = Some(marks);
+      } else {
+        hoisted_marks.push(marks);
+      }
+
+      let Some(items) = concatenator.analyze_module(module_id, ast) else {
+        return;
+      };
+      items_of_modules.push((module_id.clone(), items));
+    }
+
+    let Some(rename_map) = concatenator.resolve_bindings(&mut items_of_modules) else {
+      return;
+    };
+
+    let mut ast = SwcModule {
+      span: DUMMY_SP,
+      body: items_of_modules
+        .into_iter()
+        .flat_map(|(_, items)| items)
+        .collect(),
+      shebang: None,
+    };
+    ast.visit_mut_with(&mut BindingRenamer { map: &rename_map });
+
+    // the passes of render_module only know the marks of the root, e.g. globals of the hoisted modules must be unresolved for the root too
+    let (unresolved_mark, top_level_mark) = root_marks.unwrap();
+    let merge_map =
+      merge_contexts_into_root(&ast, &hoisted_marks, (unresolved_mark, top_level_mark));
+    ast.visit_mut_with(&mut BindingRenamer { map: &merge_map });
+    result = Some(ConcatenatedModule {
+      ast,
+      comments,
+      cm: cm.clone(),
+      unresolved_mark,
+      top_level_mark,
+      concatenated_sources: concatenator.concatenated_sources,
+    });
+  })
+  .ok()?;
+
+  result
+}
+
+/// Clone the ast of the module, move its spans and comments after the previous modules of the concatenated source map.
+fn clone_ast_with_offset(
+  module: &Module,
+  offset: u32,
+  comments: &SingleThreadedComments,
+  context: &Arc<CompilationContext>,
+) -> (SwcModule, (Mark, Mark)) {
+  let script = module.meta.as_script();
+  let mut ast = script.ast.clone();
+
+  let marks = if script.unresolved_mark == 0 && script.top_level_mark == 0 {
+    resolve_module_mark(&mut ast, module.module_type.is_typescript(), context)
+  } else {
+    (
+      Mark::from_u32(script.unresolved_mark),
+      Mark::from_u32(script.top_level_mark),
+    )
+  };
+
+  let mut span_offset = SpanOffset { offset };
+  ast.visit_mut_with(&mut span_offset);
+
+  for (items, is_leading) in [
+    (&script.comments.leading, true),
+    (&script.comments.trailing, false),
+  ] {
+    for item in items {
+      let pos = BytePos(item.byte_pos.0 + offset);
+      let item_comments = item
+        .comment
+        .iter()
+        .cloned()
+        .map(|mut comment| {
+          span_offset.visit_mut_span(&mut comment.span);
+          comment
+        })
+        .collect();
+
+      if is_leading {
+        comments.add_leading_comments(pos, item_comments);
+      } else {
+        comments.add_trailing_comments(pos, item_comments);
+      }
+    }
+  }
+
+  (ast, marks)
+}
+
+struct SpanOffset {
+  offset: u32,
+}
+
+impl VisitMut for SpanOffset {
+  fn visit_mut_span(&mut self, span: &mut Span) {
+    if !span.is_dummy() {
+      span.lo = BytePos(span.lo.0 + self.offset);
+      span.hi = BytePos(span.hi.0 + self.offset);
+    }
+  }
+}
+
+struct ModuleConcatenator<'a> {
+  root: &'a ModuleId,
+  module_graph: &'a ModuleGraph,
+  group: HashSet<ModuleId>,
+  /// import bindings that refer to the modules of the group
+  bindings: HashMap<Id, BindingTarget>,
+  exports: HashMap<ModuleId, ModuleExports>,
+  concatenated_sources: HashMap<String, ModuleId>,
+}
+
+impl<'a> ModuleConcatenator<'a> {
+  /// Collect the exports and import bindings of the module, strip the import/export declarations that refer to the modules of the group.
+  /// The exports of the root module are kept so it is still rendered as an esm module.
+  fn analyze_module(&mut self, module_id: &ModuleId, ast: SwcModule) -> Option<Vec<ModuleItem>> {
+    let is_root = module_id == self.root;
+    let mut exports = ModuleExports::default();
+    let mut items = vec![];
+
+    for item in ast.body {
+      let module_decl = match item {
+        ModuleItem::Stmt(_) => {
+          items.push(item);
+          continue;
+        }
+        ModuleItem::ModuleDecl(module_decl) => module_decl,
+      };
+
+      match module_decl {
+        ModuleDecl::Import(mut import_decl) => {
+          let dep = self.resolve_dep(module_id, &import_decl.src.value)?;
+
+          if self.group.contains(&dep) {
+            for specifier in import_decl.specifiers {
+              match specifier {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                  local, imported, ..
+                }) => {
+                  let imported = imported
+                    .map(|name| module_export_name_sym(&name))
+                    .unwrap_or_else(|| local.sym.clone());
+                  self
+                    .bindings
+                    .insert(local.to_id(), BindingTarget::Export(dep.clone(), imported));
+                }
+                ImportSpecifier::Default(default) => {
+                  self.bindings.insert(
+                    default.local.to_id(),
+                    BindingTarget::Export(dep.clone(), "default".into()),
+                  );
+                }
+                ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                  self
+                    .bindings
+                    .insert(local.to_id(), BindingTarget::Namespace(dep.clone()));
+                }
+              }
+            }
+          } else {
+            if !is_root {
+              self.replace_source(&mut import_decl.src, dep);
+            }
+            items.push(ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)));
+          }
+        }
+        ModuleDecl::ExportDecl(export_decl) => {
+          for id in decl_ids(&export_decl.decl) {
+            exports
+              .exports
+              .insert(id.0.clone(), BindingTarget::Local(id));
+          }
+
+          if is_root {
+            items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)));
+          } else {
+            items.push(ModuleItem::Stmt(Stmt::Decl(export_decl.decl)));
+          }
+        }
+        ModuleDecl::ExportNamed(mut named_export) => {
+          let Some(src) = named_export.src.take() else {
+            for specifier in &named_export.specifiers {
+              let ExportSpecifier::Named(ExportNamedSpecifier {
+                orig: ModuleExportName::Ident(orig),
+                exported,
+                ..
+              }) = specifier
+              else {
+                return None;
+              };
+              let exported = exported
+                .as_ref()
+                .map(module_export_name_sym)
+                .unwrap_or_else(|| orig.sym.clone());
+              exports
+                .exports
+                .insert(exported, BindingTarget::Local(orig.to_id()));
+            }
+
+            if is_root {
+              items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+                named_export,
+              )));
+            }
+            continue;
+          };
+
+          let dep = self.resolve_dep(module_id, &src.value)?;
+          let in_group = self.group.contains(&dep);
+
+          if is_root && !in_group {
+            named_export.src = Some(src);
+            items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+              named_export,
+            )));
+            continue;
+          }
+
+          // `export { a as b } from './dep'` is transformed to a local binding that refers to `a` of dep
+          let mut local_specifiers = vec![];
+          let mut import_specifiers = vec![];
+
+          for specifier in named_export.specifiers {
+            let (exported, target, import_specifier) = match specifier {
+              ExportSpecifier::Named(ExportNamedSpecifier { orig, exported, .. }) => {
+                let orig = module_export_name_sym(&orig);
+                let exported = exported
+                  .as_ref()
+                  .map(module_export_name_sym)
+                  .unwrap_or_else(|| orig.clone());
+                let local = fresh_ident(&exported);
+                let import_specifier = ImportSpecifier::Named(ImportNamedSpecifier {
+                  span: DUMMY_SP,
+                  local: local.clone(),
+                  imported: Some(ModuleExportName::Ident(Ident::new(orig.clone(), DUMMY_SP))),
+                  is_type_only: false,
+                });
+
+                (
+                  exported,
+                  (local, BindingTarget::Export(dep.clone(), orig)),
+                  import_specifier,
+                )
+              }
+              ExportSpecifier::Namespace(namespace) => {
+                let exported = module_export_name_sym(&namespace.name);
+                let local = fresh_ident(&exported);
+                let import_specifier = ImportSpecifier::Namespace(ImportStarAsSpecifier {
+                  span: DUMMY_SP,
+                  local: local.clone(),
+                });
+
+                (
+                  exported,
+                  (local, BindingTarget::Namespace(dep.clone())),
+                  import_specifier,
+                )
+              }
+              ExportSpecifier::Default(_) => return None,
+            };
+            let (local, binding_target) = target;
+
+            if in_group {
+              self.bindings.insert(local.to_id(), binding_target);
+            } else {
+              import_specifiers.push(import_specifier);
+            }
+
+            exports
+              .exports
+              .insert(exported.clone(), BindingTarget::Local(local.to_id()));
+            local_specifiers.push((local, exported));
+          }
+
+          if !import_specifiers.is_empty() {
+            let mut src = src;
+            self.replace_source(&mut src, dep);
+            items.push(ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+              span: DUMMY_SP,
+              specifiers: import_specifiers,
+              src,
+              type_only: false,
+              with: None,
+              phase: Default::default(),
+            })));
+          }
+
+          if is_root {
+            items.push(export_locals(local_specifiers));
+          }
+        }
+        ModuleDecl::ExportAll(export_all) => {
+          let dep = self.resolve_dep(module_id, &export_all.src.value)?;
+
+          if self.group.contains(&dep) {
+            // the root re-exports the star exports of the hoisted module by names, see [Self::resolve_bindings]
+            exports.star_exports.push(dep);
+          } else if is_root {
+            items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export_all)));
+          } else {
+            return None;
+          }
+        }
+        ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { span, decl }) => {
+          let decl = match decl {
+            DefaultDecl::Fn(mut fn_expr) => {
+              let ident = fn_expr.ident.get_or_insert_with(|| fresh_ident("_default"));
+              exports
+                .exports
+                .insert("default".into(), BindingTarget::Local(ident.to_id()));
+              DefaultDecl::Fn(fn_expr)
+            }
+            DefaultDecl::Class(mut class_expr) => {
+              let ident = class_expr
+                .ident
+                .get_or_insert_with(|| fresh_ident("_default"));
+              exports
+                .exports
+                .insert("default".into(), BindingTarget::Local(ident.to_id()));
+              DefaultDecl::Class(class_expr)
+            }
+            DefaultDecl::TsInterfaceDecl(_) => continue,
+          };
+
+          if is_root {
+            items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+              ExportDefaultDecl { span, decl },
+            )));
+          } else {
+            items.push(ModuleItem::Stmt(Stmt::Decl(match decl {
+              DefaultDecl::Fn(fn_expr) => Decl::Fn(FnDecl {
+                ident: fn_expr.ident.unwrap(),
+                declare: false,
+                function: fn_expr.function,
+              }),
+              DefaultDecl::Class(class_expr) => Decl::Class(ClassDecl {
+                ident: class_expr.ident.unwrap(),
+                declare: false,
+                class: class_expr.class,
+              }),
+              DefaultDecl::TsInterfaceDecl(_) => unreachable!(),
+            })));
+          }
+        }
+        ModuleDecl::ExportDefaultExpr(export_default_expr) => {
+          // `export default expr` to `var _default = expr`
+          let local = fresh_ident("_default");
+          items.push(var_decl(local.clone(), export_default_expr.expr));
+          exports
+            .exports
+            .insert("default".into(), BindingTarget::Local(local.to_id()));
+
+          if is_root {
+            items.push(export_locals(vec![(local, "default".into())]));
+          }
+        }
+        _ => return None,
+      }
+    }
+
+    self.exports.insert(module_id.clone(), exports);
+
+    Some(items)
+  }
+
+  fn resolve_dep(&self, module_id: &ModuleId, source: &str) -> Option<ModuleId> {
+    [ResolveKind::Import, ResolveKind::ExportFrom]
+      .into_iter()
+      .find_map(|kind| {
+        self
+          .module_graph
+          .get_dep_by_source_optional(module_id, source, Some(kind))
+      })
+  }
+
+  fn replace_source(&mut self, src: &mut Box<Str>, dep: ModuleId) {
+    let source = dep.to_string();
+    **src = Str {
+      span: DUMMY_SP,
+      value: source.as_str().into(),
+      raw: None,
+    };
+    self.concatenated_sources.insert(source, dep);
+  }
+
+  /// Resolve every import binding of the group to the local binding it refers to, and expand the star exports of the root.
+  /// Return the rename map of the import bindings.
+  fn resolve_bindings(
+    &mut self,
+    items_of_modules: &mut [(ModuleId, Vec<ModuleItem>)],
+  ) -> Option<HashMap<Id, Ident>> {
+    let mut resolver = BindingResolver {
+      concatenator: self,
+      namespaces: HashMap::new(),
+    };
+    let mut rename_map = HashMap::new();
+
+    // the star exports of the root are exported by names
+    let root = resolver.concatenator.root.clone();
+    let mut root_star_exports = vec![];
+    let root_exports = &resolver.concatenator.exports[&root];
+    let mut exported_names = root_exports.exports.keys().cloned().collect::<HashSet<_>>();
+
+    for dep in root_exports.star_exports.clone() {
+      let mut names = resolver.export_names(&dep, &mut HashSet::new());
+      names.sort();
+
+      for name in names {
+        if name != "default" && exported_names.insert(name.clone()) {
+          let local = fresh_ident(&name);
+          resolver.concatenator.bindings.insert(
+            local.to_id(),
+            BindingTarget::Export(dep.clone(), name.clone()),
+          );
+          root_star_exports.push((local, name));
+        }
+      }
+    }
+
+    let bindings = resolver.concatenator.bindings.clone();
+
+    for (id, target) in bindings {
+      let resolved = resolver.resolve(target, &mut HashSet::new())?;
+      rename_map.insert(id, resolved);
+    }
+
+    let namespaces = std::mem::take(&mut resolver.namespaces);
+
+    for (module_id, items) in items_of_modules.iter_mut() {
+      if let Some((namespace, props)) = namespaces.get(module_id) {
+        items.push(var_decl(
+          namespace.clone(),
+          Box::new(Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: props.clone(),
+          })),
+        ));
+      }
+
+      if module_id == &root && !root_star_exports.is_empty() {
+        items.push(export_locals(root_star_exports.clone()));
+      }
+    }
+
+    Some(rename_map)
+  }
+}
+
+struct BindingResolver<'a, 'b> {
+  concatenator: &'b mut ModuleConcatenator<'a>,
+  /// module -> (namespace object ident, getters of the namespace object)
+  namespaces: HashMap<ModuleId, (Ident, Vec<PropOrSpread>)>,
+}
+
+impl<'a, 'b> BindingResolver<'a, 'b> {
+  fn resolve(
+    &mut self,
+    target: BindingTarget,
+    visited: &mut HashSet<(ModuleId, JsWord)>,
+  ) -> Option<Ident> {
+    match target {
+      BindingTarget::Local(id) => {
+        if let Some(target) = self.concatenator.bindings.get(&id).cloned() {
+          self.resolve(target, visited)
+        } else {
+          Some(Ident::new(id.0, DUMMY_SP.with_ctxt(id.1)))
+        }
+      }
+      BindingTarget::Export(module_id, name) => {
+        if !visited.insert((module_id.clone(), name.clone())) {
+          return None;
+        }
+
+        let exports = self.concatenator.exports.get(&module_id)?;
+
+        if let Some(target) = exports.exports.get(&name).cloned() {
+          return self.resolve(target, visited);
+        }
+
+        if name == "default" {
+          return None;
+        }
+
+        for dep in exports.star_exports.clone() {
+          let mut star_visited = visited.clone();
+
+          if let Some(resolved) = self.resolve(
+            BindingTarget::Export(dep.clone(), name.clone()),
+            &mut star_visited,
+          ) {
+            return Some(resolved);
+          }
+        }
+
+        None
+      }
+      BindingTarget::Namespace(module_id) => self.namespace(&module_id),
+    }
+  }
+
+  /// `import * as ns from './dep'` is transformed to an object with getters of all exports of dep
+  fn namespace(&mut self, module_id: &ModuleId) -> Option<Ident> {
+    if module_id == self.concatenator.root {
+      return None;
+    }
+
+    if let Some((namespace, _)) = self.namespaces.get(module_id) {
+      return Some(namespace.clone());
+    }
+
+    let namespace = fresh_ident("_namespace");
+    self
+      .namespaces
+      .insert(module_id.clone(), (namespace.clone(), vec![]));
+
+    let mut names = self.export_names(module_id, &mut HashSet::new());
+    names.sort();
+    let mut props = vec![];
+
+    for name in names {
+      let local = self.resolve(
+        BindingTarget::Export(module_id.clone(), name.clone()),
+        &mut HashSet::new(),
+      )?;
+      props.push(PropOrSpread::Prop(Box::new(Prop::Getter(GetterProp {
+        span: DUMMY_SP,
+        key: PropName::Str(Str {
+          span: DUMMY_SP,
+          value: name,
+          raw: None,
+        }),
+        type_ann: None,
+        body: Some(BlockStmt {
+          span: DUMMY_SP,
+          stmts: vec![Stmt::Return(ReturnStmt {
+            span: DUMMY_SP,
+            arg: Some(Box::new(Expr::Ident(local))),
+          })],
+        }),
+      }))));
+    }
+
+    self.namespaces.get_mut(module_id).unwrap().1 = props;
+
+    Some(namespace)
+  }
+
+  fn export_names(&self, module_id: &ModuleId, visited: &mut HashSet<ModuleId>) -> Vec<JsWord> {
+    if !visited.insert(module_id.clone()) {
+      return vec![];
+    }
+
+    let Some(exports) = self.concatenator.exports.get(module_id) else {
+      return vec![];
+    };
+    let mut names = exports.exports.keys().cloned().collect::<HashSet<_>>();
+
+    for dep in &exports.star_exports {
+      names.extend(
+        self
+          .export_names(dep, visited)
+          .into_iter()
+          .filter(|name| name != "default"),
+      );
+    }
+
+    names.into_iter().collect()
+  }
+}
+
+/// Map the unresolved and top level contexts of the hoisted modules to the contexts of the root.
+/// The top level bindings of the hoisted modules whose names are taken by the root or a previous module are renamed.
+fn merge_contexts_into_root(
+  ast: &SwcModule,
+  hoisted_marks: &[(Mark, Mark)],
+  (unresolved_mark, top_level_mark): (Mark, Mark),
+) -> HashMap<Id, Ident> {
+  let unresolved_ctxt = SyntaxContext::empty().apply_mark(unresolved_mark);
+  let top_level_ctxt = SyntaxContext::empty().apply_mark(top_level_mark);
+  let mut contexts = HashMap::new();
+
+  for (unresolved, top_level) in hoisted_marks {
+    contexts.insert(
+      SyntaxContext::empty().apply_mark(*unresolved),
+      unresolved_ctxt,
+    );
+    contexts.insert(
+      SyntaxContext::empty().apply_mark(*top_level),
+      top_level_ctxt,
+    );
+  }
+
+  let mut collector = IdCollector::default();
+  ast.visit_with(&mut collector);
+
+  let mut used_syms = collector
+    .ids
+    .iter()
+    .map(|(sym, _)| sym.clone())
+    .collect::<HashSet<_>>();
+  // top level names -> the context of the module that owns the name
+  let mut owners = collector
+    .ids
+    .iter()
+    .filter(|(_, ctxt)| *ctxt == top_level_ctxt)
+    .map(|(sym, ctxt)| (sym.clone(), *ctxt))
+    .collect::<HashMap<_, _>>();
+  let mut map = HashMap::new();
+
+  for (sym, ctxt) in collector.ids {
+    let Some(target_ctxt) = contexts.get(&ctxt) else {
+      continue;
+    };
+    let mut target_sym = sym.clone();
+
+    if *target_ctxt == top_level_ctxt && *owners.entry(sym.clone()).or_insert(ctxt) != ctxt {
+      let mut index = 1;
+
+      while used_syms.contains(&JsWord::from(format!("{sym}{index}"))) {
+        index += 1;
+      }
+
+      target_sym = format!("{sym}{index}").into();
+      used_syms.insert(target_sym.clone());
+    }
+
+    map.insert(
+      (sym, ctxt),
+      Ident::new(target_sym, DUMMY_SP.with_ctxt(*target_ctxt)),
+    );
+  }
+
+  map
+}
+
+/// All the identifiers of the ast in the order of their first appearance.
+#[derive(Default)]
+struct IdCollector {
+  ids: Vec<Id>,
+  visited: HashSet<Id>,
+}
+
+impl Visit for IdCollector {
+  fn visit_ident(&mut self, ident: &Ident) {
+    if self.visited.insert(ident.to_id()) {
+      self.ids.push(ident.to_id());
+    }
+  }
+}
+
+/// Rename the bindings to the bindings of the map, e.g. the import bindings to the local bindings they refer to.
+struct BindingRenamer<'a> {
+  map: &'a HashMap<Id, Ident>,
+}
+
+impl<'a> VisitMut for BindingRenamer<'a> {
+  fn visit_mut_ident(&mut self, ident: &mut Ident) {
+    if let Some(target) = self.map.get(&ident.to_id()) {
+      ident.sym = target.sym.clone();
+      ident.span.ctxt = target.span.ctxt;
+    }
+  }
+
+  fn visit_mut_prop(&mut self, prop: &mut Prop) {
+    // `{ a }` to `{ a: renamed_a }`
+    if let Prop::Shorthand(ident) = prop {
+      if self
+        .map
+        .get(&ident.to_id())
+        .is_some_and(|target| target.sym != ident.sym)
+      {
+        *prop = Prop::KeyValue(KeyValueProp {
+          key: PropName::Ident(Ident::new(ident.sym.clone(), DUMMY_SP)),
+          value: Box::new(Expr::Ident(ident.clone())),
+        });
+      }
+    }
+
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_export_named_specifier(&mut self, specifier: &mut ExportNamedSpecifier) {
+    // `export { a }` to `export { renamed_a as a }`
+    if let ModuleExportName::Ident(orig) = &mut specifier.orig {
+      if specifier.exported.is_none() && self.map.contains_key(&orig.to_id()) {
+        specifier.exported = Some(ModuleExportName::Ident(Ident::new(
+          orig.sym.clone(),
+          DUMMY_SP,
+        )));
+      }
+
+      self.visit_mut_ident(orig);
+    }
+  }
+
+  fn visit_mut_member_prop(&mut self, prop: &mut MemberProp) {
+    if let MemberProp::Computed(computed) = prop {
+      computed.visit_mut_with(self);
+    }
+  }
+
+  fn visit_mut_super_prop(&mut self, prop: &mut SuperProp) {
+    if let SuperProp::Computed(computed) = prop {
+      computed.visit_mut_with(self);
+    }
+  }
+
+  fn visit_mut_prop_name(&mut self, name: &mut PropName) {
+    if let PropName::Computed(computed) = name {
+      computed.visit_mut_with(self);
+    }
+  }
+}
+
+fn module_export_name_sym(name: &ModuleExportName) -> JsWord {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.clone(),
+    ModuleExportName::Str(str) => str.value.clone(),
+  }
+}
+
+fn decl_ids(decl: &Decl) -> Vec<Id> {
+  match decl {
+    Decl::Class(class_decl) => vec![class_decl.ident.to_id()],
+    Decl::Fn(fn_decl) => vec![fn_decl.ident.to_id()],
+    Decl::Var(var_decl) => find_pat_ids(&var_decl.decls),
+    _ => vec![],
+  }
+}
+
+/// A new binding that does not conflict with any other bindings, it is renamed by the hygiene pass if needed
+fn fresh_ident(sym: &str) -> Ident {
+  Ident::new(
+    sym.into(),
+    DUMMY_SP.with_ctxt(SyntaxContext::empty().apply_mark(Mark::new())),
+  )
+}
+
+fn var_decl(ident: Ident, init: Box<Expr>) -> ModuleItem {
+  ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+    span: DUMMY_SP,
+    kind: VarDeclKind::Var,
+    declare: false,
+    decls: vec![VarDeclarator {
+      span: DUMMY_SP,
+      name: Pat::Ident(BindingIdent {
+        id: ident,
+        type_ann: None,
+      }),
+      init: Some(init),
+      definite: false,
+    }],
+  }))))
+}
+
+/// `export { local as exported }`
+fn export_locals(locals: Vec<(Ident, JsWord)>) -> ModuleItem {
+  ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+    span: DUMMY_SP,
+    specifiers: locals
+      .into_iter()
+      .map(|(local, exported)| {
+        ExportSpecifier::Named(ExportNamedSpecifier {
+          span: DUMMY_SP,
+          orig: ModuleExportName::Ident(local),
+          exported: Some(ModuleExportName::Ident(Ident::new(exported, DUMMY_SP))),
+          is_type_only: false,
+        })
+      })
+      .collect(),
+    src: None,
+    type_only: false,
+    with: None,
+  }))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use farmfe_core::{
+    swc_common::{FilePathMapping, Globals, Mark, SourceMap, SyntaxContext, GLOBALS},
+    swc_ecma_ast::{EsVersion, Ident, Module},
+    swc_ecma_parser::Syntax,
+  };
+  use farmfe_toolkit::{
+    script::parse_module,
+    swc_ecma_transforms_base::resolver,
+    swc_ecma_visit::{Visit, VisitMutWith, VisitWith},
+  };
+
+  use super::{merge_contexts_into_root, BindingRenamer};
+
+  fn parse_and_resolve(code: &str) -> (Module, (Mark, Mark)) {
+    let mut ast = parse_module(
+      "id",
+      code,
+      Syntax::Es(Default::default()),
+      EsVersion::EsNext,
+    )
+    .unwrap()
+    .ast;
+    let marks = (Mark::new(), Mark::new());
+    ast.visit_mut_with(&mut resolver(marks.0, marks.1, false));
+
+    (ast, marks)
+  }
+
+  fn codegen(ast: &Module) -> String {
+    String::from_utf8(
+      farmfe_toolkit::script::codegen_module(
+        ast,
+        EsVersion::EsNext,
+        Arc::new(SourceMap::new(FilePathMapping::empty())),
+        None,
+        false,
+        None,
+      )
+      .unwrap(),
+    )
+    .unwrap()
+  }
+
+  struct IdentContexts(Vec<(String, SyntaxContext)>);
+
+  impl Visit for IdentContexts {
+    fn visit_ident(&mut self, ident: &Ident) {
+      self.0.push((ident.sym.to_string(), ident.span.ctxt));
+    }
+  }
+
+  #[test]
+  fn test_merge_contexts_into_root() {
+    GLOBALS.set(&Globals::new(), || {
+      let (hoisted, hoisted_marks) =
+        parse_and_resolve("const name = 'a'; export const a = () => name + window.location;");
+      let (root, root_marks) = parse_and_resolve("const name = 'root'; console.log(name);");
+
+      let mut ast = Module {
+        body: hoisted.body.into_iter().chain(root.body).collect(),
+        ..root
+      };
+      let map = merge_contexts_into_root(&ast, &[hoisted_marks], root_marks);
+      ast.visit_mut_with(&mut BindingRenamer { map: &map });
+
+      let unresolved_ctxt = SyntaxContext::empty().apply_mark(root_marks.0);
+      let top_level_ctxt = SyntaxContext::empty().apply_mark(root_marks.1);
+      let mut contexts = IdentContexts(vec![]);
+      ast.visit_with(&mut contexts);
+
+      assert_eq!(
+        contexts.0,
+        vec![
+          ("name1".to_string(), top_level_ctxt),
+          ("a".to_string(), top_level_ctxt),
+          ("name1".to_string(), top_level_ctxt),
+          ("window".to_string(), unresolved_ctxt),
+          ("location".to_string(), SyntaxContext::empty()),
+          ("name".to_string(), top_level_ctxt),
+          ("console".to_string(), unresolved_ctxt),
+          ("log".to_string(), SyntaxContext::empty()),
+          ("name".to_string(), top_level_ctxt),
+        ]
+      );
+      assert_eq!(
+        codegen(&ast),
+        r#"const name1 = 'a';
+export const a = ()=>name1 + window.location;
+const name = 'root';
+console.log(name);
+"#
+      );
+    });
+  }
+}
@@ -7,6 +7,8 @@
 //! const { b } = require("xxx"); // xxx is b's id.
 //! ```
 
+use std::collections::HashMap;
+
 use farmfe_core::{
   config::{Mode, FARM_DYNAMIC_REQUIRE, FARM_REQUIRE},
   module::{module_graph::ModuleGraph, ModuleId, ModuleType},
@@ -32,6 +34,8 @@ pub struct SourceReplacer<'a> {
   module_graph: &'a ModuleGraph,
   module_id: ModuleId,
   mode: Mode,
+  /// sources that are replaced by module ids when concatenating modules, see [super::scope_hoisting]
+  concatenated_sources: HashMap<String, ModuleId>,
   pub external_modules: Vec<String>,
 }
 
@@ -42,6 +46,7 @@ impl<'a> SourceReplacer<'a> {
     module_graph: &'a ModuleGraph,
     module_id: ModuleId,
     mode: Mode,
+    concatenated_sources: HashMap<String, ModuleId>,
   ) -> Self {
     Self {
       unresolved_mark,
@@ -49,6 +54,7 @@ impl<'a> SourceReplacer<'a> {
       module_graph,
       module_id,
       mode,
+      concatenated_sources,
       external_modules: vec![],
     }
   }
@@ -118,13 +124,17 @@ impl SourceReplacer<'_> {
           return SourceReplaceResult::NotReplaced;
         }
 
-        let mut id = None;
+        let mut id = self.concatenated_sources.get(&source).cloned();
         // treat non dynamic import as the same
         for kind in [
           ResolveKind::Import,
           ResolveKind::ExportFrom,
           ResolveKind::Require,
         ] {
+          if id.is_some() {
+            break;
+          }
+
           if let Some(dep_id) =
             self
               .module_graph
@@ -168,11 +178,17 @@ impl SourceReplacer<'_> {
       {
         let source = str.value.to_string();
 
-        let id = self.module_graph.get_dep_by_source(
-          &self.module_id,
-          &source,
-          Some(ResolveKind::DynamicImport),
-        );
+        let id = self
+          .concatenated_sources
+          .get(&source)
+          .cloned()
+          .unwrap_or_else(|| {
+            self.module_graph.get_dep_by_source(
+              &self.module_id,
+              &source,
+              Some(ResolveKind::DynamicImport),
+            )
+          });
         // only execute script module
         let dep_module = self.module_graph.module(&id).unwrap();
 
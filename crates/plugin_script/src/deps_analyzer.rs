@@ -1,15 +1,15 @@
 use farmfe_core::{
   module::ModuleId,
-  plugin::{PluginAnalyzeDepsHookResultEntry, ResolveKind},
+  plugin::{constants::RESOLVE_KIND_NEW_URL, PluginAnalyzeDepsHookResultEntry, ResolveKind},
   swc_common::Mark,
   swc_ecma_ast::{
-    CallExpr, ExportAll, Expr, Lit, Module, ModuleDecl, ModuleItem, NamedExport,
+    CallExpr, ExportAll, Expr, Lit, Module, ModuleDecl, ModuleItem, NamedExport, NewExpr,
     TsExternalModuleRef, TsImportEqualsDecl,
   },
 };
 
 use farmfe_toolkit::{
-  script::{is_commonjs_require, is_dynamic_import},
+  script::{get_new_url_with_import_meta_source, is_commonjs_require, is_dynamic_import},
   swc_ecma_visit::{Visit, VisitWith},
 };
 
@@ -122,4 +122,18 @@ impl<'a> Visit for DepsAnalyzer<'a> {
 
     call_expr.visit_children_with(self);
   }
+
+  fn visit_new_expr(&mut self, new_expr: &NewExpr) {
+    // new URL('./xxx.png', import.meta.url), urls like `https://xxx` or `data:xxx` are not dependencies
+    if let Some(str) = get_new_url_with_import_meta_source(self.unresolved_mark, new_expr) {
+      if !str.value.contains(':') {
+        self.insert_dep(PluginAnalyzeDepsHookResultEntry {
+          source: str.value.to_string(),
+          kind: ResolveKind::Custom(RESOLVE_KIND_NEW_URL.to_string()),
+        })
+      }
+    }
+
+    new_expr.visit_children_with(self);
+  }
 }
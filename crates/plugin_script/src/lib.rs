@@ -27,7 +27,9 @@ use farmfe_core::{
   swc_common::{Mark, GLOBALS},
   swc_ecma_ast::EsVersion,
 };
-use farmfe_swc_transformer_import_glob::transform_import_meta_glob;
+use farmfe_swc_transformer_import_glob::{
+  transform_dynamic_import_vars, transform_import_meta_glob,
+};
 use farmfe_toolkit::{
   common::{
     create_swc_source_map, generate_source_map_resource, load_source_original_source_map, Source,
@@ -44,16 +46,21 @@ use farmfe_toolkit::{
 use import_meta_visitor::ImportMetaVisitor;
 #[cfg(feature = "swc_plugin")]
 use swc_plugins::{init_plugin_module_cache_once, transform_by_swc_plugins};
+use worker::WorkerVisitor;
+pub use worker::{WorkerCompiler, WorkerModule};
 
 mod deps_analyzer;
 mod import_meta_visitor;
 #[cfg(feature = "swc_plugin")]
 mod swc_plugins;
 mod swc_script_transforms;
+mod worker;
 
 /// ScriptPlugin is used to support compiling js/ts/jsx/tsx/... files, support loading, parse, analyze dependencies and code generation.
 /// Note that we do not do transforms here, the transforms (e.g. strip types, jsx...) are handled in a separate plugin (farmfe_plugin_swc_transforms).
-pub struct FarmPluginScript {}
+pub struct FarmPluginScript {
+  worker_compiler: Option<WorkerCompiler>,
+}
 
 impl Plugin for FarmPluginScript {
   fn name(&self) -> &str {
@@ -188,6 +195,13 @@ impl Plugin for FarmPluginScript {
           .to_string()
       };
       transform_import_meta_glob(
+        ast,
+        context.config.root.clone(),
+        cur_dir.clone(),
+        &context.config.resolve.alias,
+      )?;
+      // transform `import(`./locales/${lang}.json`)` to a lookup map of all matched files
+      transform_dynamic_import_vars(
         ast,
         context.config.root.clone(),
         cur_dir,
@@ -195,6 +209,25 @@ impl Plugin for FarmPluginScript {
       )?;
     }
 
+    // compile `new Worker(new URL('./worker.ts', import.meta.url))` as a separate entry
+    if let Some(worker_compiler) = &self.worker_compiler {
+      let script = param.meta.as_script_mut();
+      let mut worker_visitor = WorkerVisitor::new(
+        param.module_id,
+        Mark::from_u32(script.unresolved_mark),
+        worker_compiler,
+        context,
+      );
+
+      GLOBALS.set(&context.meta.script.globals, || {
+        script.ast.visit_mut_with(&mut worker_visitor)
+      });
+
+      if let Some(error) = worker_visitor.error {
+        return Err(error);
+      }
+    }
+
     Ok(Some(()))
   }
 
@@ -244,7 +277,7 @@ impl Plugin for FarmPluginScript {
     // find and replace `import.meta.xxx` to `module.meta.xxx` and detect hmr_accepted
     // skip transform import.meta when targetEnv is node
     if matches!(context.config.output.target_env, TargetEnv::Browser)
-      || matches!(context.config.output.format, ModuleFormat::CommonJs)
+      || !matches!(context.config.output.format, ModuleFormat::EsModule)
     {
       // transform `import.meta.xxx` to `module.meta.xxx`
       let ast = &mut param.module.meta.as_script_mut().ast;
@@ -309,6 +342,14 @@ impl FarmPluginScript {
   pub fn new(config: &Config) -> Self {
     #[cfg(feature = "swc_plugin")]
     init_plugin_module_cache_once(config);
-    Self {}
+    Self {
+      worker_compiler: None,
+    }
+  }
+
+  /// Web workers are compiled by `worker_compiler` when it is set, otherwise `new Worker(...)` is left as is.
+  pub fn with_worker_compiler(mut self, worker_compiler: WorkerCompiler) -> Self {
+    self.worker_compiler = Some(worker_compiler);
+    self
   }
 }
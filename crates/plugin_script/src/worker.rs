@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use farmfe_core::{
+  context::CompilationContext,
+  error::{CompilationError, Result},
+  module::ModuleId,
+  plugin::{PluginResolveHookParam, ResolveKind},
+  swc_common::{Mark, DUMMY_SP},
+  swc_ecma_ast::{Expr, ExprOrSpread, Ident, Lit, NewExpr, Str},
+};
+use farmfe_toolkit::{
+  fs::public_url,
+  script::get_new_url_with_import_meta_source,
+  swc_ecma_visit::{VisitMut, VisitMutWith},
+};
+use farmfe_utils::stringify_query;
+
+/// A web worker found in a script module, e.g. `new Worker(new URL('./worker.ts', import.meta.url))`
+pub struct WorkerModule {
+  /// the module that creates the worker
+  pub importer: ModuleId,
+  /// resolved path of the worker entry, including the query
+  pub resolved_path: String,
+}
+
+/// Compile the worker as a separate entry and emit its resources, returns the name of the emitted worker entry resource.
+pub type WorkerCompiler =
+  Arc<dyn Fn(&WorkerModule, &Arc<CompilationContext>) -> Result<String> + Send + Sync>;
+
+/// Find `new Worker(new URL('./worker.ts', import.meta.url))` and `new SharedWorker(...)`,
+/// compile the worker with [WorkerCompiler] and replace the url expression with the public url of the emitted worker.
+pub struct WorkerVisitor<'a> {
+  module_id: &'a ModuleId,
+  unresolved_mark: Mark,
+  worker_compiler: &'a WorkerCompiler,
+  context: &'a Arc<CompilationContext>,
+  pub error: Option<CompilationError>,
+}
+
+impl<'a> WorkerVisitor<'a> {
+  pub fn new(
+    module_id: &'a ModuleId,
+    unresolved_mark: Mark,
+    worker_compiler: &'a WorkerCompiler,
+    context: &'a Arc<CompilationContext>,
+  ) -> Self {
+    Self {
+      module_id,
+      unresolved_mark,
+      worker_compiler,
+      context,
+      error: None,
+    }
+  }
+
+  fn compile_worker(&self, source: &str) -> Result<String> {
+    let resolve_result = self
+      .context
+      .plugin_driver
+      .resolve(
+        &PluginResolveHookParam {
+          source: source.to_string(),
+          importer: Some(self.module_id.clone()),
+          kind: ResolveKind::Custom("worker".to_string()),
+        },
+        self.context,
+        &Default::default(),
+      )?
+      .ok_or_else(|| CompilationError::ResolveError {
+        importer: self.module_id.to_string(),
+        src: source.to_string(),
+        source: None,
+      })?;
+
+    let worker = WorkerModule {
+      importer: self.module_id.clone(),
+      resolved_path: format!(
+        "{}{}",
+        resolve_result.resolved_path,
+        stringify_query(&resolve_result.query)
+      ),
+    };
+    let name = (self.worker_compiler)(&worker, self.context)?;
+
+    Ok(public_url(&self.context.config.output.public_path, &name))
+  }
+}
+
+impl<'a> VisitMut for WorkerVisitor<'a> {
+  fn visit_mut_new_expr(&mut self, new_expr: &mut NewExpr) {
+    new_expr.visit_mut_children_with(self);
+
+    if self.error.is_some() {
+      return;
+    }
+
+    let is_worker = matches!(
+      &new_expr.callee,
+      box Expr::Ident(Ident { span, sym, .. })
+        if span.ctxt.outer() == self.unresolved_mark && matches!(sym.as_ref(), "Worker" | "SharedWorker")
+    );
+
+    if !is_worker {
+      return;
+    }
+
+    let Some(ExprOrSpread {
+      spread: None,
+      expr: box Expr::New(url_expr),
+    }) = new_expr.args.as_mut().and_then(|args| args.first_mut())
+    else {
+      return;
+    };
+
+    let Some(source) = get_new_url_with_import_meta_source(self.unresolved_mark, url_expr)
+      .map(|s| s.value.to_string())
+    else {
+      return;
+    };
+
+    match self.compile_worker(&source) {
+      Ok(url) => {
+        new_expr.args.as_mut().unwrap()[0].expr = Box::new(Expr::Lit(Lit::Str(Str {
+          span: DUMMY_SP,
+          value: url.into(),
+          raw: None,
+        })));
+      }
+      Err(e) => self.error = Some(e),
+    }
+  }
+}
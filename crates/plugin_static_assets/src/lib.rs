@@ -1,3 +1,4 @@
+#![feature(box_patterns)]
 #![feature(path_file_prefix)]
 
 use std::{
@@ -9,21 +10,28 @@ use std::{
 use base64::engine::{general_purpose, Engine};
 use farmfe_core::{
   cache_item,
-  config::Config,
+  config::{Config, TargetEnv},
   context::{CompilationContext, EmitFileParams},
   deserialize,
   error::CompilationError,
-  module::ModuleType,
+  module::{ModuleId, ModuleType},
   // plugin::{constants::PLUGIN_BUILD_STAGE_META_RESOLVE_KIND, Plugin, ResolveKind},
-  plugin::{Plugin, PluginResolveHookResult},
+  plugin::{
+    constants::RESOLVE_KIND_NEW_URL, Plugin, PluginFinalizeModuleHookParam, PluginResolveHookParam,
+    PluginResolveHookResult, ResolveKind,
+  },
   relative_path::RelativePath,
   resource::{Resource, ResourceOrigin, ResourceType},
   rkyv::Deserialize,
   serialize,
+  swc_common::{Mark, DUMMY_SP, GLOBALS},
+  swc_ecma_ast::{Expr, Ident, Lit, MemberExpr, MemberProp, NewExpr, Str},
 };
 use farmfe_toolkit::{
-  fs::{read_file_raw, read_file_utf8, transform_output_filename},
+  fs::{public_url, read_file_raw, read_file_utf8, transform_output_filename},
   lazy_static::lazy_static,
+  script::get_new_url_with_import_meta_source,
+  swc_ecma_visit::{VisitMut, VisitMutWith},
 };
 use farmfe_utils::{hash::sha256, stringify_query};
 
@@ -63,6 +71,129 @@ impl FarmPluginStaticAssets {
         .any(|a| a.eq_ignore_ascii_case(ext))
   }
 
+  /// emit the asset file, the asset is inlined as a data url instead if it's smaller than `assets.inlineLimit`.
+  /// `?url` query forces the asset to be emitted
+  fn emit_asset(
+    resolved_path: &str,
+    query: &Vec<(String, String)>,
+    module_id: &str,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<EmittedAsset> {
+    let (asset, bytes) = Self::get_asset(resolved_path, query, module_id, context)?;
+
+    if let EmittedAsset::Resource(name) = &asset {
+      let ext = Path::new(resolved_path)
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap();
+
+      context.emit_file(EmitFileParams {
+        resolved_path: module_id.to_string(),
+        name: name.clone(),
+        content: bytes,
+        resource_type: ResourceType::Asset(ext.to_string()),
+      });
+    }
+
+    Ok(asset)
+  }
+
+  /// same as [Self::emit_asset] but the asset is not emitted, returns the asset and its content
+  fn get_asset(
+    resolved_path: &str,
+    query: &Vec<(String, String)>,
+    module_id: &str,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<(EmittedAsset, Vec<u8>)> {
+    let bytes = read_file_raw(resolved_path)?;
+
+    let ext = Path::new(resolved_path)
+      .extension()
+      .and_then(|s| s.to_str())
+      .unwrap();
+
+    if bytes.len() < context.config.assets.inline_limit && !query.iter().any(|(k, _)| k == "url") {
+      return Ok((EmittedAsset::DataUrl(to_data_url(&bytes, ext)), bytes));
+    }
+
+    let filename = Path::new(resolved_path)
+      .file_prefix()
+      .and_then(|s| s.to_str())
+      .unwrap();
+    let resource_name = transform_output_filename(
+      context.config.output.assets_filename.clone(),
+      filename,
+      &bytes,
+      ext,
+    ) + stringify_query(query).as_str();
+    let resource_name = Self::get_resource_name(&resource_name, module_id);
+
+    Ok((EmittedAsset::Resource(resource_name), bytes))
+  }
+
+  fn get_asset_url(resource_name: &str, context: &Arc<CompilationContext>) -> String {
+    public_url(&context.config.output.public_path, resource_name)
+  }
+
+  /// url of the asset that is referenced by `new URL('./xxx.png', import.meta.url)`, None if it's not a asset.
+  /// The url is relative to the output file when targeting node.
+  fn new_url_asset_url(
+    &self,
+    source: &str,
+    importer: &ModuleId,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<String>> {
+    let resolve_result = context.plugin_driver.resolve(
+      &PluginResolveHookParam {
+        source: source.to_string(),
+        importer: Some(importer.clone()),
+        kind: ResolveKind::Custom(RESOLVE_KIND_NEW_URL.to_string()),
+      },
+      context,
+      &Default::default(),
+    )?;
+
+    let Some(resolve_result) = resolve_result else {
+      return Ok(None);
+    };
+
+    if let Some(source) = resolve_result
+      .resolved_path
+      .strip_prefix(PUBLIC_ASSET_PREFIX)
+    {
+      return Ok(Some(public_asset_url(source, context)));
+    }
+
+    let extension = Path::new(&resolve_result.resolved_path)
+      .extension()
+      .and_then(|s| s.to_str());
+
+    match extension {
+      Some(ext) if self.is_asset(ext, context) && !is_asset_query(&resolve_result.query) => {
+        let module_id = ModuleId::new(
+          &resolve_result.resolved_path,
+          &stringify_query(&resolve_result.query),
+          &context.config.root,
+        );
+
+        // the asset is a dependency of the importer, it's emitted when the asset module is transformed
+        let (asset, _) = Self::get_asset(
+          &resolve_result.resolved_path,
+          &resolve_result.query,
+          &module_id.to_string(),
+          context,
+        )?;
+
+        Ok(Some(match (asset, context.config.output.target_env) {
+          (EmittedAsset::Resource(name), TargetEnv::Browser) => Self::get_asset_url(&name, context),
+          (EmittedAsset::Resource(name), TargetEnv::Node) => format!("./{name}"),
+          (EmittedAsset::DataUrl(url), _) => url,
+        }))
+      }
+      _ => Ok(None),
+    }
+  }
+
   fn get_resource_name(name: &str, module_id: &str) -> String {
     let last_dot = name.rfind('.').unwrap_or(0);
     if last_dot == 0 {
@@ -128,14 +259,9 @@ impl Plugin for FarmPluginStaticAssets {
   ) -> farmfe_core::error::Result<Option<farmfe_core::plugin::PluginLoadHookResult>> {
     let path = Path::new(param.resolved_path);
     let extension = path.extension().and_then(|s| s.to_str());
-    let public_path = context.config.output.public_path.clone();
 
     if let Some(source) = param.resolved_path.strip_prefix(PUBLIC_ASSET_PREFIX) {
-      // fix https://github.com/farm-fe/farm/issues/1165
-      let mut base_path = PathBuf::from(public_path);
-      base_path.push(source.trim_start_matches("/"));
-
-      let base_path_str = base_path.to_string_lossy().to_string();
+      let base_path_str = public_asset_url(source, context);
       return Ok(Some(farmfe_core::plugin::PluginLoadHookResult {
         content: format!("export default '{base_path_str}';"),
         module_type: ModuleType::Js,
@@ -161,20 +287,17 @@ impl Plugin for FarmPluginStaticAssets {
   ) -> farmfe_core::error::Result<Option<farmfe_core::plugin::PluginTransformHookResult>> {
     if matches!(param.module_type, ModuleType::Asset) {
       if param.query.iter().any(|(k, _)| k == "inline") {
-        let file_base64 = if param.content.is_empty() {
-          general_purpose::STANDARD.encode(read_file_raw(param.resolved_path)?)
-        } else {
-          param.content.clone()
-        };
         let path = Path::new(param.resolved_path);
         let ext = path.extension().and_then(|s| s.to_str()).unwrap();
-        let mime_type = mime_guess::from_ext(ext).first_or_octet_stream();
-        let mime_type_str = mime_type.to_string();
 
-        let content = format!(
-          "export default \"data:{};base64,{}\"",
-          mime_type_str, file_base64
-        );
+        let data_url = if param.content.is_empty() {
+          to_data_url(&read_file_raw(param.resolved_path)?, ext)
+        } else {
+          let mime_type = mime_guess::from_ext(ext).first_or_octet_stream();
+          format!("data:{};base64,{}", mime_type, param.content)
+        };
+
+        let content = format!("export default \"{}\"", data_url);
 
         return Ok(Some(farmfe_core::plugin::PluginTransformHookResult {
           content,
@@ -197,59 +320,19 @@ impl Plugin for FarmPluginStaticAssets {
           ignore_previous_source_map: false,
         }));
       } else {
-        let bytes = if param.content.is_empty() {
-          read_file_raw(param.resolved_path)?
-        } else {
+        if !param.content.is_empty() {
           // if content is not empty, it means the content is already read by the load hook in other plugins
           return Ok(None);
-        };
-
-        let ext = Path::new(param.resolved_path)
-          .extension()
-          .and_then(|s| s.to_str())
-          .unwrap();
-
-        let filename = Path::new(param.resolved_path)
-          .file_prefix()
-          .and_then(|s| s.to_str())
-          .unwrap();
-        let resource_name = transform_output_filename(
-          context.config.output.assets_filename.clone(),
-          filename,
-          &bytes,
-          ext,
-        ) + stringify_query(&param.query).as_str();
-        let resource_name = Self::get_resource_name(&resource_name, &param.module_id);
-
-        let content = if !context.config.output.public_path.is_empty() {
-          let normalized_public_path = context
-            .config
-            .output
-            .public_path
-            .trim_start_matches("/")
-            .trim_end_matches("/");
-
-          if normalized_public_path.is_empty() {
-            format!("export default \"/{}\"", resource_name)
-          } else {
-            format!(
-              "export default \"/{}/{}\"",
-              normalized_public_path, resource_name
-            )
-          }
-        } else {
-          format!("export default \"/{}\"", resource_name)
-        };
+        }
 
-        context.emit_file(EmitFileParams {
-          resolved_path: param.module_id.clone(),
-          name: resource_name,
-          content: bytes,
-          resource_type: ResourceType::Asset(ext.to_string()),
-        });
+        let url =
+          match Self::emit_asset(param.resolved_path, &param.query, &param.module_id, context)? {
+            EmittedAsset::Resource(name) => Self::get_asset_url(&name, context),
+            EmittedAsset::DataUrl(url) => url,
+          };
 
         return Ok(Some(farmfe_core::plugin::PluginTransformHookResult {
-          content,
+          content: format!("export default \"{}\"", url),
           module_type: Some(ModuleType::Js),
           source_map: None,
           ignore_previous_source_map: false,
@@ -260,6 +343,44 @@ impl Plugin for FarmPluginStaticAssets {
     Ok(None)
   }
 
+  /// rewrite `new URL('./xxx.png', import.meta.url)` to the public url of the emitted asset
+  fn finalize_module(
+    &self,
+    param: &mut PluginFinalizeModuleHookParam,
+    context: &Arc<CompilationContext>,
+  ) -> farmfe_core::error::Result<Option<()>> {
+    if !param.module.module_type.is_script() {
+      return Ok(None);
+    }
+
+    let mut urls = HashMap::new();
+
+    for dep in param.deps {
+      if matches!(&dep.kind, ResolveKind::Custom(kind) if kind == RESOLVE_KIND_NEW_URL) {
+        if let Some(url) = self.new_url_asset_url(&dep.source, &param.module.id, context)? {
+          urls.insert(dep.source.clone(), url);
+        }
+      }
+    }
+
+    if urls.is_empty() {
+      return Ok(None);
+    }
+
+    let script = param.module.meta.as_script_mut();
+    let unresolved_mark = Mark::from_u32(script.unresolved_mark);
+
+    GLOBALS.set(&context.meta.script.globals, || {
+      script.ast.visit_mut_with(&mut NewUrlReplacer {
+        urls,
+        unresolved_mark,
+        target_env: context.config.output.target_env,
+      });
+    });
+
+    Ok(Some(()))
+  }
+
   fn plugin_cache_loaded(
     &self,
     cache: &Vec<u8>,
@@ -306,6 +427,107 @@ impl Plugin for FarmPluginStaticAssets {
   }
 }
 
+enum EmittedAsset {
+  /// name of the emitted resource
+  Resource(String),
+  /// the asset is inlined as a data url
+  DataUrl(String),
+}
+
+/// svg is inlined as url encoded text which is smaller than base64, other assets are inlined as base64
+fn to_data_url(bytes: &[u8], ext: &str) -> String {
+  if ext.eq_ignore_ascii_case("svg") {
+    if let Ok(svg) = std::str::from_utf8(bytes) {
+      return format!("data:image/svg+xml,{}", encode_svg(svg));
+    }
+  }
+
+  let mime_type = mime_guess::from_ext(ext).first_or_octet_stream();
+  format!(
+    "data:{};base64,{}",
+    mime_type,
+    general_purpose::STANDARD.encode(bytes)
+  )
+}
+
+/// url encode the characters that are not allowed in a data url, and collapse whitespaces
+fn encode_svg(svg: &str) -> String {
+  let mut encoded = String::with_capacity(svg.len());
+  let mut last_is_whitespace = false;
+
+  for c in svg.trim().chars() {
+    if c.is_whitespace() {
+      if !last_is_whitespace {
+        encoded.push_str("%20");
+      }
+      last_is_whitespace = true;
+      continue;
+    }
+
+    last_is_whitespace = false;
+
+    match c {
+      '"' => encoded.push_str("%22"),
+      '%' => encoded.push_str("%25"),
+      '#' => encoded.push_str("%23"),
+      '<' => encoded.push_str("%3C"),
+      '>' => encoded.push_str("%3E"),
+      '\\' => encoded.push_str("%5C"),
+      _ => encoded.push(c),
+    }
+  }
+
+  encoded
+}
+
+/// url of the asset in `assets.publicDir`
+fn public_asset_url(source: &str, context: &Arc<CompilationContext>) -> String {
+  // fix https://github.com/farm-fe/farm/issues/1165
+  let mut base_path = PathBuf::from(&context.config.output.public_path);
+  base_path.push(source.trim_start_matches('/'));
+
+  base_path.to_string_lossy().to_string()
+}
+
+/// Replace `new URL('./xxx.png', import.meta.url)` with `new URL('/xxx-hash.png', location.href)`.
+/// `import.meta.url` is kept when targeting node, the emitted asset is referenced relative to the output file.
+struct NewUrlReplacer {
+  urls: HashMap<String, String>,
+  unresolved_mark: Mark,
+  target_env: TargetEnv,
+}
+
+impl VisitMut for NewUrlReplacer {
+  fn visit_mut_new_expr(&mut self, new_expr: &mut NewExpr) {
+    new_expr.visit_mut_children_with(self);
+
+    let Some(url) = get_new_url_with_import_meta_source(self.unresolved_mark, new_expr)
+      .and_then(|source| self.urls.get(source.value.as_ref()))
+    else {
+      return;
+    };
+    let args = new_expr.args.as_mut().unwrap();
+
+    args[0].expr = Box::new(Expr::Lit(Lit::Str(Str {
+      span: DUMMY_SP,
+      value: url.as_str().into(),
+      raw: None,
+    })));
+
+    if matches!(self.target_env, TargetEnv::Browser) {
+      // `import.meta.url` is not available in the farm runtime, resolve the url against the current location
+      args[1].expr = Box::new(Expr::Member(MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(Expr::Ident(Ident::new(
+          "location".into(),
+          DUMMY_SP.apply_mark(self.unresolved_mark),
+        ))),
+        prop: MemberProp::Ident(Ident::new("href".into(), DUMMY_SP)),
+      }));
+    }
+  }
+}
+
 #[cache_item]
 struct CachedStaticAssets {
   list: Vec<Resource>,
@@ -1,15 +1,24 @@
 #![feature(box_patterns)]
 #![feature(exact_size_is_empty)]
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::{
+  collections::{HashMap, HashSet, VecDeque},
+  path::PathBuf,
+  sync::Arc,
+};
 
 use farmfe_core::{
   config::{Config, Mode},
-  module::{module_graph::ModuleGraph, ModuleId},
+  context::CompilationContext,
+  module::{module_graph::ModuleGraph, Module, ModuleId},
   plugin::{Plugin, ResolveKind},
   rayon::iter::{IntoParallelIterator, ParallelIterator},
+  swc_ecma_ast::{ModuleDecl, ModuleItem},
+};
+use farmfe_toolkit::{
+  resolve::{load_package_json, package_json_loader::Options},
+  script::swc_try_with::resolve_module_mark,
 };
-use farmfe_toolkit::script::swc_try_with::resolve_module_mark;
 use module::TreeShakeModule;
 use statement_graph::{ExportInfo, ImportInfo};
 
@@ -209,11 +218,18 @@ impl Plugin for FarmPluginTreeShake {
         module.meta.as_script_mut().top_level_mark = top_level_mark.as_u32();
       });
 
+    // modules that are marked as side effects free by the `sideEffects` field of package.json
+    let mut side_effects_free_modules = HashSet::new();
+
     module_graph.modules().iter().for_each(|module| {
       if !module.module_type.is_script() || module.external {
         return;
       }
 
+      if is_side_effects_free_by_package_json(module, context) {
+        side_effects_free_modules.insert(module.id.clone());
+      }
+
       let tree_shake_module = module::TreeShakeModule::new(module);
       tree_shake_modules_map.insert(module.id.clone(), tree_shake_module);
     });
@@ -318,6 +334,7 @@ impl Plugin for FarmPluginTreeShake {
               &*module_graph,
               &tree_shake_module_id,
               import_info,
+              &side_effects_free_modules,
             );
           }
 
@@ -523,6 +540,7 @@ impl Plugin for FarmPluginTreeShake {
               &*module_graph,
               &tree_shake_module_id,
               &import_info,
+              &side_effects_free_modules,
             );
           }
 
@@ -562,6 +580,11 @@ impl Plugin for FarmPluginTreeShake {
 
     // remove the unused modules
     for module_id in modules_to_remove {
+      // side effects free modules may be removed while they are still imported by `import './foo'`
+      if side_effects_free_modules.contains(&module_id) {
+        remove_side_effects_imports(module_graph, &module_id);
+      }
+
       module_graph.remove_module(&module_id);
     }
     // if production remove useless hot update statements
@@ -579,6 +602,7 @@ fn add_used_exports_by_import_info(
   module_graph: &ModuleGraph,
   tree_shake_module_id: &ModuleId,
   import_info: &ImportInfo,
+  side_effects_free_modules: &HashSet<ModuleId>,
 ) {
   let imported_module_id = module_graph.get_dep_by_source(
     tree_shake_module_id,
@@ -598,7 +622,12 @@ fn add_used_exports_by_import_info(
     });
 
   if import_info.is_import_executed {
-    imported_tree_shake_module.is_self_executed_import = true;
+    // `import './foo'` can be skipped if foo is side effects free
+    if imported_tree_shake_module.side_effects
+      || !side_effects_free_modules.contains(&imported_module_id)
+    {
+      imported_tree_shake_module.is_self_executed_import = true;
+    }
     return;
   }
 
@@ -912,3 +941,48 @@ fn strip_context(ident: &String) -> String {
   let ident_split = ident.split('#').collect::<Vec<_>>();
   ident_split[0].to_string()
 }
+
+/// the module's package.json declares `sideEffects` and the module is not matched
+fn is_side_effects_free_by_package_json(
+  module: &Module,
+  context: &Arc<CompilationContext>,
+) -> bool {
+  if module.side_effects {
+    return false;
+  }
+
+  load_package_json(
+    PathBuf::from(module.id.resolved_path(&context.config.root)),
+    Options {
+      follow_symlinks: context.config.resolve.symlinks,
+      resolve_ancestor_dir: true,
+    },
+  )
+  .is_ok_and(|info| info.declares_side_effects())
+}
+
+/// remove `import './foo'` of the removed module from its importers
+fn remove_side_effects_imports(module_graph: &mut ModuleGraph, module_id: &ModuleId) {
+  for importer_id in module_graph.dependents_ids(module_id) {
+    let sources = module_graph
+      .edge_info(&importer_id, module_id)
+      .map(|edge| {
+        edge
+          .items()
+          .iter()
+          .map(|item| item.source.clone())
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default();
+    let importer = module_graph.module_mut(&importer_id).unwrap();
+
+    if !importer.module_type.is_script() {
+      continue;
+    }
+
+    importer.meta.as_script_mut().ast.body.retain(|item| {
+      !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl))
+        if import_decl.specifiers.is_empty() && sources.contains(&import_decl.src.value.to_string()))
+    });
+  }
+}
@@ -13,6 +13,7 @@ use farmfe_toolkit::script::swc_try_with::try_with;
 
 use crate::statement_graph::{
   module_analyze::{ItemId, ItemIdType, Mode, ModuleAnalyze, ModuleAnalyzeItemEdge},
+  pure_annotations::PureAnnotations,
   ExportInfo, ExportSpecifierInfo, ImportInfo, StatementGraph, StatementId,
 };
 
@@ -152,7 +153,8 @@ impl TreeShakeModule {
     // 1. generate statement graph
     let ast = &module.meta.as_script().ast;
     let stmt_graph = if module_system == ModuleSystem::EsModule {
-      StatementGraph::new(ast)
+      let pure_annotations = PureAnnotations::new(ast, &module.meta.as_script().comments);
+      StatementGraph::new(ast, &pure_annotations)
     } else {
       StatementGraph::empty()
     };
@@ -10,9 +10,11 @@ use farmfe_core::{
 pub(crate) mod analyze_imports_and_exports;
 pub(crate) mod defined_idents_collector;
 pub(crate) mod module_analyze;
+pub mod pure_annotations;
 pub(crate) mod used_idents_collector;
 
 use analyze_imports_and_exports::analyze_imports_and_exports;
+use pure_annotations::PureAnnotations;
 
 use crate::module::UsedIdent;
 
@@ -127,12 +129,18 @@ pub struct StatementGraph {
 }
 
 impl StatementGraph {
-  pub fn new(module: &SwcModule) -> Self {
+  pub fn new(module: &SwcModule, pure_annotations: &PureAnnotations) -> Self {
     let mut g = petgraph::graph::Graph::new();
     let mut id_index_map = HashMap::new();
 
     for (index, stmt) in module.body.iter().enumerate() {
-      let node = g.add_node(Statement::new(index, stmt));
+      let mut statement = Statement::new(index, stmt);
+      // `/*#__PURE__*/ foo()` can be removed if it's not used
+      if statement.is_self_executed && pure_annotations.is_pure_stmt(stmt) {
+        statement.is_self_executed = false;
+      }
+
+      let node = g.add_node(statement);
       id_index_map.insert(index, node);
     }
 
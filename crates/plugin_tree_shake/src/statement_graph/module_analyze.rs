@@ -6,8 +6,8 @@ use farmfe_core::petgraph::visit::EdgeRef;
 use farmfe_core::petgraph::Direction::Outgoing;
 use farmfe_core::swc_common::Mark;
 use farmfe_core::swc_ecma_ast::{
-  op, AssignTarget, BlockStmtOrExpr, Class, Decl, DefaultDecl, ExportDecl, ExportDefaultDecl, Expr,
-  ExprStmt, Id, ImportSpecifier, MemberProp, ModuleDecl, PropName, Stmt,
+  op, AssignTarget, BlockStmtOrExpr, CallExpr, Class, Decl, DefaultDecl, ExportDecl,
+  ExportDefaultDecl, Expr, ExprStmt, Id, ImportSpecifier, MemberProp, ModuleDecl, PropName, Stmt,
 };
 use farmfe_core::{
   petgraph::{self, stable_graph::NodeIndex},
@@ -16,6 +16,8 @@ use farmfe_core::{
 use farmfe_toolkit::swc_ecma_utils::find_pat_ids;
 use farmfe_toolkit::swc_ecma_visit::{Visit, VisitWith};
 
+use super::pure_annotations::PureAnnotations;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ItemId {
   Item { index: usize, kind: ItemIdType },
@@ -47,8 +49,8 @@ pub struct ItemData {
   pub side_effect_call: Vec<Id>,
 }
 
-fn class_collection(class: &Class) -> ItemData {
-  let collect = collect_all_usage(class, None);
+fn class_collection(class: &Class, pure_annotations: &PureAnnotations) -> ItemData {
+  let collect = collect_all_usage(class, None, pure_annotations);
 
   let data = ItemData {
     var_decls: vec![],
@@ -129,6 +131,7 @@ impl ModuleAnalyze {
 
   pub fn analyze(module: &Module) -> (Vec<ItemId>, HashMap<ItemId, ItemData>) {
     let ast = &module.meta.as_script().ast;
+    let pure_annotations = PureAnnotations::new(ast, &module.meta.as_script().comments);
 
     // let mut graph = petgraph::stable_graph::StableGraph::new();
     let mut items_map = HashMap::new();
@@ -165,7 +168,7 @@ impl ModuleAnalyze {
         | ModuleItem::Stmt(Stmt::Decl(Decl::Fn(f))) => {
           let id = f.ident.to_id();
 
-          let collect = collect_all_usage(&f.function, None);
+          let collect = collect_all_usage(&f.function, None, &pure_annotations);
 
           let is_side_effect_fn = collect
             .vars
@@ -200,7 +203,7 @@ impl ModuleAnalyze {
           for (i, decl) in vars.decls.iter().enumerate() {
             let decl_ids: Vec<Id> = find_pat_ids(&decl.name);
 
-            let collect_ident = collect_all_usage(&decl.init, None);
+            let collect_ident = collect_all_usage(&decl.init, None, &pure_annotations);
 
             let data = ItemData {
               var_decls: decl_ids.clone(),
@@ -228,7 +231,7 @@ impl ModuleAnalyze {
         })) => {
           let id = f.ident.clone().map(|ident| ident.to_id());
 
-          let collect = collect_all_usage(&f.function, None);
+          let collect = collect_all_usage(&f.function, None, &pure_annotations);
 
           let is_side_effect_fn = collect
             .vars
@@ -257,7 +260,7 @@ impl ModuleAnalyze {
           decl: DefaultDecl::Class(class),
           ..
         })) => {
-          let mut data = class_collection(&class.class);
+          let mut data = class_collection(&class.class, &pure_annotations);
 
           if let Some(ident) = &class.ident {
             data.var_decls.push(ident.to_id());
@@ -279,7 +282,7 @@ impl ModuleAnalyze {
           ..
         })) => {
           let id = class.ident.to_id();
-          let mut data = class_collection(&class.class);
+          let mut data = class_collection(&class.class, &pure_annotations);
 
           data.var_decls.push(id);
 
@@ -313,7 +316,7 @@ impl ModuleAnalyze {
           expr: box Expr::Assign(assign),
           ..
         })) => {
-          let mut used_ident = collect_all_usage(stmt, None);
+          let mut used_ident = collect_all_usage(stmt, None, &pure_annotations);
 
           if assign.op != op!("=") {
             let extra_ids = collect_usage_ignore_nested(&assign.left, None);
@@ -347,7 +350,7 @@ impl ModuleAnalyze {
         }
 
         _ => {
-          let used_ids = collect_all_usage(stmt, None);
+          let used_ids = collect_all_usage(stmt, None, &pure_annotations);
 
           let data = ItemData {
             read_vars: used_ids.vars.read,
@@ -566,9 +569,9 @@ struct Vars {
   nested_write: Vec<Id>,
 }
 
-fn collect_usage_ignore_nested<N>(n: &N, c: Option<Mode>) -> CollectIdent
+fn collect_usage_ignore_nested<N>(n: &N, c: Option<Mode>) -> CollectIdent<'static>
 where
-  N: VisitWith<CollectIdent>,
+  N: for<'a> VisitWith<CollectIdent<'a>>,
 {
   let mut v = CollectIdent {
     ignore_nested: true,
@@ -588,9 +591,13 @@ where
   v
 }
 
-fn collect_all_usage<N>(n: &N, c: Option<Mode>) -> CollectIdent
+fn collect_all_usage<'a, N>(
+  n: &N,
+  c: Option<Mode>,
+  pure_annotations: &'a PureAnnotations,
+) -> CollectIdent<'a>
 where
-  N: VisitWith<CollectIdent>,
+  N: VisitWith<CollectIdent<'a>>,
 {
   let mut v = CollectIdent {
     ignore_nested: false,
@@ -602,6 +609,7 @@ where
     },
     mode: c.unwrap_or(Mode::Write),
     enforce: None,
+    pure_annotations: Some(pure_annotations),
     ..Default::default()
   };
 
@@ -611,7 +619,7 @@ where
 }
 
 #[derive(Debug, Default)]
-struct CollectIdent {
+struct CollectIdent<'a> {
   ignore_nested: bool,
   pub vars: Vars,
   mode: Mode,
@@ -619,9 +627,11 @@ struct CollectIdent {
   call: bool,
   call_reads: Vec<Id>,
   nested: bool,
+  /// calls annotated with `#__PURE__` are treated as nested, so they are not side effects
+  pure_annotations: Option<&'a PureAnnotations>,
 }
 
-impl CollectIdent {
+impl<'a> CollectIdent<'a> {
   fn with_mode<F>(&mut self, mode: Mode, f: F)
   where
     F: FnOnce(&mut Self),
@@ -666,7 +676,7 @@ impl CollectIdent {
     self.nested = prev;
   }
 }
-impl Visit for CollectIdent {
+impl<'a> Visit for CollectIdent<'a> {
   fn visit_block_stmt_or_expr(&mut self, n: &BlockStmtOrExpr) {
     if self.ignore_nested {
       return;
@@ -751,7 +761,20 @@ impl Visit for CollectIdent {
     }
   }
 
+  fn visit_call_expr(&mut self, n: &CallExpr) {
+    if self.pure_annotations.is_some_and(|p| p.is_pure_call(n)) {
+      self.with_nested(|this| n.callee.visit_with(this));
+      n.args.visit_with(self);
+    } else {
+      n.visit_children_with(self);
+    }
+  }
+
   fn visit_new_expr(&mut self, n: &farmfe_core::swc_ecma_ast::NewExpr) {
-    self.with_call(|this| n.callee.visit_children_with(this))
+    if self.pure_annotations.is_some_and(|p| p.is_pure_new(n)) {
+      self.with_nested(|this| this.with_call(|this| n.callee.visit_children_with(this)))
+    } else {
+      self.with_call(|this| n.callee.visit_children_with(this))
+    }
   }
 }
@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use farmfe_core::{
+  module::CommentsMetaData,
+  swc_common::{
+    comments::{Comment, CommentKind},
+    BytePos, Spanned,
+  },
+  swc_ecma_ast::{
+    CallExpr, Callee, Decl, ExportDecl, Expr, Id, Module as SwcModule, ModuleDecl, ModuleItem,
+    NewExpr, Stmt,
+  },
+};
+
+const PURE: &str = "PURE";
+const NO_SIDE_EFFECTS: &str = "NO_SIDE_EFFECTS";
+
+/// `/*#__PURE__*/` or `/*@__PURE__*/` annotated calls and `/*#__NO_SIDE_EFFECTS__*/` annotated functions of a module.
+/// Calling them is treated as side effects free, so the statement can be removed if its result is not used.
+#[derive(Debug, Default)]
+pub struct PureAnnotations {
+  /// start positions of the `#__PURE__` annotated call expressions
+  pure_calls: HashSet<BytePos>,
+  /// top level functions annotated with `#__NO_SIDE_EFFECTS__`
+  no_side_effects_fns: HashSet<Id>,
+}
+
+impl PureAnnotations {
+  pub fn new(ast: &SwcModule, comments: &CommentsMetaData) -> Self {
+    let flagged = |flag: &str| {
+      comments
+        .leading
+        .iter()
+        .filter(|item| item.comment.iter().any(|c| is_flag_comment(c, flag)))
+        .map(|item| item.byte_pos)
+        .collect::<HashSet<_>>()
+    };
+
+    let pure_calls = flagged(PURE);
+    let no_side_effects = flagged(NO_SIDE_EFFECTS);
+
+    if no_side_effects.is_empty() {
+      return Self {
+        pure_calls,
+        no_side_effects_fns: HashSet::new(),
+      };
+    }
+
+    let mut no_side_effects_fns = HashSet::new();
+
+    for item in &ast.body {
+      let (decl, item_annotated) = match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, span })) => {
+          (decl, no_side_effects.contains(&span.lo))
+        }
+        ModuleItem::Stmt(Stmt::Decl(decl)) => (decl, false),
+        _ => continue,
+      };
+
+      match decl {
+        // /*#__NO_SIDE_EFFECTS__*/ function foo() {}
+        Decl::Fn(fn_decl) => {
+          if item_annotated || no_side_effects.contains(&fn_decl.function.span.lo) {
+            no_side_effects_fns.insert(fn_decl.ident.to_id());
+          }
+        }
+        // /*#__NO_SIDE_EFFECTS__*/ const foo = () => {}
+        Decl::Var(var_decl) => {
+          let var_annotated = item_annotated || no_side_effects.contains(&var_decl.span.lo);
+
+          for decl in &var_decl.decls {
+            let (Some(ident), Some(init)) = (decl.name.as_ident(), &decl.init) else {
+              continue;
+            };
+
+            if matches!(&**init, Expr::Arrow(_) | Expr::Fn(_))
+              && (var_annotated || no_side_effects.contains(&init.span_lo()))
+            {
+              no_side_effects_fns.insert(ident.to_id());
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+
+    Self {
+      pure_calls,
+      no_side_effects_fns,
+    }
+  }
+
+  pub fn is_pure_call(&self, call: &CallExpr) -> bool {
+    self.pure_calls.contains(&call.span.lo)
+      || matches!(&call.callee, Callee::Expr(box Expr::Ident(ident)) if self.no_side_effects_fns.contains(&ident.to_id()))
+  }
+
+  pub fn is_pure_new(&self, new: &NewExpr) -> bool {
+    self.pure_calls.contains(&new.span.lo)
+      || matches!(&*new.callee, Expr::Ident(ident) if self.no_side_effects_fns.contains(&ident.to_id()))
+  }
+
+  /// whether the statement is a pure call like `/*#__PURE__*/ foo()`, which should not be treated as self executed
+  pub fn is_pure_stmt(&self, stmt: &ModuleItem) -> bool {
+    let ModuleItem::Stmt(Stmt::Expr(expr_stmt)) = stmt else {
+      return false;
+    };
+    let mut expr = &*expr_stmt.expr;
+
+    while let Expr::Paren(paren) = expr {
+      expr = &paren.expr;
+    }
+
+    match expr {
+      Expr::Call(call) => self.is_pure_call(call),
+      Expr::New(new) => self.is_pure_new(new),
+      _ => false,
+    }
+  }
+}
+
+/// the same as the flag checking of swc, `#__PURE__` and `@__PURE__` are both supported
+fn is_flag_comment(comment: &Comment, flag: &str) -> bool {
+  comment.kind == CommentKind::Block
+    && comment.text.lines().any(|line| {
+      let line = line.trim_start_matches(['*', ' ']).trim();
+
+      (line.starts_with("#__") || line.starts_with("@__"))
+        && line.ends_with("__")
+        && line.len() == flag.len() + 5
+        && &line[3..line.len() - 2] == flag
+    })
+}
@@ -31,7 +31,7 @@ export { a, b, c as d };"#;
   GLOBALS.set(&Globals::new(), || {
     let (ast, _) = parse_module(code);
 
-    let stmt_graph = StatementGraph::new(&ast);
+    let stmt_graph = StatementGraph::new(&ast, &Default::default());
     assert_eq!(stmt_graph.stmts().len(), 7);
     let mut edges = stmt_graph.edges();
     edges.sort_by(|a, b| {
@@ -0,0 +1,209 @@
+//! Transform dynamic import with template literal or string concatenation specifiers like `@rollup/plugin-dynamic-import-vars`.
+//! for example:
+//! ```js
+//! import(`./locales/${lang}.json`)
+//! ```
+//! will be transformed to:
+//! ```js
+//! (function(modules, path) {
+//!   var load = modules[path];
+//!   return load ? load() : Promise.reject(new Error("Unknown variable dynamic import: " + path));
+//! })({
+//!   './locales/en.json': () => import('./locales/en.json'),
+//!   './locales/zh.json': () => import('./locales/zh.json'),
+//! }, `./locales/${lang}.json`)
+//! ```
+//! so every matched file is a dynamic dependency of the module.
+use std::collections::HashMap;
+
+use farmfe_core::swc_common::{BytePos, DUMMY_SP};
+use farmfe_core::swc_ecma_ast::{
+  self, BinExpr, BinaryOp, CallExpr, Callee, Expr, ExprOrSpread, KeyValueProp, Lit,
+  Module as SwcModule, ObjectLit, Prop, PropOrSpread, Tpl,
+};
+use farmfe_toolkit::script::is_dynamic_import;
+use farmfe_toolkit::swc_ecma_parser::{Parser, StringInput, Syntax};
+use farmfe_toolkit::swc_ecma_utils::DropSpan;
+use farmfe_toolkit::swc_ecma_visit::{VisitMut, VisitMutWith};
+
+use crate::ImportGlobVisitor;
+
+const DYNAMIC_IMPORT_HELPER: &str = r#"(function(modules, path) {
+  var load = modules[path];
+  return load ? load() : Promise.reject(new Error("Unknown variable dynamic import: " + path));
+})"#;
+
+pub fn transform_dynamic_import_vars(
+  ast: &mut SwcModule,
+  root: String,
+  cur_dir: String,
+  alias: &HashMap<String, String>,
+) -> farmfe_core::error::Result<()> {
+  let mut visitor = DynamicImportVarsVisitor {
+    glob_visitor: ImportGlobVisitor::new(cur_dir, root, alias),
+  };
+  ast.visit_mut_with(&mut visitor);
+
+  if !visitor.glob_visitor.errors.is_empty() {
+    return Err(farmfe_core::error::CompilationError::GenericError(
+      visitor.glob_visitor.errors.join("\n"),
+    ));
+  }
+
+  Ok(())
+}
+
+struct DynamicImportVarsVisitor<'a> {
+  glob_visitor: ImportGlobVisitor<'a>,
+}
+
+impl<'a> DynamicImportVarsVisitor<'a> {
+  /// create the lookup map of `import(`./locales/${lang}.json`)`
+  fn create_lookup_map(&mut self, pattern: &str) -> ObjectLit {
+    let mut globed_paths = self
+      .glob_visitor
+      .glob_and_filter_sources(&vec![pattern.to_string()])
+      .into_iter()
+      .collect::<Vec<_>>();
+    globed_paths.sort();
+
+    let props = globed_paths
+      .into_iter()
+      .map(|(relative_file, source)| {
+        let (key, value) = self
+          .glob_visitor
+          .deal_with_non_eager(&relative_file, &source, &None);
+
+        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+          key: swc_ecma_ast::PropName::Str(swc_ecma_ast::Str {
+            span: DUMMY_SP,
+            value: key.into(),
+            raw: None,
+          }),
+          value,
+        })))
+      })
+      .collect();
+
+    ObjectLit {
+      span: DUMMY_SP,
+      props,
+    }
+  }
+}
+
+impl<'a> VisitMut for DynamicImportVarsVisitor<'a> {
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    expr.visit_mut_children_with(self);
+
+    let Expr::Call(call_expr) = expr else {
+      return;
+    };
+
+    if !is_dynamic_import(call_expr) || call_expr.args.len() != 1 {
+      return;
+    }
+
+    let ExprOrSpread {
+      spread: None,
+      expr: arg,
+    } = &call_expr.args[0]
+    else {
+      return;
+    };
+
+    if matches!(&**arg, Expr::Lit(Lit::Str(_))) {
+      return;
+    }
+
+    let Some(pattern) = to_glob_pattern(arg) else {
+      return;
+    };
+
+    let lookup_map = self.create_lookup_map(&pattern);
+
+    *expr = Expr::Call(CallExpr {
+      span: call_expr.span,
+      callee: Callee::Expr(create_helper()),
+      args: vec![
+        ExprOrSpread {
+          spread: None,
+          expr: Box::new(Expr::Object(lookup_map)),
+        },
+        call_expr.args.remove(0),
+      ],
+      type_args: None,
+    });
+  }
+}
+
+/// Transform the specifier to glob pattern, the dynamic parts are replaced by `*`.
+/// Return None if the specifier can not be analyzed statically, e.g. it's not relative or it may match the module itself.
+fn to_glob_pattern(expr: &Expr) -> Option<String> {
+  let mut pattern = String::new();
+  collect_pattern_parts(expr, &mut pattern)?;
+
+  // `*` never matches a directory recursively
+  while pattern.contains("**") {
+    pattern = pattern.replace("**", "*");
+  }
+
+  // query and hash can not be globed
+  if pattern.contains(['?', '#']) {
+    return None;
+  }
+
+  let file_pattern = pattern.strip_prefix("./");
+
+  if !pattern.starts_with("../") && file_pattern.is_none() {
+    return None;
+  }
+
+  // `./${name}` may import the module itself, a file name pattern is required like `./locale-${name}.js`
+  if file_pattern.is_some_and(|p| p.starts_with('*') && !p.contains('/')) {
+    return None;
+  }
+
+  Some(pattern)
+}
+
+fn collect_pattern_parts(expr: &Expr, pattern: &mut String) -> Option<()> {
+  match expr {
+    Expr::Lit(Lit::Str(str)) => pattern.push_str(&str.value),
+    Expr::Tpl(Tpl { quasis, .. }) => {
+      for (i, quasi) in quasis.iter().enumerate() {
+        if i > 0 {
+          pattern.push('*');
+        }
+
+        pattern.push_str(quasi.cooked.as_deref()?);
+      }
+    }
+    Expr::Bin(BinExpr {
+      op: BinaryOp::Add,
+      left,
+      right,
+      ..
+    }) => {
+      collect_pattern_parts(left, pattern)?;
+      collect_pattern_parts(right, pattern)?;
+    }
+    Expr::Paren(paren) => collect_pattern_parts(&paren.expr, pattern)?,
+    _ => pattern.push('*'),
+  }
+
+  Some(())
+}
+
+fn create_helper() -> Box<Expr> {
+  let input = StringInput::new(DYNAMIC_IMPORT_HELPER, BytePos::DUMMY, BytePos::DUMMY);
+  let mut parser = Parser::new(Syntax::Es(Default::default()), input, None);
+  let mut helper = parser
+    .parse_expr()
+    .expect("the dynamic import helper should be valid");
+
+  helper.visit_mut_with(&mut DropSpan {
+    preserve_ctxt: false,
+  });
+  helper
+}
@@ -31,6 +31,10 @@ use farmfe_core::wax::Glob;
 use farmfe_toolkit::swc_ecma_visit::{VisitMut, VisitMutWith};
 use farmfe_utils::relative;
 
+mod dynamic_import;
+
+pub use dynamic_import::transform_dynamic_import_vars;
+
 const REGEX_PREFIX: &str = "$__farm_regex:";
 
 pub fn transform_import_meta_glob(
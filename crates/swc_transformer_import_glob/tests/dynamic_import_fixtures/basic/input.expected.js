@@ -0,0 +1,27 @@
+export function loadLocale(lang) {
+    return (function(modules, path) {
+        var load = modules[path];
+        return load ? load() : Promise.reject(new Error("Unknown variable dynamic import: " + path));
+    })({
+        "./locales/en.js": ()=>import("./locales/en.js"),
+        "./locales/zh.js": ()=>import("./locales/zh.js")
+    }, `./locales/${lang}.js`);
+}
+export function loadPage(name) {
+    return (function(modules, path) {
+        var load = modules[path];
+        return load ? load() : Promise.reject(new Error("Unknown variable dynamic import: " + path));
+    })({
+        "./pages/about/index.js": ()=>import("./pages/about/index.js"),
+        "./pages/home/index.js": ()=>import("./pages/home/index.js")
+    }, './pages/' + name + '/index.js');
+}
+export function loadStatic() {
+    return import('./locales/en.js');
+}
+export function loadUnknown(path) {
+    return import(path);
+}
+export function loadSelf(name) {
+    return import(`./${name}`);
+}
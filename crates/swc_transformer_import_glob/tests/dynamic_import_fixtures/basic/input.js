@@ -0,0 +1,21 @@
+export function loadLocale(lang) {
+  return import(`./locales/${lang}.js`);
+}
+
+export function loadPage(name) {
+  return import('./pages/' + name + '/index.js');
+}
+
+export function loadStatic() {
+  return import('./locales/en.js');
+}
+
+export function loadUnknown(path) {
+  // not analyzable, kept as is
+  return import(path);
+}
+
+export function loadSelf(name) {
+  // may match the module itself, kept as is
+  return import(`./${name}`);
+}
@@ -6,12 +6,13 @@ use std::{
 use farmfe_core::{
   config::{comments::CommentsConfig, config_regex::ConfigRegex, SourcemapConfig},
   enhanced_magic_string::collapse_sourcemap::collapse_sourcemap_chain,
+  error::diagnostic::{Diagnostic, DiagnosticPosition},
   relative_path::RelativePath,
   resource::{resource_pot::ResourcePot, Resource, ResourceOrigin, ResourceType},
   swc_common::{
     comments::{Comment, CommentKind, SingleThreadedComments},
     source_map::SourceMapGenConfig,
-    BytePos, FileName, LineCol, SourceFile, SourceMap,
+    BytePos, FileName, LineCol, SourceFile, SourceMap, Span,
   },
 };
 use farmfe_utils::hash::base64_decode;
@@ -31,6 +32,32 @@ pub fn create_swc_source_map(source: Source) -> (Arc<SourceMap>, Arc<SourceFile>
   (cm, sf)
 }
 
+/// hint of the syntax errors reported when parsing modules
+pub const PARSE_ERROR_HINT: &str =
+  "If this kind of module is not supported natively, you may need plugins to support it";
+
+/// create an error [Diagnostic] that points to the span of the source file, with the code frame
+pub fn create_error_diagnostic(
+  cm: &SourceMap,
+  id: &str,
+  content: &str,
+  span: Span,
+  message: impl Into<String>,
+) -> Diagnostic {
+  let position = |pos: BytePos| {
+    let loc = cm.lookup_char_pos(pos);
+    DiagnosticPosition {
+      line: loc.line,
+      column: loc.col.0,
+    }
+  };
+
+  Diagnostic::error(message)
+    .with_file(id)
+    .with_span(position(span.lo), position(span.hi))
+    .with_code_frame(content)
+}
+
 pub fn append_source_map_comment(
   resource: &mut Resource,
   map: &Resource,
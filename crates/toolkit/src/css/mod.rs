@@ -13,9 +13,10 @@ use swc_css_parser::{
   lexer::Lexer,
   parser::{Parser, ParserConfig},
 };
-use swc_error_reporters::handler::try_with_handler;
 
-use crate::common::{build_source_map, create_swc_source_map, Source};
+use crate::common::{
+  build_source_map, create_error_diagnostic, create_swc_source_map, Source, PARSE_ERROR_HINT,
+};
 
 pub struct ParseCssModuleResult {
   pub ast: Stylesheet,
@@ -61,23 +62,18 @@ pub fn parse_css_stylesheet(
     }
   }
 
-  try_with_handler(cm, Default::default(), |handler| {
-    for err in recovered_errors {
-      err.to_diagnostics(handler).emit();
-    }
+  Err(CompilationError::Diagnostics(
+    recovered_errors
+      .into_iter()
+      .map(|err| {
+        let message = err.message();
+        let (span, _) = *err.into_inner();
 
-    Err(anyhow::Error::msg("SyntaxError"))
-  })
-  .map_err(|e| CompilationError::ParseError {
-    resolved_path: id.to_string(),
-    msg: if let Some(s) = e.downcast_ref::<String>() {
-      s.to_string()
-    } else if let Some(s) = e.downcast_ref::<&str>() {
-      s.to_string()
-    } else {
-      "failed to handle with unknown panic message".to_string()
-    },
-  })
+        create_error_diagnostic(&cm, id, &source_file.src, span, message)
+          .with_hint(PARSE_ERROR_HINT)
+      })
+      .collect(),
+  ))
 }
 
 /// generate css code from [Stylesheet], return css code and source map
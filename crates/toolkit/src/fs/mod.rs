@@ -25,6 +25,11 @@ pub fn read_file_raw(path: &str) -> Result<Vec<u8>> {
   std::fs::read(path).map_err(|e| CompilationError::GenericError(format!("{:?}", e)))
 }
 
+/// whether the filename config contains `[contentHash]` or `[hash]`
+pub fn has_content_hash(filename_config: &str) -> bool {
+  filename_config.contains(CONTENT_HASH) || filename_config.contains(CONTENT_HASH_NEW)
+}
+
 pub fn transform_output_filename(
   filename_config: String,
   name: &str,
@@ -69,3 +74,18 @@ pub fn transform_output_entry_filename(
 
   transform_output_filename(res, name, bytes, ext)
 }
+
+/// url of the emitted resource under `output.publicPath`, absolute public paths like `https://cdn.com/assets/` are kept as is
+pub fn public_url(public_path: &str, name: &str) -> String {
+  if public_path.contains("://") || public_path.starts_with("//") {
+    return format!("{}/{name}", public_path.trim_end_matches('/'));
+  }
+
+  let public_path = public_path.trim_matches('/');
+
+  if public_path.is_empty() {
+    format!("/{name}")
+  } else {
+    format!("/{public_path}/{name}")
+  }
+}
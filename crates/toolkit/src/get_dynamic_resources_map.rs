@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use farmfe_core::{
-  config::Mode,
+  config::{Mode, SriAlgorithm},
   module::{
     module_group::{ModuleGroupGraph, ModuleGroupId},
     ModuleId,
@@ -9,6 +9,8 @@ use farmfe_core::{
   resource::{resource_pot_map::ResourcePotMap, Resource, ResourceType},
 };
 
+use crate::hash::integrity;
+
 pub fn get_dynamic_resources_map(
   module_group_graph: &ModuleGroupGraph,
   module_group_id: &ModuleGroupId,
@@ -78,9 +80,26 @@ pub fn get_dynamic_resources_map(
   dynamic_resources_map
 }
 
+/// resource name -> subresource integrity of the js and css resources, empty if `output.sri` is not configured
+pub fn get_resources_integrity(
+  resources_map: &HashMap<String, Resource>,
+  sri: Option<SriAlgorithm>,
+) -> HashMap<String, String> {
+  let Some(algorithm) = sri else {
+    return HashMap::new();
+  };
+
+  resources_map
+    .values()
+    .filter(|r| matches!(r.resource_type, ResourceType::Js | ResourceType::Css))
+    .map(|r| (r.name.clone(), integrity(&r.bytes, algorithm)))
+    .collect()
+}
+
 pub fn get_dynamic_resources_code(
   dynamic_resources_map: &HashMap<ModuleId, Vec<(String, ResourceType)>>,
   mode: Mode,
+  resources_integrity: &HashMap<String, String>,
 ) -> String {
   let mut dynamic_resources_code_vec = vec![];
 
@@ -89,12 +108,23 @@ pub fn get_dynamic_resources_code(
     let mut resources_code = String::new();
 
     for (resource_name, resource_type) in resources {
+      let integrity = resources_integrity
+        .get(resource_name)
+        .map(|integrity| format!(", integrity: '{}'", integrity))
+        .unwrap_or_default();
+
       match resource_type {
         ResourceType::Js => {
-          resources_code += &format!(r#"{{ path: '{}', type: 'script' }},"#, resource_name)
+          resources_code += &format!(
+            r#"{{ path: '{}', type: 'script'{} }},"#,
+            resource_name, integrity
+          )
         }
         ResourceType::Css => {
-          resources_code += &format!(r#"{{ path: '{}', type: 'link' }},"#, resource_name)
+          resources_code += &format!(
+            r#"{{ path: '{}', type: 'link'{} }},"#,
+            resource_name, integrity
+          )
         }
         _ => {
           panic!(
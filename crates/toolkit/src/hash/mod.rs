@@ -1,3 +1,17 @@
+use farmfe_core::config::SriAlgorithm;
+
 pub use farmfe_utils::hash::base64_decode;
 pub use farmfe_utils::hash::base64_encode;
 pub use farmfe_utils::hash::sha256;
+pub use farmfe_utils::hash::sha256_base64;
+pub use farmfe_utils::hash::sha384_base64;
+pub use farmfe_utils::hash::sha512_base64;
+
+/// subresource integrity of the bytes, e.g. `sha384-xxx`
+pub fn integrity(bytes: &[u8], algorithm: SriAlgorithm) -> String {
+  match algorithm {
+    SriAlgorithm::Sha256 => format!("sha256-{}", sha256_base64(bytes)),
+    SriAlgorithm::Sha384 => format!("sha384-{}", sha384_base64(bytes)),
+    SriAlgorithm::Sha512 => format!("sha512-{}", sha512_base64(bytes)),
+  }
+}
@@ -18,6 +18,7 @@ use lazy_static::lazy_static;
 
 pub mod package_json_loader;
 pub mod symlinks_analyzer;
+pub mod tsconfig_loader;
 
 use package_json_loader::PackageJsonLoader;
 
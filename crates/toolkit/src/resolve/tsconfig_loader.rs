@@ -0,0 +1,314 @@
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::Arc,
+  time::SystemTime,
+};
+
+use farmfe_core::{
+  dashmap::DashMap,
+  error::{CompilationError, Result},
+  relative_path::RelativePath,
+  serde::Deserialize,
+  serde_json::from_str,
+};
+
+use crate::fs::read_file_utf8;
+
+const TSCONFIG_FILE: &str = "tsconfig.json";
+const NODE_MODULES: &str = "node_modules";
+
+/// Module resolution options of the closest tsconfig.json, `extends` is already applied.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TsConfigInfo {
+  /// all tsconfig files of the `extends` chain, the closest tsconfig.json comes first
+  pub files: Vec<String>,
+  /// absolute path of `compilerOptions.baseUrl`
+  pub base_url: Option<String>,
+  /// `compilerOptions.paths`, the targets are relative to `paths_base_dir`
+  pub paths: HashMap<String, Vec<String>>,
+  /// `baseUrl` if it's set, otherwise the directory of the tsconfig that defines `paths`
+  pub paths_base_dir: String,
+}
+
+impl TsConfigInfo {
+  /// whether the tsconfig affects module resolution
+  pub fn has_resolve_options(&self) -> bool {
+    self.base_url.is_some() || !self.paths.is_empty()
+  }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase", default)]
+struct TsConfigJson {
+  extends: Option<TsConfigExtends>,
+  compiler_options: TsConfigCompilerOptions,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "farmfe_core::serde", untagged)]
+enum TsConfigExtends {
+  Single(String),
+  Multiple(Vec<String>),
+}
+
+#[derive(Deserialize, Default)]
+#[serde(crate = "farmfe_core::serde", rename_all = "camelCase", default)]
+struct TsConfigCompilerOptions {
+  base_url: Option<String>,
+  paths: Option<HashMap<String, Vec<String>>>,
+}
+
+/// Load closest tsconfig.json and follow its `extends` chain.
+/// Like [super::package_json_loader::PackageJsonLoader], every visited directory is cached,
+/// call [TsConfigLoader::invalidate_if_changed] to drop the cache when a loaded tsconfig is modified.
+///
+/// ```ignore
+/// let tsconfig_loader = TsConfigLoader::new();
+/// let info = tsconfig_loader.load(PathBuf::from("/root/packages/app/src"))?;
+/// ```
+pub struct TsConfigLoader {
+  /// dir -> closest tsconfig info cache, None if there is no tsconfig.json in all ancestor directories
+  cache: DashMap<String, Option<Arc<TsConfigInfo>>>,
+  /// loaded tsconfig file -> (modified time, size) when it's loaded
+  loaded_files: DashMap<String, Option<(SystemTime, u64)>>,
+}
+
+impl Default for TsConfigLoader {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl TsConfigLoader {
+  pub fn new() -> Self {
+    Self {
+      cache: DashMap::new(),
+      loaded_files: DashMap::new(),
+    }
+  }
+
+  /// resolve tsconfig.json start from dir to all its ancestor.
+  /// A tsconfig that fails to load is cached as None after the error is returned, until it's modified
+  pub fn load(&self, dir: PathBuf) -> Result<Option<Arc<TsConfigInfo>>> {
+    let mut current = dir;
+    let mut visited_stack = vec![];
+
+    let result = loop {
+      let key = current.to_string_lossy().to_string();
+
+      if let Some(cached) = self.cache.get(&key) {
+        break cached.clone();
+      }
+
+      visited_stack.push(key);
+      let tsconfig_path = current.join(TSCONFIG_FILE);
+
+      if tsconfig_path.is_file() {
+        match self.load_tsconfig(&tsconfig_path) {
+          Ok(info) => break Some(Arc::new(info)),
+          Err(e) => {
+            for visited in visited_stack {
+              self.cache.insert(visited, None);
+            }
+
+            return Err(e);
+          }
+        }
+      }
+
+      match current.parent() {
+        Some(parent) => current = parent.to_path_buf(),
+        None => break None,
+      }
+    };
+
+    for visited in visited_stack {
+      self.cache.insert(visited, result.clone());
+    }
+
+    Ok(result)
+  }
+
+  /// Clear the cache if any loaded tsconfig is modified or removed, return true if the cache is cleared.
+  pub fn invalidate_if_changed(&self) -> bool {
+    let changed = self
+      .loaded_files
+      .iter()
+      .any(|item| file_stamp(Path::new(item.key())) != *item.value());
+
+    if changed {
+      self.cache.clear();
+      self.loaded_files.clear();
+    }
+
+    changed
+  }
+
+  pub fn cache(&self) -> &DashMap<String, Option<Arc<TsConfigInfo>>> {
+    &self.cache
+  }
+
+  fn load_tsconfig(&self, tsconfig_path: &Path) -> Result<TsConfigInfo> {
+    let mut info = TsConfigInfo::default();
+    self.apply_tsconfig(tsconfig_path, &mut info, &mut vec![])?;
+
+    if let Some(base_url) = &info.base_url {
+      info.paths_base_dir = base_url.clone();
+    }
+
+    Ok(info)
+  }
+
+  /// apply the `extends` chain of the tsconfig first, then the options of the tsconfig itself
+  fn apply_tsconfig(
+    &self,
+    tsconfig_path: &Path,
+    info: &mut TsConfigInfo,
+    extends_stack: &mut Vec<String>,
+  ) -> Result<()> {
+    let path_str = tsconfig_path.to_string_lossy().to_string();
+
+    if extends_stack.contains(&path_str) {
+      return Err(CompilationError::GenericError(format!(
+        "Failed to load tsconfig `{path_str}`: circular `extends` is detected"
+      )));
+    }
+
+    // tracked before parsing, so that the cache is invalidated after a broken tsconfig is fixed
+    self
+      .loaded_files
+      .insert(path_str.clone(), file_stamp(tsconfig_path));
+
+    let content = read_file_utf8(&path_str)?;
+    let tsconfig: TsConfigJson = from_str(&strip_json_comments(&content)).map_err(|e| {
+      CompilationError::GenericError(format!("Failed to load tsconfig `{path_str}`: {e}"))
+    })?;
+    let dir = tsconfig_path.parent().unwrap();
+
+    if !info.files.contains(&path_str) {
+      info.files.push(path_str.clone());
+    }
+
+    let extends = match tsconfig.extends {
+      Some(TsConfigExtends::Single(extends)) => vec![extends],
+      Some(TsConfigExtends::Multiple(extends)) => extends,
+      None => vec![],
+    };
+
+    for extends in extends {
+      let extends_path = resolve_extends(&extends, dir).ok_or_else(|| {
+        CompilationError::GenericError(format!(
+          "Failed to load tsconfig `{path_str}`: can not resolve extended tsconfig `{extends}`"
+        ))
+      })?;
+      extends_stack.push(path_str.clone());
+      self.apply_tsconfig(&extends_path, info, extends_stack)?;
+      extends_stack.pop();
+    }
+
+    let dir_str = dir.to_string_lossy().to_string();
+
+    if let Some(base_url) = tsconfig.compiler_options.base_url {
+      info.base_url = Some(join_path(dir, &base_url).to_string_lossy().to_string());
+    }
+
+    if let Some(paths) = tsconfig.compiler_options.paths {
+      info.paths = paths;
+      info.paths_base_dir = dir_str;
+    }
+
+    Ok(())
+  }
+}
+
+fn file_stamp(path: &Path) -> Option<(SystemTime, u64)> {
+  let metadata = path.metadata().ok()?;
+  Some((metadata.modified().ok()?, metadata.len()))
+}
+
+fn join_path(dir: &Path, path: &str) -> PathBuf {
+  if Path::new(path).is_absolute() {
+    PathBuf::from(path)
+  } else {
+    RelativePath::new(path).to_logical_path(dir)
+  }
+}
+
+/// `extends` can be a relative path or a tsconfig in node_modules, `.json` can be omitted
+fn resolve_extends(extends: &str, dir: &Path) -> Option<PathBuf> {
+  let try_file = |path: PathBuf| {
+    [
+      path.clone(),
+      PathBuf::from(format!("{}.json", path.to_string_lossy())),
+      path.join(TSCONFIG_FILE),
+    ]
+    .into_iter()
+    .find(|p| p.is_file())
+  };
+
+  if extends.starts_with('.') || Path::new(extends).is_absolute() {
+    return try_file(join_path(dir, extends));
+  }
+
+  dir
+    .ancestors()
+    .find_map(|ancestor| try_file(ancestor.join(NODE_MODULES).join(extends)))
+}
+
+/// tsconfig.json is jsonc, remove comments and trailing commas so that it can be parsed by serde_json
+fn strip_json_comments(content: &str) -> String {
+  let chars = content.chars().collect::<Vec<_>>();
+  let mut result = String::with_capacity(content.len());
+  let mut i = 0;
+  // position of the last comma that is not in a string
+  let mut pending_comma: Option<usize> = None;
+
+  while i < chars.len() {
+    let c = chars[i];
+
+    if c == '"' {
+      let start = i;
+      i += 1;
+
+      while i < chars.len() && chars[i] != '"' {
+        if chars[i] == '\\' {
+          i += 1;
+        }
+        i += 1;
+      }
+
+      result.extend(&chars[start..(i + 1).min(chars.len())]);
+      pending_comma = None;
+    } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+      while i < chars.len() && chars[i] != '\n' {
+        i += 1;
+      }
+      continue;
+    } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+      i += 2;
+
+      while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+        i += 1;
+      }
+
+      i += 1;
+    } else if (c == '}' || c == ']') && pending_comma.is_some() {
+      result.replace_range(pending_comma.unwrap()..pending_comma.unwrap() + 1, " ");
+      result.push(c);
+      pending_comma = None;
+    } else {
+      if c == ',' {
+        pending_comma = Some(result.len());
+      } else if !c.is_whitespace() {
+        pending_comma = None;
+      }
+
+      result.push(c);
+    }
+
+    i += 1;
+  }
+
+  result
+}
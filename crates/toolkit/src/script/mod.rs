@@ -7,21 +7,23 @@ use swc_ecma_codegen::{
 use swc_ecma_parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax, TsConfig};
 
 use farmfe_core::{
-  config::{comments::CommentsConfig, ScriptParserConfig},
+  config::{comments::CommentsConfig, ScriptParserConfig, FARM_MODULE},
   error::{CompilationError, Result},
   module::{ModuleSystem, ModuleType},
   plugin::{PluginFinalizeModuleHookParam, ResolveKind},
   swc_common::{
     comments::{Comments, SingleThreadedComments},
-    BytePos, FileName, LineCol, Mark, SourceMap,
+    BytePos, FileName, LineCol, Mark, SourceMap, Spanned, SyntaxContext,
   },
   swc_ecma_ast::{
-    CallExpr, Callee, EsVersion, Expr, Ident, Import, Module as SwcModule, ModuleItem, Stmt,
+    CallExpr, Callee, EsVersion, Expr, ExprOrSpread, Ident, Import, Lit, MemberExpr, MemberProp,
+    MetaPropExpr, MetaPropKind, Module as SwcModule, ModuleItem, NewExpr, Stmt, Str,
   },
 };
-use swc_error_reporters::handler::try_with_handler;
 
-use crate::common::{create_swc_source_map, minify_comments, Source};
+use crate::common::{
+  create_error_diagnostic, create_swc_source_map, minify_comments, Source, PARSE_ERROR_HINT,
+};
 
 pub use farmfe_toolkit_plugin_types::swc_ast::ParseScriptModuleResult;
 
@@ -56,23 +58,15 @@ pub fn parse_module(
     }
   }
 
-  try_with_handler(cm, Default::default(), |handler| {
-    for err in recovered_errors {
-      err.into_diagnostic(handler).emit();
-    }
-
-    Err(anyhow::Error::msg("SyntaxError"))
-  })
-  .map_err(|e| CompilationError::ParseError {
-    resolved_path: id.to_string(),
-    msg: if let Some(s) = e.downcast_ref::<String>() {
-      s.to_string()
-    } else if let Some(s) = e.downcast_ref::<&str>() {
-      s.to_string()
-    } else {
-      "failed to handle with unknown panic message".to_string()
-    },
-  })
+  Err(CompilationError::Diagnostics(
+    recovered_errors
+      .into_iter()
+      .map(|err| {
+        create_error_diagnostic(&cm, id, content, err.span(), err.kind().msg())
+          .with_hint(PARSE_ERROR_HINT)
+      })
+      .collect(),
+  ))
 }
 
 /// parse the content of a module to [SwcModule] ast.
@@ -210,6 +204,63 @@ pub fn is_dynamic_import(call_expr: &CallExpr) -> bool {
   matches!(&call_expr.callee, Callee::Import(Import { .. }))
 }
 
+/// Get the string literal of `new URL('./xxx', import.meta.url)`, return None if the new expr is not of this pattern.
+/// `URL` must be a global variable. `module.meta.url` that `import.meta.url` is transformed to by the script plugin is also matched.
+pub fn get_new_url_with_import_meta_source(
+  unresolved_mark: Mark,
+  new_expr: &NewExpr,
+) -> Option<&Str> {
+  let NewExpr {
+    callee: box Expr::Ident(Ident { span, sym, .. }),
+    args: Some(args),
+    ..
+  } = new_expr
+  else {
+    return None;
+  };
+
+  if sym != "URL" || span.ctxt.outer() != unresolved_mark || args.len() != 2 {
+    return None;
+  }
+
+  let is_import_meta_url = match &args[1] {
+    ExprOrSpread {
+      spread: None,
+      expr:
+        box Expr::Member(MemberExpr {
+          obj: box meta,
+          prop: MemberProp::Ident(Ident { sym: prop, .. }),
+          ..
+        }),
+    } if prop == "url" => is_import_meta(meta),
+    _ => false,
+  };
+
+  match &args[0] {
+    ExprOrSpread {
+      spread: None,
+      expr: box Expr::Lit(Lit::Str(s)),
+    } if is_import_meta_url => Some(s),
+    _ => None,
+  }
+}
+
+/// `import.meta`, or `module.meta` created by the script plugin which does not have a syntax context
+fn is_import_meta(expr: &Expr) -> bool {
+  match expr {
+    Expr::MetaProp(MetaPropExpr {
+      kind: MetaPropKind::ImportMeta,
+      ..
+    }) => true,
+    Expr::Member(MemberExpr {
+      obj: box Expr::Ident(Ident { span, sym, .. }),
+      prop: MemberProp::Ident(Ident { sym: prop, .. }),
+      ..
+    }) => sym == FARM_MODULE && prop == "meta" && span.ctxt == SyntaxContext::empty(),
+    _ => false,
+  }
+}
+
 pub fn module_system_from_deps(deps: Vec<ResolveKind>) -> ModuleSystem {
   let mut module_system = ModuleSystem::Custom(String::from("unknown"));
 
@@ -0,0 +1,181 @@
+//! Resolve the browserslist `targets` shared by script down-levelling, polyfills and css prefixing.
+use std::{collections::HashMap, path::Path};
+
+use farmfe_core::{
+  config::{Config, Mode},
+  error::{CompilationError, Result},
+  serde_json::{self, Value},
+  swc_ecma_ast::EsVersion,
+};
+use preset_env_base::query::{targets_to_versions, Query, Targets};
+use swc_ecma_preset_env::Feature;
+
+const BROWSERSLIST_RC: &str = ".browserslistrc";
+/// `targets: "browserslist"` loads the queries from `.browserslistrc` or package.json
+const BROWSERSLIST: &str = "browserslist";
+const DEFAULT_SECTION: &str = "defaults";
+
+/// Load `config.targets` from `.browserslistrc` or the `browserslist` field of package.json under root when it's `"browserslist"`,
+/// then derive `script.target` and the css prefixer targets from it unless they are configured.
+pub fn normalize_targets(config: &mut Config) -> Result<()> {
+  if matches!(&config.targets, Some(Targets::Query(Query::Single(q))) if q == BROWSERSLIST) {
+    config.targets = load_browserslist_config(&config.root, &config.mode).map(Targets::Query);
+  }
+
+  let Some(targets) = &config.targets else {
+    return Ok(());
+  };
+
+  if config.script.target.is_none() {
+    config.script.target = Some(es_version_from_targets(targets)?);
+  }
+
+  if let Some(prefixer) = config.css.prefixer.as_mut() {
+    prefixer.targets.get_or_insert_with(|| targets.clone());
+  }
+
+  Ok(())
+}
+
+/// The highest [EsVersion] whose syntax is supported by all browsers of the targets.
+pub fn es_version_from_targets(targets: &Targets) -> Result<EsVersion> {
+  let versions = targets_to_versions(Some(targets.clone()))
+    .map_err(|e| CompilationError::GenericError(format!("Invalid targets {targets:?}: {e:?}")))?;
+
+  // features introduced by each es version, from the newest to the oldest
+  let es_features = [
+    (
+      EsVersion::Es2022,
+      vec![
+        Feature::ClassProperties,
+        Feature::PrivateMethods,
+        Feature::ClassStaticBlock,
+      ],
+    ),
+    (
+      EsVersion::Es2021,
+      vec![
+        Feature::LogicalAssignmentOperators,
+        Feature::NumericSeparator,
+      ],
+    ),
+    (
+      EsVersion::Es2020,
+      vec![Feature::NullishCoalescing, Feature::OptionalChaining],
+    ),
+    (EsVersion::Es2019, vec![Feature::OptionalCatchBinding]),
+    (
+      EsVersion::Es2018,
+      vec![Feature::ObjectRestSpread, Feature::AsyncGeneratorFunctions],
+    ),
+    (EsVersion::Es2017, vec![Feature::AsyncToGenerator]),
+    (EsVersion::Es2016, vec![Feature::ExponentiationOperator]),
+    (
+      EsVersion::Es2015,
+      vec![
+        Feature::ArrowFunctions,
+        Feature::BlockScoping,
+        Feature::Classes,
+        Feature::TemplateLiterals,
+        Feature::Destructuring,
+        Feature::Parameters,
+        Feature::Spread,
+        Feature::ForOf,
+      ],
+    ),
+  ];
+
+  let mut es_version = EsVersion::Es5;
+
+  // a version is supported only when all its features and the features of older versions are supported
+  for (version, features) in es_features.into_iter().rev() {
+    if features
+      .into_iter()
+      .any(|f| f.should_enable(versions, false, false))
+    {
+      break;
+    }
+
+    es_version = version;
+  }
+
+  Ok(es_version)
+}
+
+/// Load the browserslist query of current mode from `.browserslistrc` or package.json under root.
+/// The section named by the mode is preferred, and fallback to the default queries.
+pub fn load_browserslist_config(root: &str, mode: &Mode) -> Option<Query> {
+  let root = Path::new(root);
+
+  let sections = if let Ok(content) = std::fs::read_to_string(root.join(BROWSERSLIST_RC)) {
+    parse_browserslist_rc(&content)
+  } else {
+    let content = std::fs::read_to_string(root.join("package.json")).ok()?;
+    let package_json: Value = serde_json::from_str(&content).ok()?;
+    parse_package_json_browserslist(package_json.get("browserslist")?)
+  };
+
+  sections
+    .get(&mode.to_string())
+    .or_else(|| sections.get(DEFAULT_SECTION))
+    .filter(|queries| !queries.is_empty())
+    .map(|queries| Query::Multiple(queries.clone()))
+}
+
+fn parse_browserslist_rc(content: &str) -> HashMap<String, Vec<String>> {
+  let mut sections = HashMap::<String, Vec<String>>::new();
+  let mut current = vec![DEFAULT_SECTION.to_string()];
+
+  for line in content.lines() {
+    let line = line.split('#').next().unwrap_or_default().trim();
+
+    if line.is_empty() {
+      continue;
+    }
+
+    // `[production staging]` starts a section shared by multiple envs
+    if let Some(envs) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+      current = envs.split_whitespace().map(|e| e.to_string()).collect();
+      continue;
+    }
+
+    for env in &current {
+      sections
+        .entry(env.clone())
+        .or_default()
+        .extend(split_queries(line));
+    }
+  }
+
+  sections
+}
+
+fn parse_package_json_browserslist(value: &Value) -> HashMap<String, Vec<String>> {
+  fn to_queries(value: &Value) -> Vec<String> {
+    match value {
+      Value::String(s) => split_queries(s).collect(),
+      Value::Array(arr) => arr
+        .iter()
+        .filter_map(|v| v.as_str())
+        .flat_map(split_queries)
+        .collect(),
+      _ => vec![],
+    }
+  }
+
+  match value {
+    Value::Object(envs) => envs
+      .iter()
+      .map(|(env, queries)| (env.clone(), to_queries(queries)))
+      .collect(),
+    _ => HashMap::from([(DEFAULT_SECTION.to_string(), to_queries(value))]),
+  }
+}
+
+fn split_queries(line: &str) -> impl Iterator<Item = String> + '_ {
+  line
+    .split(',')
+    .map(|q| q.trim())
+    .filter(|q| !q.is_empty())
+    .map(|q| q.to_string())
+}
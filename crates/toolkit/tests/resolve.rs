@@ -31,11 +31,10 @@ fn load_package_json() {
     assert!(matches!(result.side_effects(), ParsedSideEffects::Array(_)));
 
     if let ParsedSideEffects::Array(arr) = result.side_effects() {
-      assert_eq!(
-        *arr,
-        vec![sub.join("main.css").to_string_lossy().to_string()]
-      );
+      assert_eq!(*arr, vec!["**/*.css".to_string()]);
     }
+    assert!(result.has_side_effects(&sub.join("main.css").to_string_lossy()));
+    assert!(!result.has_side_effects(&sub.join("main.ts").to_string_lossy()));
 
     // make sure cache works
     let cache = PACKAGE_JSON_LOADER.cache();
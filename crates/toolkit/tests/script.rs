@@ -2,6 +2,7 @@ use std::{path::PathBuf, sync::Arc};
 
 use farmfe_core::{
   config::comments::CommentsConfig,
+  error::{diagnostic::DiagnosticPosition, CompilationError},
   swc_common::{FilePathMapping, SourceMap},
 };
 use farmfe_toolkit::{
@@ -48,3 +49,31 @@ fn parse_and_codegen_module() {
     );
   });
 }
+
+#[test]
+fn parse_module_syntax_error() {
+  let id = "/root/index.ts";
+  let content = "const a = 1;\nconst b = ;\n";
+  let syntax =
+    syntax_from_module_type(&module_type_from_id(id).unwrap(), Default::default()).unwrap();
+
+  let err = match parse_module(id, content, syntax, Default::default()) {
+    Err(CompilationError::Diagnostics(diagnostics)) => diagnostics,
+    _ => panic!("expected syntax error diagnostics"),
+  };
+
+  assert_eq!(err.len(), 1);
+  assert_eq!(err[0].file.as_deref(), Some(id));
+  assert_eq!(
+    err[0].start,
+    Some(DiagnosticPosition {
+      line: 2,
+      column: 10
+    })
+  );
+  assert!(err[0]
+    .code_frame
+    .as_ref()
+    .unwrap()
+    .contains("> 2 | const b = ;"));
+}
@@ -0,0 +1,89 @@
+use farmfe_core::{
+  config::{Config, Mode},
+  swc_ecma_ast::EsVersion,
+};
+use farmfe_toolkit::{
+  preset_env_base::query::{Query, Targets},
+  targets::{es_version_from_targets, load_browserslist_config, normalize_targets},
+};
+
+fn fixture_root(name: &str) -> String {
+  std::env::current_dir()
+    .unwrap()
+    .join("tests")
+    .join("fixtures")
+    .join("targets")
+    .join(name)
+    .to_string_lossy()
+    .to_string()
+}
+
+#[test]
+fn load_browserslist_rc() {
+  let root = fixture_root("browserslistrc");
+
+  assert_eq!(
+    load_browserslist_config(&root, &Mode::Development),
+    Some(Query::Multiple(vec![
+      "chrome >= 60".to_string(),
+      "firefox >= 60".to_string()
+    ]))
+  );
+  assert_eq!(
+    load_browserslist_config(&root, &Mode::Production),
+    Some(Query::Multiple(vec!["chrome >= 90".to_string()]))
+  );
+}
+
+#[test]
+fn load_package_json_browserslist() {
+  assert_eq!(
+    load_browserslist_config(&fixture_root("package_json"), &Mode::Production),
+    Some(Query::Multiple(vec!["ie >= 11".to_string()]))
+  );
+  assert_eq!(
+    load_browserslist_config(&fixture_root("none"), &Mode::Production),
+    None
+  );
+}
+
+#[test]
+fn es_version_of_targets() {
+  let es_version = |query: &str| {
+    es_version_from_targets(&Targets::Query(Query::Single(query.to_string()))).unwrap()
+  };
+
+  assert_eq!(es_version("ie >= 11"), EsVersion::Es5);
+  assert_eq!(es_version("chrome >= 51"), EsVersion::Es2015);
+  assert_eq!(es_version("chrome >= 91"), EsVersion::Es2021);
+  assert_eq!(es_version("chrome >= 100"), EsVersion::Es2022);
+}
+
+#[test]
+fn normalize_script_target() {
+  let normalize = |targets: Option<&str>, target: Option<EsVersion>| {
+    let mut config = Config {
+      root: fixture_root("browserslistrc"),
+      mode: Mode::Production,
+      targets: targets.map(|t| Targets::Query(Query::Single(t.to_string()))),
+      ..Default::default()
+    };
+    config.script.target = target;
+    normalize_targets(&mut config).unwrap();
+    (config.targets, config.script.target)
+  };
+
+  // the browserslist config is only read when it's opted in
+  assert!(matches!(normalize(None, None), (None, None)));
+
+  let (targets, target) = normalize(Some("browserslist"), None);
+  assert!(matches!(targets, Some(Targets::Query(Query::Multiple(q))) if q == vec!["chrome >= 90"]));
+  assert_eq!(target, Some(EsVersion::Es2019));
+
+  assert_eq!(normalize(Some("ie >= 11"), None).1, Some(EsVersion::Es5));
+  // explicit script target is kept
+  assert_eq!(
+    normalize(Some("ie >= 11"), Some(EsVersion::Es2017)).1,
+    Some(EsVersion::Es2017)
+  );
+}
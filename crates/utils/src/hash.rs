@@ -1,5 +1,5 @@
 use base64::{engine::general_purpose, Engine};
-use sha2::{Digest, Sha256};
+use sha2::{Digest, Sha256, Sha384, Sha512};
 
 pub fn sha256(bytes: &[u8], len: usize) -> String {
   let mut hasher = Sha256::new();
@@ -11,6 +11,19 @@ pub fn sha256(bytes: &[u8], len: usize) -> String {
   format!("{:x}", hash)[..len].to_string()
 }
 
+/// base64 encoded sha256 digest, used by content security policy and subresource integrity
+pub fn sha256_base64(bytes: &[u8]) -> String {
+  base64_encode(&Sha256::digest(bytes))
+}
+
+pub fn sha384_base64(bytes: &[u8]) -> String {
+  base64_encode(&Sha384::digest(bytes))
+}
+
+pub fn sha512_base64(bytes: &[u8]) -> String {
+  base64_encode(&Sha512::digest(bytes))
+}
+
 pub fn base64_encode(bytes: &[u8]) -> String {
   general_purpose::STANDARD.encode(bytes)
 }
@@ -26,6 +39,22 @@ mod tests {
     assert_eq!(super::sha256(b"hello world", 8), "b94d27b9".to_string());
   }
 
+  #[test]
+  fn test_sha256_base64() {
+    assert_eq!(
+      super::sha256_base64(b"hello world"),
+      "uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek="
+    );
+  }
+
+  #[test]
+  fn test_sha384_base64() {
+    assert_eq!(
+      super::sha384_base64(b"hello world"),
+      "/b2OdaZ/KfcBpOBAOF4uI5hjA+oQI5IRr5B/y7g1eLPkF8txzmRu/QgZ3YwIjeG9"
+    );
+  }
+
   #[test]
   fn test_base64_encode() {
     assert_eq!(super::base64_encode(b"hello world"), "aGVsbG8gd29ybGQ=");
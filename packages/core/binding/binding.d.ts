@@ -28,6 +28,11 @@ export const enum JsPluginTransformHtmlHookOrder {
   Normal = 1,
   Post = 2
 }
+/** A changed file passed to `update`, `updateType` is one of `added`, `updated` and `removed`, defaults to `updated` */
+export interface JsUpdateEvent {
+  path: string
+  updateType?: string
+}
 export interface WatchDiffResult {
   add: Array<string>
   remove: Array<string>
@@ -113,9 +118,15 @@ export class Compiler {
   /** sync compile */
   compileSync(): void
   /** TODO: usage example */
-  update(paths: Array<string>, callback: (...args: any[]) => any, sync: boolean, generateUpdateResource: boolean): object
+  /**
+   * Update the compiler with the changed files, a path string means the file is updated.
+   * e.g. `compiler.update(['/root/a.ts', { path: '/root/b.ts', updateType: 'added' }], callback, true, true)`
+   */
+  update(paths: Array<string | JsUpdateEvent>, callback: (...args: any[]) => any, sync: boolean, generateUpdateResource: boolean): object
   addWatchFiles(root: string, paths: Array<string>): void
   hasModule(resolvedPath: string): boolean
+  /** Whether the added file may be a dependency that some modules failed to resolve */
+  isMissingDependency(resolvedPath: string): boolean
   getParentFiles(resolvedPath: string): Array<string>
   resources(): Record<string, Buffer>
   resourcesMap(): Record<string, unknown>
@@ -140,7 +140,50 @@ export interface OutputConfig {
   /**
    * output modul format
    */
-  format?: 'cjs' | 'esm';
+  format?: 'cjs' | 'esm' | 'iife' | 'umd';
+  /**
+   * Global variable name that holds the exports of the entry when `format` is `iife` or `umd`.
+   */
+  name?: string;
+  /**
+   * Map external module ids to global variable names, used by `iife` and `umd` format, e.g. `{ react: 'React' }`.
+   */
+  globals?: Record<string, string>;
+  /**
+   * Emit a json manifest that maps every entry and dynamic import to the js/css resources it needs.
+   * Entries are keyed by the entry name of `input` and dynamic imports are keyed by module id.
+   * @default false
+   */
+  manifest?:
+    | boolean
+    | {
+        /**
+         * @default 'manifest.json'
+         */
+        filename?: string;
+      };
+  /**
+   * Add subresource integrity to the scripts and stylesheets injected into html and to the lazily loaded resources.
+   */
+  sri?: 'sha256' | 'sha384' | 'sha512';
+  /**
+   * Emit pre-compressed `.gz` and `.br` siblings of js, css, html, svg and json resources.
+   * @default false
+   */
+  compress?:
+    | boolean
+    | {
+        /** @default true */
+        gzip?: boolean;
+        /** gzip compression level from 0 to 9, @default 9 */
+        gzipLevel?: number;
+        /** @default true */
+        brotli?: boolean;
+        /** brotli compression quality from 0 to 11, @default 11 */
+        brotliLevel?: number;
+        /** resources smaller than this size in bytes are not compressed, @default 1024 */
+        threshold?: number;
+      };
 }
 
 export interface ResolveConfig {
@@ -256,6 +299,26 @@ export interface CssConfig {
     paths?: string[];
     // configure the generated css class name, the default is `[name]-[hash]`
     indentName?: string;
+    /**
+     * How the class names are exposed as the keys of the exports:
+     * - asIs: keep the class names as they are
+     * - camelCase: export both the original and the camel cased class names
+     * - camelCaseOnly: only export the camel cased class names
+     * - dashes: export both the original class names and the names with only dashes camel cased
+     * @default 'asIs'
+     */
+    localsConvention?: 'asIs' | 'camelCase' | 'camelCaseOnly' | 'dashes';
+    /**
+     * Export the class names that are valid identifiers as named exports, e.g. `export const fooBar`, so that unused class names can be tree shaken.
+     * @default false
+     */
+    namedExports?: boolean;
+    /**
+     * Write `*.d.ts` typings of the exported class names next to the css files, or into `outDir` (relative to root).
+     * The typings are regenerated when the css files are updated.
+     * @default false
+     */
+    dts?: boolean | { outDir?: string };
   } | null;
   /**
    * Configure CSS compatibility prefixes, such as -webkit-.
@@ -278,6 +341,21 @@ export interface PersistentCacheConfig {
     hash?: boolean;
   };
   envs?: Record<string, String>;
+  /**
+   * Share the caches through a http content-addressed store, e.g. between CI machines.
+   * A cache is read by `GET <url>/<cache key>` and written by `PUT <url>/<cache key>`.
+   * The caches are always written to the disk, and the caches missing on the disk are read from the remote store
+   */
+  remote?: {
+    url: string;
+    /** Headers sent with every request, e.g. the authorization token of the store */
+    headers?: Record<string, string>;
+    /**
+     * Only read the caches from the remote store
+     * @default true
+     */
+    readOnly?: boolean;
+  };
   /**
    * Whether to ignore the built-in keys of the cache, such as define, buildDependencies, lockfile, etc.
    * If these keys are not ignored, the cache will be fully invalidated when these keys change.
@@ -371,7 +449,8 @@ export interface Config {
     output?: OutputConfig;
     resolve?: ResolveConfig;
     /**
-     * Global variable injection, the configured variable name and value will be injected into the product at compile time. Farm injects process.env.NODE_ENV and some variables used by Farm itself such as FARM_HMR_PORT by default
+     * Global variable injection, the configured variable name and value will be injected into the product at compile time. Farm injects process.env.NODE_ENV and some variables used by Farm itself such as FARM_HMR_PORT by default.
+     * In script modules, keys like `process.env.NODE_ENV`, `import.meta.env.MODE` or `typeof window` only replace global references, string literals and local variables are left untouched.
      */
     define?: Record<string, any>;
     /**
@@ -386,11 +465,51 @@ export interface Config {
     assets?: {
       include?: string[];
       publicDir?: string;
+      /**
+       * Assets smaller than this size(in bytes) are inlined as data urls instead of being emitted, svg is inlined as url encoded text and other assets are inlined as base64.
+       * `?url` and `?inline` query of the import take precedence over this option.
+       * @default 0
+       */
+      inlineLimit?: number;
     };
     script?: ScriptConfig;
     css?: CssConfig;
     html?: {
       base?: string;
+      /**
+       * Content Security Policy support for the tags injected into html entries.
+       * A json resource that maps html resources to the `sha256-` hashes of their injected scripts is emitted.
+       */
+      csp?: {
+        /** nonce placeholder added to every injected tag, e.g. `{{CSP_NONCE}}`, replace it per request on the server */
+        nonce?: string;
+        /** move all inline bootstrap code into hashed external files */
+        external?: boolean;
+        /** name of the emitted hashes resource, default to `csp-hashes.json` */
+        hashesFilename?: string;
+      };
+      /**
+       * Add `<link rel="preload">` for the initial scripts and the fonts and images referenced by the initial css.
+       * @default false
+       */
+      preload?: boolean;
+      /**
+       * Add `<link rel="prefetch">` for the resources of the dynamic imports of the html entry.
+       * @default false
+       */
+      prefetch?: boolean;
+    };
+    json?: {
+      /**
+       * Emit `JSON.parse('...')` instead of an object literal for json files that are not smaller than `stringifyThreshold` bytes, which is faster to parse.
+       * Only the default export is emitted in this case, top-level keys are not exported as named exports.
+       * @default false
+       */
+      stringify?: boolean;
+      /**
+       * @default 10240
+       */
+      stringifyThreshold?: number;
     };
     /**
      * Configure whether to enable sourcemap, optional configuration items and descriptions are as follows:
@@ -413,10 +532,21 @@ export interface Config {
      * Whether to enable tree shake, set to false to disable. See https://farmfe.org/docs/features/tree-shake
      */
     treeShaking?: boolean;
+    /**
+     * Whether to concatenate esm modules that are only imported inside the same resource pot into one scope, only works in production mode.
+     * Default to true in production mode.
+     */
+    concatenateModules?: boolean;
     minify?: boolean | JsMinifyOptions;
     record?: boolean;
     progress?: boolean;
     presetEnv?: boolean | PresetEnvConfig;
+    /**
+     * Browserslist targets shared by script down-levelling, polyfills and css prefixing.
+     * Set to `'browserslist'` to use `.browserslistrc` or the `browserslist` field of package.json under root.
+     * `script.target` is derived from the targets when it's not configured.
+     */
+    targets?: string[] | string | BrowserTargetsRecord;
     persistentCache?: boolean | PersistentCacheConfig;
     comments?: boolean | 'license';
     custom?:Record<string, any>;
@@ -4,7 +4,11 @@ import { Logger } from '../utils/logger.js';
 import { Compiler as BindingCompiler } from '../../binding/index.js';
 
 import type { ILogger } from '../utils/logger.js';
-import type { Config, JsUpdateResult } from '../../binding/index.js';
+import type {
+  Config,
+  JsUpdateEvent,
+  JsUpdateResult
+} from '../../binding/index.js';
 import { JsPlugin, Resource } from '../index.js';
 
 export const VIRTUAL_FARM_DYNAMIC_IMPORT_SUFFIX =
@@ -15,8 +19,9 @@ export const VIRTUAL_FARM_DYNAMIC_IMPORT_SUFFIX =
  * So the latter update process will not override the previous one if they are updating at the same time.
  */
 export interface UpdateQueueItem {
-  paths: string[];
+  paths: (string | JsUpdateEvent)[];
   resolve: (res: JsUpdateResult) => void;
+  reject: (err: unknown) => void;
 }
 
 export type PluginStats = Record<
@@ -33,7 +38,12 @@ export type PluginStats = Record<
 export class Compiler {
   private _bindingCompiler: BindingCompiler;
   private _updateQueue: UpdateQueueItem[] = [];
-  private _onUpdateFinishQueue: (() => void | Promise<void>)[] = [];
+  /**
+   * Called when the resources of the update are regenerated, `err` is set if the regeneration failed.
+   * The resources of the last successful compilation are kept in that case
+   */
+  private _onUpdateFinishQueue: ((err?: Error) => void | Promise<void>)[] =
+    [];
 
   public compiling = false;
 
@@ -76,20 +86,22 @@ export class Compiler {
   }
 
   async update(
-    paths: string[],
+    paths: (string | JsUpdateEvent)[],
     sync = false,
     ignoreCompilingCheck = false,
     generateUpdateResource = true
   ): Promise<JsUpdateResult> {
     let resolve: (res: JsUpdateResult) => void;
+    let reject: (err: unknown) => void;
 
-    const promise = new Promise<JsUpdateResult>((r) => {
+    const promise = new Promise<JsUpdateResult>((r, j) => {
       resolve = r;
+      reject = j;
     });
 
     // if there is already a update process, we need to wait for it to finish
     if (this.compiling && !ignoreCompilingCheck) {
-      this._updateQueue.push({ paths, resolve });
+      this._updateQueue.push({ paths, resolve, reject });
       return promise;
     }
 
@@ -97,24 +109,11 @@ export class Compiler {
     try {
       const res = await this._bindingCompiler.update(
         paths,
-        async () => {
-          const next = this._updateQueue.shift();
-
-          if (next) {
-            await this.update(
-              next.paths,
-              true,
-              true,
-              generateUpdateResource
-            ).then(next.resolve);
-          } else {
-            this.compiling = false;
-            for (const cb of this._onUpdateFinishQueue) {
-              await cb();
-            }
-            // clear update finish queue
-            this._onUpdateFinishQueue = [];
-          }
+        async (err?: string) => {
+          await this._updateFinished(
+            generateUpdateResource,
+            err ? new Error(err) : undefined
+          );
         },
         sync,
         generateUpdateResource
@@ -122,15 +121,38 @@ export class Compiler {
 
       return res as JsUpdateResult;
     } catch (e) {
-      this.compiling = false;
+      // the failed update does not block the queued updates
+      this._updateFinished(generateUpdateResource);
       throw e;
     }
   }
 
+  private async _updateFinished(generateUpdateResource: boolean, err?: Error) {
+    const next = this._updateQueue.shift();
+
+    if (next) {
+      await this.update(next.paths, true, true, generateUpdateResource).then(
+        next.resolve,
+        next.reject
+      );
+    } else {
+      this.compiling = false;
+      for (const cb of this._onUpdateFinishQueue) {
+        await cb(err);
+      }
+      // clear update finish queue
+      this._onUpdateFinishQueue = [];
+    }
+  }
+
   hasModule(resolvedPath: string): boolean {
     return this._bindingCompiler.hasModule(resolvedPath);
   }
 
+  isMissingDependency(resolvedPath: string): boolean {
+    return this._bindingCompiler.isMissingDependency(resolvedPath);
+  }
+
   getParentFiles(idOrResolvedPath: string): string[] {
     return this._bindingCompiler.getParentFiles(idOrResolvedPath);
   }
@@ -220,7 +242,7 @@ export class Compiler {
     return path.join(root, p);
   }
 
-  onUpdateFinish(cb: () => void) {
+  onUpdateFinish(cb: (err?: Error) => void | Promise<void>) {
     this._onUpdateFinishQueue.push(cb);
   }
 
@@ -311,8 +311,10 @@ export async function normalizeUserCompilationConfig(
     config.output?.targetEnv === 'node'
       ? {}
       : Object.keys(userConfig.env || {}).reduce((env: any, key) => {
-          env[`$__farm_regex:(global(This)?\\.)?process\\.env\\.${key}`] =
-            JSON.stringify(userConfig.env[key]);
+          const value = JSON.stringify(userConfig.env[key]);
+          env[`process.env.${key}`] = value;
+          env[`global.process.env.${key}`] = value;
+          env[`globalThis.process.env.${key}`] = value;
           return env;
         }, {})
   );
@@ -449,6 +451,10 @@ export async function normalizeUserCompilationConfig(
     }
   }
 
+  if (config.concatenateModules === undefined) {
+    config.concatenateModules = isProduction;
+  }
+
   if (config.script?.plugins?.length) {
     logger.info(
       `Swc plugins are configured, note that Farm uses ${colors.yellow(
@@ -84,6 +84,11 @@ const targetsMap: TargetsMap = {
  * @param config
  */
 function normalizeTargetEnv(config: Config['config']) {
+  // presetEnv, the css prefixer and the script target are derived from `targets` by the rust compiler when it's configured
+  if (config.targets) {
+    return;
+  }
+
   const aliasMap: Record<string, keyof TargetsMap> = {
     node: 'node16',
     browser: 'browser-es2017'
@@ -28,7 +28,30 @@ const compilationConfigSchema = z
             'browser-es2017'
           ])
           .optional(),
-        format: z.enum(['cjs', 'esm']).optional()
+        format: z.enum(['cjs', 'esm', 'iife', 'umd']).optional(),
+        name: z.string().optional(),
+        globals: z.record(z.string()).optional(),
+        manifest: z
+          .union([
+            z.boolean(),
+            z.object({ filename: z.string().optional() }).strict()
+          ])
+          .optional(),
+        sri: z.enum(['sha256', 'sha384', 'sha512']).optional(),
+        compress: z
+          .union([
+            z.boolean(),
+            z
+              .object({
+                gzip: z.boolean().optional(),
+                gzipLevel: z.number().int().min(0).max(9).optional(),
+                brotli: z.boolean().optional(),
+                brotliLevel: z.number().int().min(0).max(11).optional(),
+                threshold: z.number().optional()
+              })
+              .strict()
+          ])
+          .optional()
       })
       .strict()
       .optional(),
@@ -76,7 +99,8 @@ const compilationConfigSchema = z
       .optional(),
     assets: z
       .object({
-        include: z.array(z.string()).optional()
+        include: z.array(z.string()).optional(),
+        inlineLimit: z.number().nonnegative().int().optional()
       })
       .strict()
       .optional(),
@@ -181,6 +205,7 @@ const compilationConfigSchema = z
       .optional(),
     lazyCompilation: z.boolean().optional(),
     treeShaking: z.boolean().optional(),
+    concatenateModules: z.boolean().optional(),
     minify: z
       .union([
         z.boolean(),
@@ -200,6 +225,11 @@ const compilationConfigSchema = z
       .optional(),
     record: z.boolean().optional(),
     progress: z.boolean().optional(),
+    targets: z
+      .string()
+      .or(z.record(z.string()))
+      .or(z.array(z.string()))
+      .optional(),
     presetEnv: z
       .union([
         z.boolean(),
@@ -215,7 +245,19 @@ const compilationConfigSchema = z
       .object({
         modules: z
           .object({
-            indentName: z.string().optional()
+            indentName: z.string().optional(),
+            localsConvention: z
+              .enum(['asIs', 'camelCase', 'camelCaseOnly', 'dashes'])
+              .optional(),
+            namedExports: z.boolean().optional(),
+            dts: z
+              .union([
+                z.boolean(),
+                z.object({
+                  outDir: z.string().optional()
+                })
+              ])
+              .optional()
           })
           .optional(),
         prefixer: z
@@ -229,7 +271,28 @@ const compilationConfigSchema = z
           .optional()
       })
       .optional(),
-    html: z.object({ base: z.string().optional() }).optional(),
+    html: z
+      .object({
+        base: z.string().optional(),
+        csp: z
+          .object({
+            nonce: z.string().optional(),
+            external: z.boolean().optional(),
+            hashesFilename: z.string().optional()
+          })
+          .strict()
+          .optional(),
+        preload: z.boolean().optional(),
+        prefetch: z.boolean().optional()
+      })
+      .optional(),
+    json: z
+      .object({
+        stringify: z.boolean().optional(),
+        stringifyThreshold: z.number().nonnegative().int().optional()
+      })
+      .strict()
+      .optional(),
     persistentCache: z.union([
       z.boolean(),
       z
@@ -244,6 +307,14 @@ const compilationConfigSchema = z
             })
             .optional(),
           envs: z.record(z.string(), z.string()).optional(),
+          remote: z
+            .object({
+              url: z.string(),
+              headers: z.record(z.string(), z.string()).optional(),
+              readOnly: z.boolean().optional()
+            })
+            .strict()
+            .optional(),
           globalBuiltinCacheKeyStrategy: z
             .object({
               env: z.boolean().optional(),
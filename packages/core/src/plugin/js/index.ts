@@ -179,40 +179,51 @@ function compatibleWin32Path(path: string): string {
 }
 
 export function convertPlugin(plugin: JsPlugin): void {
-  if (
-    plugin.transform &&
-    !plugin.transform.filters?.moduleTypes &&
-    !plugin.transform.filters?.resolvedPaths
-  ) {
-    throw new Error(
-      `transform hook of plugin ${plugin.name} must have at least one filter(like moduleTypes or resolvedPaths)`
+  for (const hookName of [
+    'transform',
+    'processModule',
+    'analyzeDeps'
+  ] as const) {
+    const hook = plugin[hookName];
+
+    if (!hook) {
+      continue;
+    }
+
+    if (!hook.filters?.moduleTypes && !hook.filters?.resolvedPaths) {
+      throw new Error(
+        `${hookName} hook of plugin ${plugin.name} must have at least one filter(like moduleTypes or resolvedPaths)`
+      );
+    }
+
+    hook.filters.moduleTypes ??= [];
+    hook.filters.resolvedPaths = (hook.filters.resolvedPaths ?? []).map(
+      normalizeFilterPath
     );
   }
-  if (plugin.transform) {
-    if (!plugin.transform.filters.moduleTypes) {
-      plugin.transform.filters.moduleTypes = [];
-    } else if (!plugin.transform.filters.resolvedPaths) {
-      plugin.transform.filters.resolvedPaths = [];
+
+  for (const hookName of [
+    'renderResourcePot',
+    'optimizeResourcePot'
+  ] as const) {
+    const hook = plugin[hookName];
+
+    if (!hook) {
+      continue;
     }
-  }
 
-  if (plugin.renderResourcePot) {
-    plugin.renderResourcePot.filters ??= {};
+    hook.filters ??= {};
 
-    if (
-      !plugin.renderResourcePot?.filters?.moduleIds &&
-      !plugin.renderResourcePot?.filters?.resourcePotTypes
-    ) {
+    if (!hook.filters.moduleIds && !hook.filters.resourcePotTypes) {
       throw new Error(
-        `renderResourcePot hook of plugin ${plugin.name} must have at least one filter(like moduleIds or resourcePotTypes)`
+        `${hookName} hook of plugin ${plugin.name} must have at least one filter(like moduleIds or resourcePotTypes)`
       );
     }
 
-    if (!plugin.renderResourcePot.filters?.resourcePotTypes) {
-      plugin.renderResourcePot.filters.resourcePotTypes = [];
-    } else if (!plugin.renderResourcePot.filters?.moduleIds) {
-      plugin.renderResourcePot.filters.moduleIds = [];
-    }
+    hook.filters.resourcePotTypes ??= [];
+    hook.filters.moduleIds = (hook.filters.moduleIds ?? []).map(
+      normalizeFilterPath
+    );
   }
 
   if (plugin.augmentResourceHash) {
@@ -244,17 +255,8 @@ export function convertPlugin(plugin: JsPlugin): void {
       plugin.load.filters.resolvedPaths.map(normalizeFilterPath);
   }
 
-  if (plugin.transform?.filters?.resolvedPaths?.length) {
-    plugin.transform.filters.resolvedPaths =
-      plugin.transform.filters.resolvedPaths.map(normalizeFilterPath);
-  }
   if (plugin.augmentResourceHash?.filters?.moduleIds) {
     plugin.augmentResourceHash.filters.moduleIds =
       plugin.augmentResourceHash.filters.moduleIds.map(normalizeFilterPath);
   }
-
-  if (plugin.renderResourcePot?.filters?.moduleIds) {
-    plugin.renderResourcePot.filters.moduleIds =
-      plugin.renderResourcePot.filters.moduleIds.map(normalizeFilterPath);
-  }
 }
@@ -31,6 +31,24 @@ export interface ViteModule {
   type: 'js' | 'css';
 }
 
+export interface DiagnosticPosition {
+  /** 1-based line */
+  line: number;
+  /** 0-based column */
+  column: number;
+}
+
+export interface Diagnostic {
+  message: string;
+  severity?: 'error' | 'warning';
+  plugin?: string;
+  file?: string;
+  start?: DiagnosticPosition;
+  end?: DiagnosticPosition;
+  codeFrame?: string;
+  hints?: string[];
+}
+
 export interface CompilationContext {
   resolve(
     param: PluginResolveHookParam,
@@ -40,8 +58,8 @@ export interface CompilationContext {
   addWatchFile(currentFile: string, targetFile: string): void;
   emitFile(params: CompilationContextEmitFileParams): void;
   getWatchFiles(): string[];
-  warn(message: string): void;
-  error(message: string): void;
+  warn(message: string | Diagnostic): void;
+  error(message: string | Diagnostic): void;
   sourceMapEnabled(id: string): boolean;
 
   viteGetModulesByFile(file: string): ViteModule[];
@@ -107,6 +125,25 @@ export interface PluginRenderResourcePotResult {
   sourceMap?: string;
 }
 
+export interface PluginProcessModuleParams {
+  moduleId: string;
+  moduleType: string;
+  content: string;
+}
+
+export interface PluginAnalyzeDepsEntry {
+  source: string;
+  kind: string | { entry: string };
+}
+
+export interface PluginAnalyzeDepsParams {
+  moduleId: string;
+  moduleType: string;
+  content: string;
+  /** deps analyzed by previous plugins */
+  deps: PluginAnalyzeDepsEntry[];
+}
+
 export interface Resource {
   name: string;
   bytes: number[];
@@ -116,6 +153,26 @@ export interface Resource {
   info?: ResourcePotInfo;
 }
 
+export interface Stats {
+  /** time cost of each compilation stage, in milliseconds */
+  timings: {
+    build: number;
+    optimize: number;
+    partialBundling: number;
+    render: number;
+    finalize: number;
+  };
+  /** count of modules keyed by module type */
+  moduleCounts: Record<string, number>;
+  cache: {
+    moduleCacheHits: number;
+    moduleCacheMisses: number;
+    resourceCacheHits: number;
+    resourceCacheMisses: number;
+  };
+  resources: { name: string; resourceType: string; size: number }[];
+}
+
 export type PluginFinalizeResourcesHookParams = {
   resourcesMap: Record<string, Resource>;
   config: Config['config'];
@@ -176,6 +233,25 @@ export interface JsPlugin {
     PluginTransformHookResult
   >;
 
+  /**
+   * Called after the module is parsed, the ast is not exposed so only the transformed content is available.
+   * This hook is read-only, the returned value is ignored and the module can not be modified, use `transform` instead
+   */
+  processModule?: JsPluginHook<
+    { resolvedPaths?: string[]; moduleTypes?: string[] },
+    Readonly<PluginProcessModuleParams>,
+    void
+  >;
+
+  /**
+   * The returned deps replace the deps analyzed by previous plugins, return `params.deps` with extra entries to add dependencies
+   */
+  analyzeDeps?: JsPluginHook<
+    { resolvedPaths?: string[]; moduleTypes?: string[] },
+    PluginAnalyzeDepsParams,
+    PluginAnalyzeDepsEntry[]
+  >;
+
   buildEnd?: { executor: Callback<Record<string, never>, void> };
 
   renderStart?: {
@@ -200,6 +276,18 @@ export interface JsPlugin {
     string
   >;
 
+  /**
+   * Post-process the final rendered content of the resource pot, for example minify or wrap it
+   */
+  optimizeResourcePot?: JsPluginHook<
+    {
+      resourcePotTypes?: ResourcePotType[];
+      moduleIds?: string[];
+    },
+    PluginRenderResourcePotParams,
+    PluginRenderResourcePotResult
+  >;
+
   finalizeResources?: {
     executor: Callback<
       PluginFinalizeResourcesHookParams,
@@ -227,7 +315,7 @@ export interface JsPlugin {
     executor: Callback<undefined, number[]>;
   };
 
-  finish?: { executor: Callback<Record<string, never>, void> };
+  finish?: { executor: Callback<Stats, void> };
 
   updateModules?: {
     executor: Callback<
@@ -1,6 +1,7 @@
 import type { RollupError } from 'rollup';
 import { colors } from '../utils/color.js';
 import { pad } from '../utils/share.js';
+import type { Diagnostic } from '../plugin/type.js';
 // import { DevServer } from './index.js';
 
 export function prepareError(err: Error & { potentialSolution?: string }) {
@@ -29,14 +30,18 @@ export function cleanStack(stack: string) {
 }
 
 export function buildErrorMessage(
-  err: RollupError & { source: string },
+  err: RollupError & Partial<Diagnostic> & { source: string },
   args: string[] = [],
   includeStack = true
 ): string {
+  // diagnostics of the compiler are serialized as `file`, `start` and `codeFrame`
+  const id = err.id ?? err.file;
+  const position = err.loc ?? err.start;
+  const frame = err.frame ?? err.codeFrame;
   if (err.plugin) args.push(`  Plugin: ${colors.magenta(err.plugin)}`);
-  const loc = err.loc ? `:${err.loc.line}:${err.loc.column}` : '';
-  if (err.id) args.push(`  File: ${colors.cyan(err.id)}${loc}`);
-  if (err.frame) args.push(colors.yellow(pad(err.frame)));
+  const loc = position ? `:${position.line}:${position.column}` : '';
+  if (id) args.push(`  File: ${colors.cyan(id)}${loc}`);
+  if (frame) args.push(colors.yellow(pad(frame)));
   else if (err.source) args.push(colors.yellow(err.source));
   if (includeStack && err.stack) args.push(pad(cleanStack(err.stack)));
   return args.join('\n');
@@ -7,13 +7,13 @@ import { stat } from 'node:fs/promises';
 import { Compiler } from '../compiler/index.js';
 import { Server } from './index.js';
 import { Logger, bold, clearScreen, cyan, green } from '../utils/index.js';
-import { JsUpdateResult } from '../../binding/binding.js';
+import { JsUpdateEvent, JsUpdateResult } from '../../binding/binding.js';
 import type { Resource } from '@farmfe/runtime/src/resource-loader.js';
 import { WebSocketClient } from './ws.js';
 import { logError } from './error.js';
 
 export class HmrEngine {
-  private _updateQueue: string[] = [];
+  private _updateQueue: (string | JsUpdateEvent)[] = [];
   // private _updateResults: Map<string, { result: string; count: number }> =
 
   private _compiler: Compiler;
@@ -48,6 +48,7 @@ export class HmrEngine {
     }
 
     let updatedFilesStr = queue
+      .map(getUpdatePath)
       .map((item) => {
         if (isAbsolute(item)) {
           return relative(this._compiler.config.config.root, item);
@@ -122,7 +123,12 @@ export class HmrEngine {
       `);
       });
 
-      this._compiler.onUpdateFinish(async () => {
+      this._compiler.onUpdateFinish(async (err) => {
+        // the resources failed to regenerate after the result is sent, the previous resources are still served
+        if (err) {
+          this.sendError(new Error(logError(err, false)));
+        }
+
         // if there are more updates, recompile again
         if (this._updateQueue.length > 0) {
           await this.recompileAndSendResult();
@@ -134,11 +140,36 @@ export class HmrEngine {
     }
   };
 
-  async hmrUpdate(absPath: string | string[], force = false) {
+  /**
+   * Queue the changed files and update them. A path string means the file is updated, added and removed files are passed as
+   * `{ path, updateType }`, the watcher only passes the ones that affect the module graph.
+   */
+  async hmrUpdate(
+    absPath: string | JsUpdateEvent | (string | JsUpdateEvent)[],
+    force = false
+  ) {
     const paths = Array.isArray(absPath) ? absPath : [absPath];
 
-    for (const path of paths) {
-      if (this._compiler.hasModule(path) && !this._updateQueue.includes(path)) {
+    for (const item of paths) {
+      if (
+        typeof item !== 'string' &&
+        (item.updateType ?? 'updated') !== 'updated'
+      ) {
+        this._lastModifiedTimestamp.delete(item.path);
+        // the latest event of the file wins, e.g. a file that is removed and added back
+        this._updateQueue = this._updateQueue.filter(
+          (queued) => getUpdatePath(queued) !== item.path
+        );
+        this._updateQueue.push(item);
+        continue;
+      }
+
+      const path = getUpdatePath(item);
+
+      if (
+        this._compiler.hasModule(path) &&
+        !this._updateQueue.some((queued) => getUpdatePath(queued) === path)
+      ) {
         if (fse.existsSync(path)) {
           const lastModifiedTimestamp = this._lastModifiedTimestamp.get(path);
           const currentTimestamp = (await stat(path)).mtime.toISOString();
@@ -157,23 +188,31 @@ export class HmrEngine {
       try {
         await this.recompileAndSendResult();
       } catch (e) {
-        // eslint-disable-next-line no-control-regex
-        const serialization = e.message.replace(/\x1b\[[0-9;]*m/g, '');
-        const errorStr = `${JSON.stringify({
-          message: serialization
-        })}`;
-        this._devServer.ws.clients.forEach((client: WebSocketClient) => {
-          client.rawSend(`
-            {
-              type: 'error',
-              err: ${errorStr}
-            }
-          `);
-        });
-        this._logger.error(e);
+        this.sendError(e);
       }
     }
   }
+
+  private sendError(e: Error) {
+    // eslint-disable-next-line no-control-regex
+    const serialization = e.message.replace(/\x1b\[[0-9;]*m/g, '');
+    const errorStr = `${JSON.stringify({
+      message: serialization
+    })}`;
+    this._devServer.ws.clients.forEach((client: WebSocketClient) => {
+      client.rawSend(`
+        {
+          type: 'error',
+          err: ${errorStr}
+        }
+      `);
+    });
+    this._logger.error(e);
+  }
+}
+
+function getUpdatePath(item: string | JsUpdateEvent) {
+  return typeof item === 'string' ? item : item.path;
 }
 
 function formatHmrResult(array: string[]) {
@@ -88,7 +88,7 @@ export class FileWatcher implements ImplFileWatcher {
           compilerHandler(
             async () => {
               const result = await compiler.update([path], true);
-              handleUpdateFinish(result);
+              this.handleUpdateFinish(result);
               compiler.writeResourcesToDisk();
             },
             this.options,
@@ -111,28 +111,82 @@ export class FileWatcher implements ImplFileWatcher {
       handlePathChange(path);
     });
 
-    const handleUpdateFinish = (updateResult: JsUpdateResult) => {
-      const added = [
-        ...updateResult.added,
-        ...updateResult.extraWatchResult.add
-      ].map((addedModule) => {
-        const resolvedPath = compiler.transformModulePath(
-          this._root,
-          addedModule
-        );
-        return resolvedPath;
-      });
-      const filteredAdded = added.filter((file) =>
-        this.filterWatchFile(file, this._root)
+    this._watcher.on('add', (path) =>
+      this.handleFileAddedOrRemoved(path, 'added')
+    );
+    this._watcher.on('unlink', (path) =>
+      this.handleFileAddedOrRemoved(path, 'removed')
+    );
+
+    if (this.serverOrCompiler instanceof Server) {
+      this.serverOrCompiler.hmrEngine?.onUpdateFinish((result) =>
+        this.handleUpdateFinish(result)
       );
+    }
+  }
 
-      if (filteredAdded.length > 0) {
-        this._watcher.add(filteredAdded);
+  /**
+   * Added files may be the missing dependencies of some modules, and the importers of removed files should report the missing dependencies.
+   * Other added or removed files do not affect the compilation, so they are ignored.
+   */
+  async handleFileAddedOrRemoved(
+    path: string,
+    updateType: 'added' | 'removed'
+  ): Promise<void> {
+    if (this._close) {
+      return;
+    }
+
+    const compiler = this.getCompilerFromServerOrCompiler(
+      this.serverOrCompiler
+    );
+    const affectsCompilation =
+      compiler.hasModule(path) ||
+      (updateType === 'added' && compiler.isMissingDependency(path));
+
+    if (!affectsCompilation) {
+      return;
+    }
+
+    try {
+      if (this.serverOrCompiler instanceof Server) {
+        await this.serverOrCompiler.hmrEngine.hmrUpdate({ path, updateType });
+      } else {
+        await compilerHandler(
+          async () => {
+            const result = await compiler.update([{ path, updateType }], true);
+            this.handleUpdateFinish(result);
+            compiler.writeResourcesToDisk();
+          },
+          this.options,
+          { clear: true }
+        );
       }
-    };
+    } catch (error) {
+      this._logger.error(error);
+    }
+  }
 
-    if (this.serverOrCompiler instanceof Server) {
-      this.serverOrCompiler.hmrEngine?.onUpdateFinish(handleUpdateFinish);
+  private handleUpdateFinish(updateResult: JsUpdateResult) {
+    const compiler = this.getCompilerFromServerOrCompiler(
+      this.serverOrCompiler
+    );
+    const added = [
+      ...updateResult.added,
+      ...updateResult.extraWatchResult.add
+    ].map((addedModule) => {
+      const resolvedPath = compiler.transformModulePath(
+        this._root,
+        addedModule
+      );
+      return resolvedPath;
+    });
+    const filteredAdded = added.filter((file) =>
+      this.filterWatchFile(file, this._root)
+    );
+
+    if (filteredAdded.length > 0) {
+      this._watcher.add(filteredAdded);
     }
   }
 
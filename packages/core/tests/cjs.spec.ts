@@ -26,7 +26,9 @@ test('resolveUserConfig', async () => {
       NODE_ENV: 'development'
     },
     // FARM_HMR_PROTOCOL: 'ws',
-    '$__farm_regex:(global(This)?\\.)?process\\.env\\.NODE_ENV': '"development"'
+    'process.env.NODE_ENV': '"development"',
+    'global.process.env.NODE_ENV': '"development"',
+    'globalThis.process.env.NODE_ENV': '"development"'
   });
   expect(config.compilation.input).toEqual({
     main: './main.tsx'
@@ -55,8 +57,9 @@ test('resolveUserConfig', async () => {
       NODE_ENV: 'development',
       'package.json[name]': 'farm-fe',
       'package.json[type]': 'unknown',
-      '$__farm_regex:(global(This)?\\.)?process\\.env\\.NODE_ENV':
-        '"development"',
+      'process.env.NODE_ENV': '"development"',
+      'global.process.env.NODE_ENV': '"development"',
+      'globalThis.process.env.NODE_ENV': '"development"',
       'package.json[browser]': 'unknown',
       'package.json[exports]': 'unknown',
       'package.json[main]': 'unknown',
@@ -27,7 +27,9 @@ test('resolveUserConfig', async () => {
       NODE_ENV: 'development'
     },
     // FARM_HMR_PROTOCOL: 'ws',
-    '$__farm_regex:(global(This)?\\.)?process\\.env\\.NODE_ENV': '"development"'
+    'process.env.NODE_ENV': '"development"',
+    'global.process.env.NODE_ENV': '"development"',
+    'globalThis.process.env.NODE_ENV': '"development"'
   });
   expect(config.compilation.input).toEqual({
     main: './main.tsx'
@@ -56,8 +58,9 @@ test('resolveUserConfig', async () => {
       NODE_ENV: 'development',
       'package.json[name]': 'farm-fe',
       'package.json[type]': 'unknown',
-      '$__farm_regex:(global(This)?\\.)?process\\.env\\.NODE_ENV':
-        '"development"',
+      'process.env.NODE_ENV': '"development"',
+      'global.process.env.NODE_ENV': '"development"',
+      'globalThis.process.env.NODE_ENV': '"development"',
       'package.json[browser]': 'unknown',
       'package.json[exports]': 'unknown',
       'package.json[main]': 'unknown',
@@ -87,7 +90,9 @@ test('resolveUserConfig-prod', async () => {
     FARM_PROCESS_ENV: {
       NODE_ENV: 'production'
     },
-    '$__farm_regex:(global(This)?\\.)?process\\.env\\.NODE_ENV': '"production"'
+    'process.env.NODE_ENV': '"production"',
+    'global.process.env.NODE_ENV': '"production"',
+    'globalThis.process.env.NODE_ENV': '"production"'
   });
   expect(config.compilation.input).toEqual({
     main: './main.tsx'
@@ -122,8 +127,9 @@ test('resolveUserConfig-prod', async () => {
       'package.json[exports]': 'unknown',
       'package.json[main]': 'unknown',
       'package.json[module]': 'unknown',
-      '$__farm_regex:(global(This)?\\.)?process\\.env\\.NODE_ENV':
-        '"production"'
+      'process.env.NODE_ENV': '"production"',
+      'global.process.env.NODE_ENV': '"production"',
+      'globalThis.process.env.NODE_ENV': '"production"'
     },
     moduleCacheKeyStrategy: {}
   });
@@ -155,7 +161,9 @@ test('resolveUserConfig-input-html-prod', async () => {
     FARM_PROCESS_ENV: {
       NODE_ENV: 'production'
     },
-    '$__farm_regex:(global(This)?\\.)?process\\.env\\.NODE_ENV': '"production"'
+    'process.env.NODE_ENV': '"production"',
+    'global.process.env.NODE_ENV': '"production"',
+    'globalThis.process.env.NODE_ENV': '"production"'
   });
 
   expect(config.compilation.output).toEqual({
@@ -220,8 +228,9 @@ test('resolveUserConfig-input-html-prod', async () => {
       'package.json[exports]': 'unknown',
       'package.json[main]': 'unknown',
       'package.json[module]': 'unknown',
-      '$__farm_regex:(global(This)?\\.)?process\\.env\\.NODE_ENV':
-        '"production"'
+      'process.env.NODE_ENV': '"production"',
+      'global.process.env.NODE_ENV': '"production"',
+      'globalThis.process.env.NODE_ENV': '"production"'
     },
     moduleCacheKeyStrategy: {}
   });
@@ -0,0 +1,45 @@
+import { describe, expect, test } from 'vitest';
+import { normalizeOutput } from '../../src/config/normalize-config/normalize-output.js';
+
+import type { Config } from '../../binding/index.js';
+
+describe('normalizeOutput', () => {
+  test('targetEnv sets presetEnv and script target', async () => {
+    const config: Config['config'] = {
+      input: { index: './index.html' },
+      output: { targetEnv: 'browser-es2015' }
+    };
+    await normalizeOutput(config, true);
+
+    expect(config.presetEnv).toMatchObject({
+      options: { targets: expect.any(Array) }
+    });
+    expect(config.script.target).toBe('es2015');
+  });
+
+  test('targets skip the defaults of targetEnv', async () => {
+    const config: Config['config'] = {
+      input: { index: './index.html' },
+      output: { targetEnv: 'browser-es2015' },
+      targets: ['chrome >= 80']
+    };
+    await normalizeOutput(config, true);
+
+    expect(config.presetEnv).toBeUndefined();
+    expect(config.script).toBeUndefined();
+    expect(config.css).toBeUndefined();
+  });
+
+  test('targets are not overridden by browser-esnext', async () => {
+    const config: Config['config'] = {
+      input: { index: './index.html' },
+      output: { targetEnv: 'browser-esnext' },
+      targets: 'defaults'
+    };
+    await normalizeOutput(config, true);
+
+    expect(config.presetEnv).toBeUndefined();
+    expect(config.script).toBeUndefined();
+    expect(config.css).toBeUndefined();
+  });
+});
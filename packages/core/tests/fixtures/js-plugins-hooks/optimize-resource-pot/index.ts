@@ -0,0 +1 @@
+export default 'optimize-resource-pot-return-value';
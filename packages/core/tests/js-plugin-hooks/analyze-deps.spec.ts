@@ -0,0 +1,44 @@
+import { expect, test } from 'vitest';
+import { getCompiler } from './common.js';
+
+test('Js Plugin Execution - processModule and analyzeDeps', async () => {
+  const hookName = 'analyze-deps';
+  const calledHooks: string[] = [];
+  const compiler = await getCompiler(
+    '',
+    [
+      {
+        name: 'test-analyze-deps',
+        priority: 1000,
+        processModule: {
+          filters: {
+            resolvedPaths: ['dep\\.ts$']
+          },
+          executor: async (param) => {
+            expect(param.moduleId).toBe('dep.ts');
+            expect(param.moduleType).toBe('ts');
+            expect(param.content).toContain('extra-dep');
+            calledHooks.push('processModule');
+          }
+        },
+        analyzeDeps: {
+          filters: {
+            resolvedPaths: ['index\\.ts$']
+          },
+          executor: async (param) => {
+            expect(param.moduleId).toBe('index.ts?foo=bar');
+            expect(param.moduleType).toBe('ts');
+            expect(param.deps).toEqual([]);
+            calledHooks.push('analyzeDeps');
+            return [...param.deps, { source: './dep.ts', kind: 'import' }];
+          }
+        }
+      }
+    ],
+    hookName
+  );
+
+  await compiler.compile();
+
+  expect(calledHooks).toEqual(['analyzeDeps', 'processModule']);
+});
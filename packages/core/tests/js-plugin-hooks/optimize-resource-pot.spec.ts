@@ -0,0 +1,51 @@
+import { pathToFileURL } from 'url';
+import { expect, test } from 'vitest';
+import { getCompiler, getOutputFilePath } from './common.js';
+
+test('Js Plugin Execution - optimizeResourcePot', async () => {
+  const hookName = 'optimize-resource-pot';
+  const calledHooks: string[] = [];
+  const compiler = await getCompiler(
+    '',
+    [
+      {
+        name: 'test-optimize-resource-pot',
+        priority: 1000,
+        optimizeResourcePot: {
+          filters: {
+            resourcePotTypes: ['js']
+          },
+          executor: async (param) => {
+            expect(param.content).toContain(
+              'optimize-resource-pot-return-value'
+            );
+            expect(param.resourcePotInfo.resourcePotType).toBe('js');
+            calledHooks.push('optimizeResourcePot');
+            return {
+              content: param.content.replace(
+                'optimize-resource-pot-return-value',
+                'optimized'
+              )
+            };
+          }
+        }
+      }
+    ],
+    hookName
+  );
+
+  await compiler.compile();
+  await compiler.writeResourcesToDisk();
+
+  expect(calledHooks).toEqual(['optimizeResourcePot']);
+
+  const outputFilePath = getOutputFilePath('', hookName);
+
+  if (process.platform === 'win32') {
+    const result = await import(pathToFileURL(outputFilePath).toString());
+    expect(result.default).toBe('optimized');
+  } else {
+    const result = await import(outputFilePath);
+    expect(result.default).toBe('optimized');
+  }
+});
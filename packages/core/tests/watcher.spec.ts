@@ -0,0 +1,39 @@
+import path from 'path';
+import { expect, test, vi } from 'vitest';
+
+import { Server } from '../src/index.js';
+import { FileWatcher } from '../src/watcher/index.js';
+import { getCompiler, getFixturesDir } from './common.js';
+
+import type { ResolvedUserConfig } from '../src/config/index.js';
+
+test('FileWatcher - should pass added and removed files that affect the module graph to the hmr engine', async () => {
+  const root = path.join(getFixturesDir(), 'watcher');
+  const compiler = await getCompiler(root, 'watcher', [], {
+    index: './index.ts'
+  });
+  await compiler.compile();
+
+  const hmrUpdate = vi.fn(async () => undefined);
+  // the dev server is not started, only the compiler and the hmr engine are used by the watcher
+  const server: Server = Object.assign(Object.create(Server.prototype), {
+    compiler,
+    hmrEngine: { hmrUpdate }
+  });
+  const watcher = new FileWatcher(server, {
+    root
+  } as ResolvedUserConfig);
+
+  const dep = path.join(root, 'dep.ts');
+  await watcher.handleFileAddedOrRemoved(dep, 'removed');
+  expect(hmrUpdate).toHaveBeenCalledTimes(1);
+  expect(hmrUpdate).toHaveBeenCalledWith({ path: dep, updateType: 'removed' });
+
+  // the file is not a module or a missing dependency
+  await watcher.handleFileAddedOrRemoved(path.join(root, 'other.ts'), 'added');
+  await watcher.handleFileAddedOrRemoved(
+    path.join(root, 'other.ts'),
+    'removed'
+  );
+  expect(hmrUpdate).toHaveBeenCalledTimes(1);
+});
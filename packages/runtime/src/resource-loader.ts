@@ -6,6 +6,7 @@ import type { ResourceLoadResult } from './plugin';
 export interface Resource {
   path: string;
   type: 'script' | 'link';
+  integrity?: string;
 }
 
 // Injected during build
@@ -104,9 +105,9 @@ export class ResourceLoader {
     let promise = Promise.resolve();
 
     if (resource.type === 'script') {
-      promise = this._loadScript(url);
+      promise = this._loadScript(url, resource.integrity);
     } else if (resource.type === 'link') {
-      promise = this._loadLink(url);
+      promise = this._loadLink(url, resource.integrity);
     }
 
     this._loadingResources[resource.path] = promise;
@@ -134,13 +135,19 @@ export class ResourceLoader {
     return promise;
   }
 
-  private _loadScript(path: string): Promise<void> {
+  private _loadScript(path: string, integrity?: string): Promise<void> {
     if (!isBrowser) {
       return import(path);
     } else {
       return new Promise((resolve, reject) => {
         const script = document.createElement('script');
         script.src = path;
+
+        if (integrity) {
+          script.integrity = integrity;
+          script.crossOrigin = 'anonymous';
+        }
+
         document.body.appendChild(script);
 
         script.onload = () => {
@@ -153,7 +160,7 @@ export class ResourceLoader {
     }
   }
 
-  private _loadLink(path: string): Promise<void> {
+  private _loadLink(path: string, integrity?: string): Promise<void> {
     if (!isBrowser) {
       // return Promise.reject(new Error('Not support loading css in SSR'));
       // ignore css loading in SSR
@@ -163,6 +170,12 @@ export class ResourceLoader {
         const link = document.createElement('link');
         link.rel = 'stylesheet';
         link.href = path;
+
+        if (integrity) {
+          link.integrity = integrity;
+          link.crossOrigin = 'anonymous';
+        }
+
         document.head.appendChild(link);
 
         link.onload = () => {